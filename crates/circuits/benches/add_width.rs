@@ -0,0 +1,86 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Compares proving a single addition gadget of each word width over columns of the same size.
+//!
+//! Every gadget commits one carry bit per input bit, so the proving time should depend on the
+//! column size rather than on how the bits are grouped into words.
+
+mod common;
+
+use binius_circuits::{
+	arithmetic::{self, Flags},
+	builder::ConstraintSystemBuilder,
+	u16add::u16add,
+	u64add::u64add,
+	unconstrained::unconstrained,
+};
+use binius_core::oracle::OracleId;
+use binius_field::BinaryField1b;
+use common::time_prove;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+const LOG_SIZES: [usize; 2] = [14, 16];
+
+type Adder =
+	fn(&mut ConstraintSystemBuilder, OracleId, OracleId) -> Result<OracleId, anyhow::Error>;
+
+fn add_u16(
+	builder: &mut ConstraintSystemBuilder,
+	x: OracleId,
+	y: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	u16add(builder, "x + y", x, y)
+}
+
+fn add_u32(
+	builder: &mut ConstraintSystemBuilder,
+	x: OracleId,
+	y: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	arithmetic::u32::add(builder, "x + y", x, y, Flags::Unchecked)
+}
+
+fn add_u64(
+	builder: &mut ConstraintSystemBuilder,
+	x: OracleId,
+	y: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	u64add(builder, "x + y", x, y)
+}
+
+/// Adds two unconstrained columns of `2^log_size` bits with `adder`.
+fn add_columns(
+	builder: &mut ConstraintSystemBuilder,
+	adder: Adder,
+	log_size: usize,
+) -> Result<(), anyhow::Error> {
+	let x = unconstrained::<BinaryField1b>(builder, "x", log_size)?;
+	let y = unconstrained::<BinaryField1b>(builder, "y", log_size)?;
+	adder(builder, x, y)?;
+	Ok(())
+}
+
+fn bench_add_width(c: &mut Criterion) {
+	let mut group = c.benchmark_group("add_width");
+	group.sample_size(10);
+
+	for log_size in LOG_SIZES {
+		group.throughput(Throughput::Elements(1 << log_size));
+		for (name, adder) in [
+			("u16", add_u16 as Adder),
+			("u32", add_u32 as Adder),
+			("u64", add_u64 as Adder),
+		] {
+			group.bench_function(BenchmarkId::new(name, log_size), |b| {
+				b.iter_custom(|iters| {
+					time_prove(iters, |builder| add_columns(builder, adder, log_size))
+				})
+			});
+		}
+	}
+
+	group.finish();
+}
+
+criterion_group!(add_width, bench_add_width);
+criterion_main!(add_width);
@@ -0,0 +1,96 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Proving and verification helpers shared by the benchmarks.
+//!
+//! Every benchmark includes this module with `mod common;` and uses a different subset of it.
+#![allow(dead_code)]
+
+use std::time::{Duration, Instant};
+
+use binius_circuits::builder::{
+	test_utils::{LOG_INV_RATE, SECURITY_BITS},
+	types::{F, U},
+	ConstraintSystemBuilder,
+};
+use binius_core::{
+	constraint_system::{self, channel::Boundary, ConstraintSystem, Proof},
+	fiat_shamir::HasherChallenger,
+	tower::CanonicalTowerFamily,
+	witness::MultilinearExtensionIndex,
+};
+use binius_field::as_packed_field::PackedType;
+use binius_hal::make_portable_backend;
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+
+/// The witness of a circuit built with [`ConstraintSystemBuilder`].
+pub type Witness<'arena> = MultilinearExtensionIndex<'arena, PackedType<U, F>>;
+
+/// Runs `build_circuit` on a builder with a witness, and returns the constraint system together
+/// with the witness.
+pub fn build_with_witness<'arena>(
+	allocator: &'arena bumpalo::Bump,
+	build_circuit: impl FnOnce(&mut ConstraintSystemBuilder<'arena>) -> Result<(), anyhow::Error>,
+) -> Result<(ConstraintSystem<F>, Witness<'arena>), anyhow::Error> {
+	let mut builder = ConstraintSystemBuilder::new_with_witness(allocator);
+	build_circuit(&mut builder)?;
+	let witness = builder.take_witness()?;
+	let constraint_system = builder.build()?;
+	Ok((constraint_system, witness))
+}
+
+/// Proves `witness` with Grøstl-256 hashing and the portable backend, at [`LOG_INV_RATE`] and
+/// [`SECURITY_BITS`].
+pub fn prove(
+	constraint_system: &ConstraintSystem<F>,
+	boundaries: &[Boundary<F>],
+	witness: Witness,
+) -> Result<Proof, anyhow::Error> {
+	let backend = make_portable_backend();
+	let proof = constraint_system::prove::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(constraint_system, LOG_INV_RATE, SECURITY_BITS, boundaries, witness, &backend)?;
+	Ok(proof)
+}
+
+/// Verifies a proof produced by [`prove`].
+pub fn verify(
+	constraint_system: &ConstraintSystem<F>,
+	boundaries: &[Boundary<F>],
+	proof: Proof,
+) -> Result<(), anyhow::Error> {
+	constraint_system::verify::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(constraint_system, LOG_INV_RATE, SECURITY_BITS, boundaries, proof)?;
+	Ok(())
+}
+
+/// Returns the wall-clock time of proving `iters` fresh witnesses of `build_circuit`.
+///
+/// The witness is consumed by the prover, so it is regenerated for every iteration, outside the
+/// timed region. This is the shape expected by criterion's `iter_custom`.
+pub fn time_prove(
+	iters: u64,
+	build_circuit: impl Fn(&mut ConstraintSystemBuilder) -> Result<(), anyhow::Error>,
+) -> Duration {
+	let mut elapsed = Duration::ZERO;
+	for _ in 0..iters {
+		let allocator = bumpalo::Bump::new();
+		let (constraint_system, witness) =
+			build_with_witness(&allocator, &build_circuit).expect("benchmarked circuit must build");
+		let start = Instant::now();
+		std::hint::black_box(
+			prove(&constraint_system, &[], witness).expect("benchmarked circuit must prove"),
+		);
+		elapsed += start.elapsed();
+	}
+	elapsed
+}
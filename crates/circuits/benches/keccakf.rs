@@ -0,0 +1,116 @@
+// Copyright 2025 Irreducible Inc.
+
+mod common;
+
+use std::{
+	hint::black_box,
+	time::{Duration, Instant},
+};
+
+use binius_circuits::{
+	builder::ConstraintSystemBuilder,
+	keccakf::{keccakf, KeccakfState},
+};
+use binius_core::{
+	constraint_system::{ConstraintSystem, Proof},
+	oracle::MultilinearPolyVariant,
+};
+use binius_field::BinaryField128b;
+use common::{build_with_witness, prove, time_prove, verify};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const LOG_N_PERMUTATIONS: [usize; 4] = [7, 9, 11, 13];
+
+fn random_states(n_permutations: usize) -> Vec<KeccakfState> {
+	let mut rng = StdRng::seed_from_u64(0);
+	(0..n_permutations)
+		.map(|_| KeccakfState(rng.gen()))
+		.collect()
+}
+
+/// Returns the time of a single native Keccak-f permutation, averaged over `states`.
+fn native_permutation_time(states: &[KeccakfState]) -> Duration {
+	let start = Instant::now();
+	for state in states {
+		let mut state = state.0;
+		tiny_keccak::keccakf(black_box(&mut state));
+		black_box(state);
+	}
+	start.elapsed() / states.len() as u32
+}
+
+/// Prints the size of the constraint system and proof, and the proving overhead over native
+/// Keccak-f computation.
+fn report(
+	constraint_system: &ConstraintSystem<BinaryField128b>,
+	proof: &Proof,
+	prove_time: Duration,
+	states: &[KeccakfState],
+) {
+	let n_permutations = states.len();
+	let committed_cells = constraint_system
+		.oracles
+		.iter()
+		.filter(|oracle| matches!(oracle.variant, MultilinearPolyVariant::Committed))
+		.map(|oracle| 1usize << oracle.n_vars())
+		.sum::<usize>();
+	let prove_time_per_permutation = prove_time / n_permutations as u32;
+	let native_time_per_permutation = native_permutation_time(states);
+
+	println!("keccakf/{n_permutations}:");
+	println!("  oracles: {}", constraint_system.oracles.size());
+	println!("  committed cells: {committed_cells}");
+	println!("  proof size: {} bytes", proof.get_proof_size());
+	println!("  prove time per permutation: {prove_time_per_permutation:?}");
+	println!("  native time per permutation: {native_time_per_permutation:?}");
+	println!(
+		"  overhead: {:.0}x",
+		prove_time_per_permutation.as_secs_f64() / native_time_per_permutation.as_secs_f64()
+	);
+}
+
+fn bench_keccakf(c: &mut Criterion) {
+	let mut group = c.benchmark_group("keccakf");
+	group.sample_size(10);
+
+	for log_n_permutations in LOG_N_PERMUTATIONS {
+		let n_permutations = 1 << log_n_permutations;
+		let states = random_states(n_permutations);
+		group.throughput(Throughput::Elements(n_permutations as u64));
+
+		group.bench_function(BenchmarkId::new("native", n_permutations), |b| {
+			b.iter(|| {
+				for state in &states {
+					let mut state = state.0;
+					tiny_keccak::keccakf(&mut state);
+					black_box(state);
+				}
+			})
+		});
+
+		let circuit = |builder: &mut ConstraintSystemBuilder| -> Result<(), anyhow::Error> {
+			keccakf(builder, &Some(&states), log_n_permutations)?;
+			Ok(())
+		};
+
+		group.bench_function(BenchmarkId::new("prove", n_permutations), |b| {
+			b.iter_custom(|iters| time_prove(iters, circuit))
+		});
+
+		let allocator = bumpalo::Bump::new();
+		let (constraint_system, witness) = build_with_witness(&allocator, circuit).unwrap();
+		let start = Instant::now();
+		let proof = prove(&constraint_system, &[], witness).unwrap();
+		report(&constraint_system, &proof, start.elapsed(), &states);
+
+		group.bench_function(BenchmarkId::new("verify", n_permutations), |b| {
+			b.iter(|| verify(&constraint_system, &[], proof.clone()).unwrap())
+		});
+	}
+
+	group.finish();
+}
+
+criterion_group!(keccakf_benches, bench_keccakf);
+criterion_main!(keccakf_benches);
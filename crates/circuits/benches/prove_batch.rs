@@ -0,0 +1,90 @@
+// Copyright 2025 Irreducible Inc.
+
+mod common;
+
+use binius_circuits::{
+	arithmetic,
+	builder::{
+		test_utils::{LOG_INV_RATE, SECURITY_BITS},
+		types::{F, U},
+		ConstraintSystemBuilder,
+	},
+	unconstrained::unconstrained,
+};
+use binius_core::{
+	constraint_system::{self, ConstraintSystem},
+	fiat_shamir::HasherChallenger,
+	tower::CanonicalTowerFamily,
+	witness::MultilinearExtensionIndex,
+};
+use binius_field::{as_packed_field::PackedType, BinaryField1b};
+use binius_hal::make_portable_backend;
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use common::prove;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+const LOG_SIZE: usize = 10;
+
+const N_PROOFS: [usize; 2] = [10, 100];
+
+fn build_circuit(builder: &mut ConstraintSystemBuilder) -> Result<(), anyhow::Error> {
+	let x = unconstrained::<BinaryField1b>(builder, "x", LOG_SIZE)?;
+	let y = unconstrained::<BinaryField1b>(builder, "y", LOG_SIZE)?;
+	arithmetic::u32::add(builder, "x + y", x, y, arithmetic::Flags::Unchecked)?;
+	Ok(())
+}
+
+fn make_witness(allocator: &bumpalo::Bump) -> MultilinearExtensionIndex<'_, PackedType<U, F>> {
+	let mut builder = ConstraintSystemBuilder::new_with_witness(allocator);
+	build_circuit(&mut builder).unwrap();
+	builder.take_witness().unwrap()
+}
+
+fn bench_prove_batch(c: &mut Criterion) {
+	let mut group = c.benchmark_group("prove_batch");
+	group.sample_size(10);
+
+	let backend = make_portable_backend();
+	let mut builder = ConstraintSystemBuilder::new();
+	build_circuit(&mut builder).unwrap();
+	let constraint_system: ConstraintSystem<F> = builder.build().unwrap();
+
+	// The witnesses are consumed by the prover, so both variants generate them in the loop.
+	for n_proofs in N_PROOFS {
+		group.throughput(Throughput::Elements(n_proofs as u64));
+
+		group.bench_function(BenchmarkId::new("sequential", n_proofs), |b| {
+			b.iter(|| {
+				let allocator = bumpalo::Bump::new();
+				for _ in 0..n_proofs {
+					prove(&constraint_system, &[], make_witness(&allocator)).unwrap();
+				}
+			})
+		});
+		group.bench_function(BenchmarkId::new("batch", n_proofs), |b| {
+			b.iter(|| {
+				let allocator = bumpalo::Bump::new();
+				constraint_system::prove_batch::<
+					U,
+					CanonicalTowerFamily,
+					Groestl256,
+					Groestl256ByteCompression,
+					HasherChallenger<Groestl256>,
+					_,
+				>(
+					&constraint_system,
+					LOG_INV_RATE,
+					SECURITY_BITS,
+					(0..n_proofs).map(|_| (&[][..], make_witness(&allocator))),
+					&backend,
+				)
+				.unwrap()
+			})
+		});
+	}
+
+	group.finish();
+}
+
+criterion_group!(prove_batch_benches, bench_prove_batch);
+criterion_main!(prove_batch_benches);
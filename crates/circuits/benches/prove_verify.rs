@@ -0,0 +1,227 @@
+// Copyright 2025 Irreducible Inc.
+
+//! End-to-end benchmarks of witness generation, proving and verification.
+//!
+//! Every circuit is measured twice: once in wall-clock time, and once in CPU time summed over all
+//! threads of the process, which shows how much work the parallel prover does in total. The CPU
+//! time is only measured on Unix.
+//!
+//! Run with `cargo bench -p binius_circuits --bench prove_verify -- --save-baseline main`.
+
+mod common;
+
+use std::hint::black_box;
+
+use binius_circuits::{
+	bitwise,
+	builder::ConstraintSystemBuilder,
+	keccakf::{keccakf, KeccakfState},
+	sha256::sha256,
+	unconstrained::unconstrained,
+};
+use binius_core::oracle::OracleId;
+use binius_field::BinaryField1b;
+use common::{build_with_witness, prove, verify};
+use criterion::{
+	criterion_group, criterion_main,
+	measurement::{Measurement, WallTime},
+	BenchmarkId, Criterion,
+};
+
+const SHA256_COMPRESSION_LOG_LEN: usize = 5;
+
+type Circuit = fn(&mut ConstraintSystemBuilder, usize) -> Result<(), anyhow::Error>;
+
+/// A single bitwise AND constraint over two unconstrained columns.
+fn trivial_circuit(
+	builder: &mut ConstraintSystemBuilder,
+	log_size: usize,
+) -> Result<(), anyhow::Error> {
+	let x = unconstrained::<BinaryField1b>(builder, "x", log_size)?;
+	let y = unconstrained::<BinaryField1b>(builder, "y", log_size)?;
+	bitwise::and(builder, "x and y", x, y)?;
+	Ok(())
+}
+
+fn sha256_circuit(
+	builder: &mut ConstraintSystemBuilder,
+	log_n_compressions: usize,
+) -> Result<(), anyhow::Error> {
+	let log_size = log_n_compressions + SHA256_COMPRESSION_LOG_LEN;
+	let input: [OracleId; 16] = array_util::try_from_fn(|i| {
+		unconstrained::<BinaryField1b>(builder, format!("input_{i}"), log_size)
+	})?;
+	sha256(builder, input, log_size)?;
+	Ok(())
+}
+
+fn keccakf_circuit(
+	builder: &mut ConstraintSystemBuilder,
+	log_n_permutations: usize,
+) -> Result<(), anyhow::Error> {
+	keccakf(builder, &Some(Vec::<KeccakfState>::new()), log_n_permutations)?;
+	Ok(())
+}
+
+/// A criterion measurement that the benchmarks can instantiate themselves, to time the prover
+/// inside `iter_custom`.
+trait BenchMeasurement: Measurement + 'static {
+	/// Appended to the benchmark group names, so that the measurements are reported separately.
+	const GROUP_SUFFIX: &'static str;
+
+	fn new() -> Self;
+}
+
+impl BenchMeasurement for WallTime {
+	const GROUP_SUFFIX: &'static str = "";
+
+	fn new() -> Self {
+		Self
+	}
+}
+
+/// CPU time consumed by all threads of the process.
+///
+/// The time is read with `clock_gettime(CLOCK_PROCESS_CPUTIME_ID)`, so it is only measured on
+/// Unix.
+#[cfg(unix)]
+mod cpu_time {
+	use std::time::Duration;
+
+	use criterion::measurement::{Measurement, ValueFormatter, WallTime};
+
+	use super::BenchMeasurement;
+
+	pub struct CpuTime;
+
+	impl BenchMeasurement for CpuTime {
+		const GROUP_SUFFIX: &'static str = "_cpu_time";
+
+		fn new() -> Self {
+			Self
+		}
+	}
+
+	static WALL_TIME: WallTime = WallTime;
+
+	fn process_cpu_time() -> Duration {
+		let mut time = libc::timespec {
+			tv_sec: 0,
+			tv_nsec: 0,
+		};
+		// SAFETY: `time` is a valid, writable `timespec`.
+		let ret = unsafe { libc::clock_gettime(libc::CLOCK_PROCESS_CPUTIME_ID, &raw mut time) };
+		assert_eq!(ret, 0, "clock_gettime(CLOCK_PROCESS_CPUTIME_ID) failed");
+		Duration::new(time.tv_sec as u64, time.tv_nsec as u32)
+	}
+
+	impl Measurement for CpuTime {
+		type Intermediate = Duration;
+		type Value = Duration;
+
+		fn start(&self) -> Self::Intermediate {
+			process_cpu_time()
+		}
+
+		fn end(&self, start: Self::Intermediate) -> Self::Value {
+			process_cpu_time() - start
+		}
+
+		fn add(&self, v1: &Self::Value, v2: &Self::Value) -> Self::Value {
+			*v1 + *v2
+		}
+
+		fn zero(&self) -> Self::Value {
+			Duration::ZERO
+		}
+
+		fn to_f64(&self, value: &Self::Value) -> f64 {
+			value.as_nanos() as f64
+		}
+
+		fn formatter(&self) -> &dyn ValueFormatter {
+			// Both measurements are in nanoseconds, so they are formatted the same way.
+			WALL_TIME.formatter()
+		}
+	}
+}
+
+/// Benchmarks witness generation, proving and verification of `circuit` separately.
+///
+/// `sizes` pairs the parameter reported by criterion with the binary logarithm passed to the
+/// circuit.
+fn bench_circuit<M: BenchMeasurement>(
+	c: &mut Criterion<M>,
+	name: &str,
+	circuit: Circuit,
+	sizes: &[(usize, usize)],
+) {
+	let mut group = c.benchmark_group(format!("{name}{}", M::GROUP_SUFFIX));
+	group.sample_size(10);
+
+	for &(size, log_size) in sizes {
+		let circuit = |builder: &mut ConstraintSystemBuilder| circuit(builder, log_size);
+
+		group.bench_function(BenchmarkId::new("witness", size), |b| {
+			b.iter(|| {
+				let allocator = bumpalo::Bump::new();
+				let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+				circuit(&mut builder).unwrap();
+				black_box(builder.take_witness().unwrap());
+			})
+		});
+
+		// The witness is consumed by the prover, so it is regenerated outside the timed region.
+		group.bench_function(BenchmarkId::new("prove", size), |b| {
+			b.iter_custom(|iters| {
+				let measurement = M::new();
+				let mut elapsed = measurement.zero();
+				for _ in 0..iters {
+					let allocator = bumpalo::Bump::new();
+					let (constraint_system, witness) =
+						build_with_witness(&allocator, circuit).unwrap();
+					let start = measurement.start();
+					black_box(prove(&constraint_system, &[], witness).unwrap());
+					elapsed = measurement.add(&elapsed, &measurement.end(start));
+				}
+				elapsed
+			})
+		});
+
+		let allocator = bumpalo::Bump::new();
+		let (constraint_system, witness) = build_with_witness(&allocator, circuit).unwrap();
+		let proof = prove(&constraint_system, &[], witness).unwrap();
+		println!("{name}/{size}: proof size {} bytes", proof.get_proof_size());
+
+		let constraint_system = constraint_system.no_base_constraints();
+		group.bench_function(BenchmarkId::new("verify", size), |b| {
+			b.iter(|| verify(&constraint_system, &[], proof.clone()).unwrap())
+		});
+	}
+
+	group.finish();
+}
+
+fn bench_trivial<M: BenchMeasurement>(c: &mut Criterion<M>) {
+	bench_circuit(c, "trivial", trivial_circuit, &[(14, 14), (16, 16), (18, 18)]);
+}
+
+fn bench_sha256<M: BenchMeasurement>(c: &mut Criterion<M>) {
+	bench_circuit(c, "sha256_compressions", sha256_circuit, &[(128, 7), (512, 9)]);
+}
+
+fn bench_keccakf<M: BenchMeasurement>(c: &mut Criterion<M>) {
+	bench_circuit(c, "keccakf_permutations", keccakf_circuit, &[(128, 7), (512, 9)]);
+}
+
+criterion_group!(prove_verify, bench_trivial, bench_sha256, bench_keccakf);
+#[cfg(unix)]
+criterion_group! {
+	name = prove_verify_cpu_time;
+	config = Criterion::default().with_measurement(cpu_time::CpuTime);
+	targets = bench_trivial, bench_sha256, bench_keccakf
+}
+#[cfg(unix)]
+criterion_main!(prove_verify, prove_verify_cpu_time);
+#[cfg(not(unix))]
+criterion_main!(prove_verify);
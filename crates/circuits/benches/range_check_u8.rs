@@ -0,0 +1,107 @@
+// Copyright 2025 Irreducible Inc.
+
+mod common;
+
+use binius_circuits::{
+	builder::{types::F, ConstraintSystemBuilder},
+	lookup::range_check_u8_table,
+};
+use binius_core::oracle::OracleId;
+use binius_field::{
+	packed::set_packed_slice, BinaryField1b, BinaryField32b, ExtensionField, Field, TowerField,
+};
+use binius_math::ArithExpr;
+use common::{build_with_witness, prove, time_prove};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Large enough for every row of the largest column to look up the same table entry.
+const LOG_MAX_MULTIPLICITY: usize = 17;
+
+const LOG_ROWS: [usize; 2] = [12, 16];
+
+type RangeCheck = fn(&mut ConstraintSystemBuilder, OracleId) -> Result<(), anyhow::Error>;
+
+fn table(builder: &mut ConstraintSystemBuilder, value: OracleId) -> Result<(), anyhow::Error> {
+	range_check_u8_table::<LOG_MAX_MULTIPLICITY>(builder, "range_check", value)
+}
+
+/// Commits the 8 bits of every value, and checks that they recompose the value.
+fn bit_decomposition(
+	builder: &mut ConstraintSystemBuilder,
+	value: OracleId,
+) -> Result<(), anyhow::Error> {
+	let log_rows = builder.log_rows([value])?;
+	let bits = builder.add_committed_multiple::<8>("bits", log_rows, BinaryField1b::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let values = witness.get::<BinaryField32b>(value)?.as_slice::<u32>();
+		for (i, bit) in bits.into_iter().enumerate() {
+			let mut column = witness.new_column::<BinaryField1b>(bit);
+			let column = column.packed();
+			for (j, value) in values.iter().enumerate() {
+				if (value >> i) & 1 == 1 {
+					set_packed_slice(column, j, BinaryField1b::ONE);
+				}
+			}
+		}
+	}
+
+	let recomposition = (0..8).fold(ArithExpr::Var(0), |acc, i| {
+		let basis = <BinaryField32b as ExtensionField<BinaryField1b>>::basis(i);
+		acc + ArithExpr::Const(F::from(basis)) * ArithExpr::Var(i + 1)
+	});
+	builder.assert_zero("recomposition", std::iter::once(value).chain(bits), recomposition);
+	Ok(())
+}
+
+/// Commits a column of byte values and range checks it with `range_check`.
+fn range_check_column(
+	builder: &mut ConstraintSystemBuilder,
+	range_check: RangeCheck,
+	log_rows: usize,
+) -> Result<(), anyhow::Error> {
+	let value = builder.add_committed("value", log_rows, BinaryField32b::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		for (i, value) in witness
+			.new_column::<BinaryField32b>(value)
+			.as_mut_slice::<u32>()
+			.iter_mut()
+			.enumerate()
+		{
+			*value = i as u32 % 256;
+		}
+	}
+	range_check(builder, value)
+}
+
+fn bench_range_check_u8(c: &mut Criterion) {
+	let mut group = c.benchmark_group("range_check_u8");
+	group.sample_size(10);
+
+	for log_rows in LOG_ROWS {
+		for (name, range_check) in [
+			("table", table as RangeCheck),
+			("bit_decomposition", bit_decomposition as RangeCheck),
+		] {
+			let circuit = |builder: &mut ConstraintSystemBuilder| {
+				range_check_column(builder, range_check, log_rows)
+			};
+
+			let allocator = bumpalo::Bump::new();
+			let (constraint_system, witness) = build_with_witness(&allocator, circuit).unwrap();
+			let proof_size = prove(&constraint_system, &[], witness)
+				.unwrap()
+				.get_proof_size();
+			println!("range_check_u8/{name}/{log_rows}: proof size {proof_size} bytes");
+
+			group.bench_function(BenchmarkId::new(name, log_rows), |b| {
+				b.iter_custom(|iters| time_prove(iters, circuit))
+			});
+		}
+	}
+
+	group.finish();
+}
+
+criterion_group!(range_check_u8, bench_range_check_u8);
+criterion_main!(range_check_u8);
@@ -0,0 +1,75 @@
+// Copyright 2025 Irreducible Inc.
+
+mod common;
+
+use binius_circuits::{
+	arithmetic::{self, Flags},
+	builder::ConstraintSystemBuilder,
+	unconstrained::unconstrained,
+};
+use binius_core::oracle::OracleId;
+use binius_field::BinaryField1b;
+use common::time_prove;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Number of u32 additions per input column is `2^(LOG_SIZE - 5)`.
+const LOG_SIZE: usize = 14;
+
+const N_INPUTS: [usize; 3] = [4, 8, 16];
+
+type Summation = fn(&mut ConstraintSystemBuilder, &[OracleId]) -> Result<OracleId, anyhow::Error>;
+
+fn add_tree(
+	builder: &mut ConstraintSystemBuilder,
+	inputs: &[OracleId],
+) -> Result<OracleId, anyhow::Error> {
+	arithmetic::u32::add_chain(builder, "sum", inputs, Flags::Unchecked)
+}
+
+fn add_sequential(
+	builder: &mut ConstraintSystemBuilder,
+	inputs: &[OracleId],
+) -> Result<OracleId, anyhow::Error> {
+	inputs[1..]
+		.iter()
+		.enumerate()
+		.try_fold(inputs[0], |acc, (i, &input)| {
+			arithmetic::u32::add(builder, format!("add_{i}"), acc, input, Flags::Unchecked)
+		})
+}
+
+/// Sums `n_inputs` unconstrained columns with `summation`.
+fn add_inputs(
+	builder: &mut ConstraintSystemBuilder,
+	summation: Summation,
+	n_inputs: usize,
+) -> Result<(), anyhow::Error> {
+	let inputs = (0..n_inputs)
+		.map(|i| unconstrained::<BinaryField1b>(builder, format!("input_{i}"), LOG_SIZE))
+		.collect::<Result<Vec<_>, _>>()?;
+	summation(builder, &inputs)?;
+	Ok(())
+}
+
+fn bench_u32_add_chain(c: &mut Criterion) {
+	let mut group = c.benchmark_group("u32_add_chain");
+	group.sample_size(10);
+
+	for n_inputs in N_INPUTS {
+		for (name, summation) in [
+			("tree", add_tree as Summation),
+			("sequential", add_sequential as Summation),
+		] {
+			group.bench_function(BenchmarkId::new(name, n_inputs), |b| {
+				b.iter_custom(|iters| {
+					time_prove(iters, |builder| add_inputs(builder, summation, n_inputs))
+				})
+			});
+		}
+	}
+
+	group.finish();
+}
+
+criterion_group!(u32_add_chain, bench_u32_add_chain);
+criterion_main!(u32_add_chain);
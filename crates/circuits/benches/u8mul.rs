@@ -0,0 +1,91 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Compares proving u8 multiplication checked with a plain lookup into the full product table
+//! against the Lasso-based gadget.
+
+mod common;
+
+use binius_circuits::{
+	builder::ConstraintSystemBuilder,
+	lasso::{self, batch::LookupBatch, lookups::u8_arithmetic::mul_lookup},
+	u8mul::u8mul,
+	unconstrained::unconstrained,
+};
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField32b, BinaryField8b};
+use common::{build_with_witness, prove, time_prove};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+/// Large enough for every row of the largest column to look up the same table entry.
+const LOG_MAX_MULTIPLICITY: usize = 17;
+
+const LOG_ROWS: [usize; 2] = [12, 16];
+
+type Multiplier =
+	fn(&mut ConstraintSystemBuilder, OracleId, OracleId, usize) -> Result<(), anyhow::Error>;
+
+fn plain_lookup(
+	builder: &mut ConstraintSystemBuilder,
+	a: OracleId,
+	b: OracleId,
+	_log_rows: usize,
+) -> Result<(), anyhow::Error> {
+	u8mul::<LOG_MAX_MULTIPLICITY>(builder, "a * b", a, b)?;
+	Ok(())
+}
+
+fn lasso(
+	builder: &mut ConstraintSystemBuilder,
+	a: OracleId,
+	b: OracleId,
+	log_rows: usize,
+) -> Result<(), anyhow::Error> {
+	let mul_table = mul_lookup(builder, "mul table")?;
+	let mut lookup_batch = LookupBatch::new([mul_table]);
+	lasso::u8mul(builder, &mut lookup_batch, "a * b", a, b, 1 << log_rows)?;
+	lookup_batch.execute::<BinaryField32b>(builder)
+}
+
+/// Multiplies two unconstrained columns of `2^log_rows` bytes with `multiplier`.
+fn multiply_columns(
+	builder: &mut ConstraintSystemBuilder,
+	multiplier: Multiplier,
+	log_rows: usize,
+) -> Result<(), anyhow::Error> {
+	let a = unconstrained::<BinaryField8b>(builder, "a", log_rows)?;
+	let b = unconstrained::<BinaryField8b>(builder, "b", log_rows)?;
+	multiplier(builder, a, b, log_rows)
+}
+
+fn bench_u8mul(c: &mut Criterion) {
+	let mut group = c.benchmark_group("u8mul");
+	group.sample_size(10);
+
+	for log_rows in LOG_ROWS {
+		group.throughput(Throughput::Elements(1 << log_rows));
+		for (name, multiplier) in [
+			("plain_lookup", plain_lookup as Multiplier),
+			("lasso", lasso as Multiplier),
+		] {
+			let circuit = |builder: &mut ConstraintSystemBuilder| {
+				multiply_columns(builder, multiplier, log_rows)
+			};
+
+			let allocator = bumpalo::Bump::new();
+			let (constraint_system, witness) = build_with_witness(&allocator, circuit).unwrap();
+			let proof_size = prove(&constraint_system, &[], witness)
+				.unwrap()
+				.get_proof_size();
+			println!("u8mul/{name}/{log_rows}: proof size {proof_size} bytes");
+
+			group.bench_function(BenchmarkId::new(name, log_rows), |b| {
+				b.iter_custom(|iters| time_prove(iters, circuit))
+			});
+		}
+	}
+
+	group.finish();
+}
+
+criterion_group!(u8mul_benches, bench_u8mul);
+criterion_main!(u8mul_benches);
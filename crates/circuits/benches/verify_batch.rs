@@ -0,0 +1,94 @@
+// Copyright 2025 Irreducible Inc.
+
+mod common;
+
+use binius_circuits::{
+	arithmetic,
+	builder::{
+		test_utils::{LOG_INV_RATE, SECURITY_BITS},
+		types::U,
+		ConstraintSystemBuilder,
+	},
+	unconstrained::unconstrained,
+};
+use binius_core::{
+	constraint_system::{self, ConstraintSystem, Proof},
+	fiat_shamir::HasherChallenger,
+	tower::CanonicalTowerFamily,
+};
+use binius_field::{BinaryField128b, BinaryField1b};
+use binius_hal::make_portable_backend;
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use common::verify;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+const LOG_SIZE: usize = 10;
+
+const N_PROOFS: [usize; 2] = [10, 100];
+
+fn build_circuit(builder: &mut ConstraintSystemBuilder) -> Result<(), anyhow::Error> {
+	let x = unconstrained::<BinaryField1b>(builder, "x", LOG_SIZE)?;
+	let y = unconstrained::<BinaryField1b>(builder, "y", LOG_SIZE)?;
+	arithmetic::u32::add(builder, "x + y", x, y, arithmetic::Flags::Unchecked)?;
+	Ok(())
+}
+
+fn prove() -> Proof {
+	let allocator = bumpalo::Bump::new();
+	let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+	build_circuit(&mut builder).unwrap();
+	builder
+		.prove_and_verify::<Groestl256, Groestl256ByteCompression, HasherChallenger<Groestl256>, _>(
+			LOG_INV_RATE,
+			SECURITY_BITS,
+			vec![],
+			&make_portable_backend(),
+		)
+		.unwrap()
+}
+
+fn verify_batch(constraint_system: &ConstraintSystem<BinaryField128b>, proofs: &[Proof]) {
+	constraint_system::verify_batch::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(
+		constraint_system,
+		LOG_INV_RATE,
+		SECURITY_BITS,
+		proofs.iter().map(|proof| (&[][..], proof.clone())),
+	)
+	.unwrap()
+}
+
+fn bench_verify_batch(c: &mut Criterion) {
+	let mut group = c.benchmark_group("verify_batch");
+	group.sample_size(10);
+
+	let mut builder = ConstraintSystemBuilder::new();
+	build_circuit(&mut builder).unwrap();
+	let constraint_system = builder.build().unwrap();
+
+	for n_proofs in N_PROOFS {
+		let proofs = (0..n_proofs).map(|_| prove()).collect::<Vec<_>>();
+		group.throughput(Throughput::Elements(n_proofs as u64));
+
+		group.bench_function(BenchmarkId::new("sequential", n_proofs), |b| {
+			b.iter(|| {
+				for proof in &proofs {
+					verify(&constraint_system, &[], proof.clone()).unwrap();
+				}
+			})
+		});
+		group.bench_function(BenchmarkId::new("batch", n_proofs), |b| {
+			b.iter(|| verify_batch(&constraint_system, &proofs))
+		});
+	}
+
+	group.finish();
+}
+
+criterion_group!(verify_batch_benches, bench_verify_batch);
+criterion_main!(verify_batch_benches);
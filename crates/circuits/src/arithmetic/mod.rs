@@ -1,8 +1,11 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use binius_core::oracle::OracleId;
+
 pub mod mul;
 pub mod static_exp;
 pub mod u32;
+pub mod u64;
 
 /// Whether to allow or disallow arithmetic overflow
 #[derive(Debug, Clone, Copy)]
@@ -10,3 +13,16 @@ pub enum Flags {
 	Checked,
 	Unchecked,
 }
+
+/// A bit column that is set on the rows where an addition overflowed.
+///
+/// The column has one bit per u32 row of the addition. With [`Flags::Checked`] it is constrained
+/// to be zero, with [`Flags::Unchecked`] it can be used as a boolean input to other gadgets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverflowFlag(pub OracleId);
+
+impl From<OverflowFlag> for OracleId {
+	fn from(flag: OverflowFlag) -> Self {
+		flag.0
+	}
+}
@@ -1,16 +1,20 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use binius_core::{
+	constraint_system::channel::OracleOrConst,
 	oracle::{OracleId, ShiftVariant},
 	transparent::MultilinearExtensionTransparent,
 };
 use binius_field::{
-	as_packed_field::PackedType, packed::set_packed_slice, underlier::WithUnderlier, BinaryField1b,
-	BinaryField32b, Field, PackedField, TowerField,
+	as_packed_field::PackedType,
+	packed::{get_packed_slice, set_packed_slice},
+	underlier::WithUnderlier,
+	BinaryField1b, BinaryField32b, Field, PackedField, TowerField,
 };
 use binius_macros::arith_expr;
+use binius_math::ArithExpr;
 use binius_maybe_rayon::prelude::*;
-use binius_utils::checked_arithmetics::checked_log_2;
+use binius_utils::checked_arithmetics::{checked_log_2, log2_ceil_usize};
 use bytemuck::{pod_collect_to_vec, Pod};
 
 use crate::{
@@ -18,6 +22,7 @@ use crate::{
 		types::{F, U},
 		ConstraintSystemBuilder,
 	},
+	mux::mux_constraints,
 	transparent,
 };
 
@@ -92,6 +97,220 @@ pub fn add(
 	yin: OracleId,
 	flags: super::Flags,
 ) -> Result<OracleId, anyhow::Error> {
+	let (zout, _cout) = add_with_carry(builder, name, xin, yin, flags)?;
+	Ok(zout)
+}
+
+/// Adds two u32 columns, and returns the sum together with its [`super::OverflowFlag`].
+///
+/// This is [`add`] with the carry out of the most significant bit exposed as a bit column. With
+/// [`super::Flags::Checked`], the overflow flag is constrained to be zero.
+pub fn add_with_overflow(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	flags: super::Flags,
+) -> Result<(OracleId, super::OverflowFlag), anyhow::Error> {
+	builder.push_namespace(name);
+	let (zout, cout) = add_with_carry(builder, "add", xin, yin, super::Flags::Unchecked)?;
+	let overflow = select_bit(builder, "overflow", cout, 31)?;
+	if matches!(flags, super::Flags::Checked) {
+		builder.assert_zero(
+			"overflow",
+			[overflow],
+			arith_expr!([overflow] = overflow).convert_field(),
+		);
+	}
+	builder.pop_namespace();
+	Ok((zout, super::OverflowFlag(overflow)))
+}
+
+/// Adds all `inputs` using a balanced binary tree of [`add`] gadgets.
+///
+/// For `n` inputs this creates `n - 1` adders, arranged in `ceil(log2(n))` levels. With
+/// [`super::Flags::Checked`], the circuit fails if the total sum overflows.
+pub fn add_chain(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	inputs: &[OracleId],
+	flags: super::Flags,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let (sum, _carries) = add_tree(builder, inputs, flags)?;
+	builder.pop_namespace();
+	Ok(sum)
+}
+
+/// Adds all `inputs` modulo 2^32, using a balanced binary tree of [`add`] gadgets.
+///
+/// This is [`add_chain`] with wrapping semantics, so that the result equals folding the inputs
+/// with [`u32::wrapping_add`].
+pub fn add_many(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	inputs: &[OracleId],
+) -> Result<OracleId, anyhow::Error> {
+	add_chain(builder, name, inputs, super::Flags::Unchecked)
+}
+
+/// Adds a u32 column holding the counter `initial, initial + 1, ..., initial + n_steps - 1`,
+/// wrapping modulo 2^32.
+///
+/// Every transition is checked through a channel: each row pushes `(count, count + 1)` and pulls
+/// `(count, next)`, where `next` is the column shifted by one row. The channel balances only if
+/// every `next` equals `count + 1`. The first row is constrained to be `initial`. Rows past
+/// `n_steps`, which pad the column to a power of two, are unconstrained.
+pub fn wrapping_add_counter(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	initial: u32,
+	n_steps: usize,
+) -> Result<OracleId, anyhow::Error> {
+	anyhow::ensure!(n_steps > 0, "The counter must have at least one step");
+	builder.push_namespace(name);
+	let log_count = log2_ceil_usize(n_steps).max(PackedType::<U, B1>::LOG_WIDTH - LOG_U32_BITS);
+	let log_size = log_count + LOG_U32_BITS;
+
+	let count = builder.add_committed("count", log_size, B1::TOWER_LEVEL);
+	let next = builder.add_shifted("next", count, 32, log_size, ShiftVariant::LogicalRight)?;
+	if let Some(witness) = builder.witness() {
+		let mut count = witness.new_column::<B1>(count);
+		let count = count.as_mut_slice::<u32>();
+		count
+			.par_iter_mut()
+			.enumerate()
+			.for_each(|(i, count)| *count = initial.wrapping_add(i as u32));
+		let mut next = witness.new_column::<B1>(next);
+		let next = next.as_mut_slice::<u32>();
+		(&mut next[..count.len() - 1], &count[1..])
+			.into_par_iter()
+			.for_each(|(next, count)| *next = *count);
+	}
+
+	let one = constant(builder, "one", log_count, 1)?;
+	let incremented = add(builder, "incremented", count, one, super::Flags::Unchecked)?;
+
+	let count_packed = packed(builder, "count_packed", count)?;
+	let incremented_packed = packed(builder, "incremented_packed", incremented)?;
+	let next_packed = packed(builder, "next_packed", next)?;
+
+	let channel = builder.add_channel();
+	builder.send(
+		channel,
+		n_steps - 1,
+		[
+			OracleOrConst::Oracle(count_packed),
+			OracleOrConst::Oracle(incremented_packed),
+		],
+	)?;
+	builder.receive(
+		channel,
+		n_steps - 1,
+		[
+			OracleOrConst::Oracle(count_packed),
+			OracleOrConst::Oracle(next_packed),
+		],
+	)?;
+
+	let first = transparent::step_down(builder, "first", log_count, 1)?;
+	builder.assert_zero(
+		"initial",
+		[count_packed, first],
+		(ArithExpr::Var(0) - ArithExpr::Const(F::from(B32::new(initial)))) * ArithExpr::Var(1),
+	);
+
+	builder.pop_namespace();
+	Ok(count)
+}
+
+/// Adds all `inputs` modulo 2^32, and counts how many times the addition overflowed.
+///
+/// Returns the wrapped sum and the overflow counter, i.e. the total sum divided by 2^32, both as
+/// u32 columns.
+pub fn sum_with_overflow_flag(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	inputs: &[OracleId],
+) -> Result<(OracleId, OracleId), anyhow::Error> {
+	builder.push_namespace(name);
+	let (sum, carries) = add_tree(builder, inputs, super::Flags::Unchecked)?;
+
+	// Move the carry out of the most significant bit of each adder into the least significant
+	// bit of a u32, so that the carries can be summed up.
+	let overflows = carries
+		.into_iter()
+		.enumerate()
+		.map(|(i, cout)| {
+			let overflow = builder.add_shifted(
+				format!("overflow_{i}"),
+				cout,
+				31,
+				5,
+				ShiftVariant::LogicalRight,
+			)?;
+			if let Some(witness) = builder.witness() {
+				(
+					witness.get::<B1>(cout)?.as_slice::<u32>(),
+					witness.new_column::<B1>(overflow).as_mut_slice::<u32>(),
+				)
+					.into_par_iter()
+					.for_each(|(cout, overflow)| *overflow = *cout >> 31);
+			}
+			Ok(overflow)
+		})
+		.collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+	let overflow_count = if overflows.is_empty() {
+		let log_rows = builder.log_rows(inputs.iter().copied())?;
+		constant(builder, "overflow_count", log_rows - 5, 0)?
+	} else {
+		// The counter is at most `inputs.len() - 1`, so summing it never overflows.
+		add_chain(builder, "overflow_count", &overflows, super::Flags::Unchecked)?
+	};
+	builder.pop_namespace();
+	Ok((sum, overflow_count))
+}
+
+/// Adds `inputs` pairwise, level by level. Returns the sum and the carry columns of all adders.
+fn add_tree(
+	builder: &mut ConstraintSystemBuilder,
+	inputs: &[OracleId],
+	flags: super::Flags,
+) -> Result<(OracleId, Vec<OracleId>), anyhow::Error> {
+	anyhow::ensure!(!inputs.is_empty(), "At least one input is required");
+	builder.log_rows(inputs.iter().copied())?;
+
+	let mut level = inputs.to_vec();
+	let mut carries = Vec::with_capacity(inputs.len() - 1);
+	let mut depth = 0;
+	while level.len() > 1 {
+		let mut next_level = Vec::with_capacity(level.len().div_ceil(2));
+		for (i, pair) in level.chunks(2).enumerate() {
+			match *pair {
+				[x, y] => {
+					let (z, cout) =
+						add_with_carry(builder, format!("add_{depth}_{i}"), x, y, flags)?;
+					carries.push(cout);
+					next_level.push(z);
+				}
+				[x] => next_level.push(x),
+				_ => unreachable!("chunks of at most two elements"),
+			}
+		}
+		level = next_level;
+		depth += 1;
+	}
+	Ok((level[0], carries))
+}
+
+fn add_with_carry(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	flags: super::Flags,
+) -> Result<(OracleId, OracleId), anyhow::Error> {
 	builder.push_namespace(name);
 	let log_rows = builder.log_rows([xin, yin])?;
 	let cout = builder.add_committed("cout", log_rows, B1::TOWER_LEVEL);
@@ -138,7 +357,7 @@ pub fn add(
 	}
 
 	builder.pop_namespace();
-	Ok(zout)
+	Ok((zout, cout))
 }
 
 pub fn sub(
@@ -148,11 +367,143 @@ pub fn sub(
 	yin: OracleId,
 	flags: super::Flags,
 ) -> Result<OracleId, anyhow::Error> {
+	let (xout, _cout) = sub_with_borrow(builder, name, zin, yin, flags, true)?;
+	Ok(xout)
+}
+
+/// Adds two u32 columns, clamping the result at `u32::MAX` instead of wrapping.
+pub fn saturating_add(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let (sum, overflow) = add_with_overflow(builder, "add", xin, yin, super::Flags::Unchecked)?;
+	let zout = select_if(builder, "zout", overflow.into(), u32::MAX, sum)?;
+	builder.pop_namespace();
+	Ok(zout)
+}
+
+/// Subtracts `yin` from `xin`, clamping the result at 0 instead of wrapping.
+pub fn saturating_sub(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let (diff, cout) = sub_with_borrow(builder, "sub", xin, yin, super::Flags::Unchecked, false)?;
+	let borrow = select_bit(builder, "borrow", cout, 31)?;
+	let zout = select_if(builder, "zout", borrow, 0, diff)?;
+	builder.pop_namespace();
+	Ok(zout)
+}
+
+/// Returns a u32 column that is `value` on the rows where the bit column `flag` is set, and equal
+/// to `otherwise` elsewhere.
+///
+/// This is [`mux`](crate::mux::mux) between a constant and a column of u32 values, which are
+/// selected as a whole rather than bit by bit.
+fn select_if(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	flag: OracleId,
+	value: u32,
+	otherwise: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	let log_rows = builder.log_rows([otherwise])?;
+	let zout = builder.add_committed(name, log_rows, B1::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		let flag = witness.get::<B1>(flag)?;
+		let flag = flag.packed();
+		(
+			witness.get::<B1>(otherwise)?.as_slice::<u32>(),
+			witness.new_column::<B1>(zout).as_mut_slice::<u32>(),
+		)
+			.into_par_iter()
+			.enumerate()
+			.for_each(|(i, (otherwise, zout))| {
+				*zout = if get_packed_slice(flag, i) == B1::ONE {
+					value
+				} else {
+					*otherwise
+				};
+			});
+	}
+
+	// The selection is constrained on the packed u32 values, with a constant column of `value`.
+	let zout_packed = builder.add_packed("zout_packed", zout, 5)?;
+	let otherwise_packed = builder.add_packed("otherwise_packed", otherwise, 5)?;
+	let value = builder.add_transparent_constant("value", log_rows - 5, B32::new(value))?;
+	if let Some(witness) = builder.witness() {
+		witness.set(zout_packed, witness.get::<B1>(zout)?.repacked::<B32>())?;
+		witness.set(otherwise_packed, witness.get::<B1>(otherwise)?.repacked::<B32>())?;
+	}
+	mux_constraints(builder, flag, value, otherwise_packed, zout_packed);
+	Ok(zout)
+}
+
+/// Compares two u32 columns row by row.
+///
+/// Returns a bit column with one bit per u32 row, which is set on the rows where
+/// `xin <= yin`.
+pub fn le(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([xin, yin])?;
+	let (_diff, cout) = sub_with_borrow(builder, "sub", yin, xin, super::Flags::Unchecked, false)?;
+	let borrow = select_bit(builder, "borrow", cout, 31)?;
+	let le = builder.add_linear_combination_with_offset(
+		"le",
+		log_rows - 5,
+		F::ONE,
+		[(borrow, F::ONE)],
+	)?;
+
+	if let Some(witness) = builder.witness() {
+		let borrow = witness.get::<B1>(borrow)?;
+		let borrow = borrow.packed();
+		let mut le = witness.new_column::<B1>(le);
+		let le = le.packed();
+		for i in 0..1 << (log_rows - 5) {
+			set_packed_slice(le, i, get_packed_slice(borrow, i) + B1::ONE);
+		}
+	}
+
+	builder.pop_namespace();
+	Ok(le)
+}
+
+/// Subtracts `yin` from `zin`, and returns the difference together with the committed borrows out
+/// of every bit.
+///
+/// The borrow out of the most significant bit of a value is set iff the subtraction underflows.
+/// The difference is a linear combination of the operands and the borrows, so it is only
+/// committed if `committed` is set, which allows it to be used where a committed oracle is
+/// required. Only [`sub`] commits it: [`le`] only needs the borrows, and [`saturating_sub`] and
+/// [`u32sub`](crate::u32sub::u32sub) save a commitment by using the virtual difference.
+pub(crate) fn sub_with_borrow(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	zin: OracleId,
+	yin: OracleId,
+	flags: super::Flags,
+	committed: bool,
+) -> Result<(OracleId, OracleId), anyhow::Error> {
 	builder.push_namespace(name);
 	let log_rows = builder.log_rows([zin, yin])?;
 	let cout = builder.add_committed("cout", log_rows, B1::TOWER_LEVEL);
 	let cin = builder.add_shifted("cin", cout, 1, 5, ShiftVariant::LogicalLeft)?;
-	let xout = builder.add_committed("xin", log_rows, B1::TOWER_LEVEL);
+	let xout = if committed {
+		builder.add_committed("xin", log_rows, B1::TOWER_LEVEL)
+	} else {
+		builder.add_linear_combination("xin", log_rows, [zin, yin, cin].map(|id| (id, F::ONE)))?
+	};
 
 	if let Some(witness) = builder.witness() {
 		(
@@ -171,11 +522,13 @@ pub fn sub(
 			});
 	}
 
-	builder.assert_zero(
-		"sum",
-		[xout, yin, cin, zin],
-		arith_expr!([xout, yin, cin, zin] = xout + yin + cin - zin).convert_field(),
-	);
+	if committed {
+		builder.assert_zero(
+			"sum",
+			[xout, yin, cin, zin],
+			arith_expr!([xout, yin, cin, zin] = xout + yin + cin - zin).convert_field(),
+		);
+	}
 
 	builder.assert_zero(
 		"carry",
@@ -195,7 +548,7 @@ pub fn sub(
 	}
 
 	builder.pop_namespace();
-	Ok(xout)
+	Ok((xout, cout))
 }
 
 pub fn half(
@@ -258,6 +611,50 @@ pub fn shr(
 	Ok(shifted)
 }
 
+/// Rotates every u32 word of `input` left by `amount` bits.
+pub fn rotate_left(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+	amount: usize,
+) -> Result<OracleId, anyhow::Error> {
+	rotate(builder, name, input, amount % 32)
+}
+
+/// Rotates every u32 word of `input` right by `amount` bits.
+pub fn rotate_right(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+	amount: usize,
+) -> Result<OracleId, anyhow::Error> {
+	rotate(builder, name, input, (32 - amount % 32) % 32)
+}
+
+// Circular shifts only go left, so a right rotation is a left rotation by the complement.
+fn rotate(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+	left_amount: usize,
+) -> Result<OracleId, anyhow::Error> {
+	if left_amount == 0 {
+		return Ok(input);
+	}
+
+	let rotated = builder.add_shifted(name, input, left_amount, 5, ShiftVariant::CircularLeft)?;
+	if let Some(witness) = builder.witness() {
+		(
+			witness.new_column::<B1>(rotated).as_mut_slice::<u32>(),
+			witness.get::<B1>(input)?.as_slice::<u32>(),
+		)
+			.into_par_iter()
+			.for_each(|(rotated, input)| *rotated = input.rotate_left(left_amount as u32));
+	}
+
+	Ok(rotated)
+}
+
 pub fn select_bit(
 	builder: &mut ConstraintSystemBuilder,
 	name: impl ToString,
@@ -268,7 +665,7 @@ pub fn select_bit(
 	anyhow::ensure!(log_rows >= 5, "Polynomial must have n_vars >= 5. Got {log_rows}");
 	anyhow::ensure!(index < 32, "Only index values between 0 and 32 are allowed. Got {index}");
 
-	let query = binius_core::polynomial::test_utils::decompose_index_to_hypercube_point(5, index);
+	let query = binius_core::polynomial::decompose_index_to_hypercube_point(5, index);
 	let bits = builder.add_projected(name, input, query, 0)?;
 
 	if let Some(witness) = builder.witness() {
@@ -367,9 +764,45 @@ pub fn u32const_repeating(
 
 #[cfg(test)]
 mod tests {
-	use binius_field::{BinaryField1b, TowerField};
+	use binius_core::{constraint_system::channel::Boundary, oracle::OracleId};
+	use binius_field::{packed::get_packed_slice, BinaryField1b, Field, TowerField};
+
+	use crate::{
+		arithmetic,
+		builder::{
+			test_utils::{test_circuit, test_operands},
+			types::F,
+			ConstraintSystemBuilder,
+		},
+		unconstrained::{fixed_u32, unconstrained},
+	};
 
-	use crate::{arithmetic, builder::test_utils::test_circuit, unconstrained::unconstrained};
+	#[test]
+	fn test_wrapping_add_counter() {
+		test_circuit(|builder| {
+			let initial = u32::MAX - 5;
+			let counter = arithmetic::u32::wrapping_add_counter(builder, "counter", initial, 100)?;
+			if let Some(witness) = builder.witness() {
+				let values = witness.get::<BinaryField1b>(counter)?.as_slice::<u32>();
+				assert_eq!(
+					values[..8],
+					[
+						initial,
+						initial + 1,
+						initial + 2,
+						initial + 3,
+						initial + 4,
+						u32::MAX,
+						0,
+						1
+					]
+				);
+				assert_eq!(values[99], 93);
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
 
 	#[test]
 	fn test_mul_const() {
@@ -400,6 +833,160 @@ mod tests {
 		.unwrap();
 	}
 
+	/// Sums `n_inputs` pseudo-random columns with `sum_gadget` and checks the wrapping sum.
+	fn add_many_circuit(
+		builder: &mut ConstraintSystemBuilder,
+		n_inputs: usize,
+		sum_gadget: impl FnOnce(
+			&mut ConstraintSystemBuilder,
+			&[OracleId],
+		) -> Result<OracleId, anyhow::Error>,
+	) -> Result<Vec<Boundary<F>>, anyhow::Error> {
+		let log_size = 10;
+		let inputs = (0..n_inputs)
+			.map(|i| unconstrained::<BinaryField1b>(builder, format!("input_{i}"), log_size))
+			.collect::<Result<Vec<_>, _>>()?;
+		let sum = sum_gadget(builder, &inputs)?;
+		if let Some(witness) = builder.witness() {
+			let inputs = inputs
+				.iter()
+				.map(|&id| Ok(witness.get::<BinaryField1b>(id)?.as_slice::<u32>()))
+				.collect::<Result<Vec<_>, anyhow::Error>>()?;
+			let sum = witness.get::<BinaryField1b>(sum)?.as_slice::<u32>();
+			for (row, &sum) in sum.iter().enumerate() {
+				let expected = inputs
+					.iter()
+					.fold(0u32, |acc, input| acc.wrapping_add(input[row]));
+				assert_eq!(sum, expected);
+			}
+		}
+		Ok(vec![])
+	}
+
+	#[test]
+	fn test_add_many() {
+		fn add_many(
+			builder: &mut ConstraintSystemBuilder,
+			inputs: &[OracleId],
+		) -> Result<OracleId, anyhow::Error> {
+			arithmetic::u32::add_many(builder, "sum", inputs)
+		}
+
+		test_circuit(|builder| add_many_circuit(builder, 3, add_many)).unwrap();
+		test_circuit(|builder| add_many_circuit(builder, 4, add_many)).unwrap();
+		test_circuit(|builder| add_many_circuit(builder, 8, add_many)).unwrap();
+		test_circuit(|builder| add_many_circuit(builder, 16, add_many)).unwrap();
+	}
+
+	#[test]
+	fn test_add_chain() {
+		test_circuit(|builder| {
+			add_many_circuit(builder, 5, |builder, inputs| {
+				arithmetic::u32::add_chain(builder, "sum", inputs, arithmetic::Flags::Unchecked)
+			})
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_sum_with_overflow_flag() {
+		test_circuit(|builder| {
+			let log_size = 10;
+			let inputs = (0..7)
+				.map(|i| unconstrained::<BinaryField1b>(builder, format!("input_{i}"), log_size))
+				.collect::<Result<Vec<_>, _>>()?;
+			let (sum, overflow_count) =
+				arithmetic::u32::sum_with_overflow_flag(builder, "sum", &inputs)?;
+			if let Some(witness) = builder.witness() {
+				let inputs = inputs
+					.iter()
+					.map(|&id| Ok(witness.get::<BinaryField1b>(id)?.as_slice::<u32>()))
+					.collect::<Result<Vec<_>, anyhow::Error>>()?;
+				let sum = witness.get::<BinaryField1b>(sum)?.as_slice::<u32>();
+				let overflow_count = witness
+					.get::<BinaryField1b>(overflow_count)?
+					.as_slice::<u32>();
+				for row in 0..sum.len() {
+					let total = inputs.iter().map(|input| input[row] as u64).sum::<u64>();
+					assert_eq!(sum[row], total as u32);
+					assert_eq!(overflow_count[row] as u64, total >> 32);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_add_chain_single_input() {
+		test_circuit(|builder| {
+			let a = unconstrained::<BinaryField1b>(builder, "a", 7)?;
+			let sum = arithmetic::u32::add_chain(builder, "sum", &[a], arithmetic::Flags::Checked)?;
+			assert_eq!(sum, a);
+			assert!(arithmetic::u32::add_chain(builder, "empty", &[], arithmetic::Flags::Checked)
+				.is_err());
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_add_overflow_flag() {
+		test_circuit(|builder| {
+			let log_size = 10;
+			let a = unconstrained::<BinaryField1b>(builder, "a", log_size)?;
+			let b = unconstrained::<BinaryField1b>(builder, "b", log_size)?;
+			let (_c, overflow) = arithmetic::u32::add_with_overflow(
+				builder,
+				"add",
+				a,
+				b,
+				arithmetic::Flags::Unchecked,
+			)?;
+			if let Some(witness) = builder.witness() {
+				let a = witness.get::<BinaryField1b>(a)?.as_slice::<u32>();
+				let b = witness.get::<BinaryField1b>(b)?.as_slice::<u32>();
+				let overflow = witness.get::<BinaryField1b>(overflow.into())?;
+				for (i, (a, b)) in a.iter().zip(b).enumerate() {
+					let expected = if a.overflowing_add(*b).1 {
+						BinaryField1b::ONE
+					} else {
+						BinaryField1b::ZERO
+					};
+					assert_eq!(get_packed_slice(overflow.packed(), i), expected);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_rotate() {
+		test_circuit(|builder| {
+			let word = arithmetic::u32::constant(builder, "word", 5, 0x12345678)?;
+			for (amount, left, right) in [
+				(7, 0x1a2b3c09, 0xf02468ac),
+				(8, 0x34567812, 0x78123456),
+				(12, 0x45678123, 0x67812345),
+				(16, 0x56781234, 0x56781234),
+			] {
+				let rotated_left =
+					arithmetic::u32::rotate_left(builder, format!("rotl_{amount}"), word, amount)?;
+				let rotated_right =
+					arithmetic::u32::rotate_right(builder, format!("rotr_{amount}"), word, amount)?;
+				if let Some(witness) = builder.witness() {
+					let rotated_left = witness.get::<BinaryField1b>(rotated_left)?;
+					let rotated_right = witness.get::<BinaryField1b>(rotated_right)?;
+					assert!(rotated_left.as_slice::<u32>().iter().all(|&v| v == left));
+					assert!(rotated_right.as_slice::<u32>().iter().all(|&v| v == right));
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
 	#[test]
 	fn test_sub() {
 		test_circuit(|builder| {
@@ -410,4 +997,90 @@ mod tests {
 		})
 		.unwrap();
 	}
+
+	fn saturating_inputs(
+		builder: &mut ConstraintSystemBuilder,
+	) -> Result<[OracleId; 2], anyhow::Error> {
+		let log_size = 10;
+		let edge_cases = [
+			(u32::MAX, 1),
+			(u32::MAX - 1, 1),
+			(u32::MAX, u32::MAX),
+			(0, 1),
+			(5, 3),
+			(3, 5),
+			(0, 0),
+		];
+		let (x, y) = test_operands::<u32>(1 << (log_size - 5), edge_cases);
+		Ok([
+			fixed_u32::<BinaryField1b>(builder, "x", log_size, x)?,
+			fixed_u32::<BinaryField1b>(builder, "y", log_size, y)?,
+		])
+	}
+
+	#[test]
+	fn test_saturating_add() {
+		test_circuit(|builder| {
+			let [x, y] = saturating_inputs(builder)?;
+			let z = arithmetic::u32::saturating_add(builder, "x + y", x, y)?;
+			if let Some(witness) = builder.witness() {
+				let x = witness.get::<BinaryField1b>(x)?.as_slice::<u32>();
+				let y = witness.get::<BinaryField1b>(y)?.as_slice::<u32>();
+				let z = witness.get::<BinaryField1b>(z)?.as_slice::<u32>();
+				assert_eq!(z[..5], [u32::MAX, u32::MAX, u32::MAX, 1, 8]);
+				for ((x, y), z) in x.iter().zip(y).zip(z) {
+					assert_eq!(x.saturating_add(*y), *z);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_saturating_sub() {
+		test_circuit(|builder| {
+			let [x, y] = saturating_inputs(builder)?;
+			let z = arithmetic::u32::saturating_sub(builder, "x - y", x, y)?;
+			if let Some(witness) = builder.witness() {
+				let x = witness.get::<BinaryField1b>(x)?.as_slice::<u32>();
+				let y = witness.get::<BinaryField1b>(y)?.as_slice::<u32>();
+				let z = witness.get::<BinaryField1b>(z)?.as_slice::<u32>();
+				assert_eq!(z[..7], [u32::MAX - 1, u32::MAX - 2, 0, 0, 2, 0, 0]);
+				for ((x, y), z) in x.iter().zip(y).zip(z) {
+					assert_eq!(x.saturating_sub(*y), *z);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_le() {
+		test_circuit(|builder| {
+			let log_size = 10;
+			let a = unconstrained::<BinaryField1b>(builder, "a", log_size)?;
+			let b = unconstrained::<BinaryField1b>(builder, "b", log_size)?;
+			let le = arithmetic::u32::le(builder, "a <= b", a, b)?;
+			let a_le_a = arithmetic::u32::le(builder, "a <= a", a, a)?;
+			if let Some(witness) = builder.witness() {
+				let a = witness.get::<BinaryField1b>(a)?.as_slice::<u32>();
+				let b = witness.get::<BinaryField1b>(b)?.as_slice::<u32>();
+				let le = witness.get::<BinaryField1b>(le)?;
+				let a_le_a = witness.get::<BinaryField1b>(a_le_a)?;
+				for (i, (a, b)) in a.iter().zip(b).enumerate() {
+					let expected = if a <= b {
+						BinaryField1b::ONE
+					} else {
+						BinaryField1b::ZERO
+					};
+					assert_eq!(get_packed_slice(le.packed(), i), expected);
+					assert_eq!(get_packed_slice(a_le_a.packed(), i), BinaryField1b::ONE);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
 }
@@ -0,0 +1,84 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::oracle::OracleId;
+
+use crate::builder::ConstraintSystemBuilder;
+
+/// Adds two u64 columns, each given as the u32 columns of its low and high halves.
+///
+/// The low halves are added first, and the carry out of the low addition is added to the sum of
+/// the high halves. Returns the `(low, high)` halves of the sum. With [`super::Flags::Checked`],
+/// the circuit fails if the 64-bit addition overflows, otherwise the sum wraps modulo 2^64.
+pub fn add_from_u32_halves(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	lo_a: OracleId,
+	hi_a: OracleId,
+	lo_b: OracleId,
+	hi_b: OracleId,
+	flags: super::Flags,
+) -> Result<(OracleId, OracleId), anyhow::Error> {
+	builder.push_namespace(name);
+	// The overflow counter of a two input sum is the carry, as a u32 column.
+	let (lo, carry) = super::u32::sum_with_overflow_flag(builder, "lo", &[lo_a, lo_b])?;
+	let hi = super::u32::add_chain(builder, "hi", &[hi_a, hi_b, carry], flags)?;
+	builder.pop_namespace();
+	Ok((lo, hi))
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField1b;
+
+	use crate::{
+		arithmetic::{self, Flags},
+		builder::test_utils::{test_circuit, test_operands},
+		unconstrained::fixed_u32,
+	};
+
+	#[test]
+	fn test_add_from_u32_halves() {
+		test_circuit(|builder| {
+			let log_size = 10;
+			// Operands are `[lo, hi]` pairs of u32 halves.
+			let edge_cases = [
+				([u32::MAX, 0], [1, 0]),
+				([u32::MAX, u32::MAX], [1, 0]),
+				([5, 7], [3, 11]),
+			];
+			let (a, b) = test_operands::<[u32; 2]>(1 << (log_size - 5), edge_cases);
+			let (lo_a_values, hi_a_values): (Vec<_>, Vec<_>) =
+				a.iter().map(|&halves| halves.into()).unzip();
+			let (lo_b_values, hi_b_values): (Vec<_>, Vec<_>) =
+				b.iter().map(|&halves| halves.into()).unzip();
+			let lo_a = fixed_u32::<BinaryField1b>(builder, "lo_a", log_size, lo_a_values)?;
+			let hi_a = fixed_u32::<BinaryField1b>(builder, "hi_a", log_size, hi_a_values)?;
+			let lo_b = fixed_u32::<BinaryField1b>(builder, "lo_b", log_size, lo_b_values)?;
+			let hi_b = fixed_u32::<BinaryField1b>(builder, "hi_b", log_size, hi_b_values)?;
+
+			let (lo, hi) = arithmetic::u64::add_from_u32_halves(
+				builder,
+				"a + b",
+				lo_a,
+				hi_a,
+				lo_b,
+				hi_b,
+				Flags::Unchecked,
+			)?;
+			if let Some(witness) = builder.witness() {
+				let lo = witness.get::<BinaryField1b>(lo)?.as_slice::<u32>();
+				let hi = witness.get::<BinaryField1b>(hi)?.as_slice::<u32>();
+				assert_eq!((lo[0], hi[0]), (0, 1));
+				assert_eq!((lo[1], hi[1]), (0, 0));
+				for (row, ([lo_a, hi_a], [lo_b, hi_b])) in a.iter().zip(&b).enumerate() {
+					let a = ((*hi_a as u64) << 32) | *lo_a as u64;
+					let b = ((*hi_b as u64) << 32) | *lo_b as u64;
+					let sum = a.wrapping_add(b);
+					assert_eq!((lo[row], hi[row]), (sum as u32, (sum >> 32) as u32));
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
@@ -1,11 +1,17 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use binius_core::oracle::OracleId;
-use binius_field::{BinaryField1b, Field, TowerField};
+use binius_core::{
+	oracle::{OracleId, ShiftVariant},
+	polynomial::decompose_index_to_hypercube_point,
+};
+use binius_field::{
+	packed::{get_packed_slice, set_packed_slice},
+	BinaryField1b, Field, TowerField,
+};
 use binius_macros::arith_expr;
 use binius_maybe_rayon::prelude::*;
 
-use crate::builder::ConstraintSystemBuilder;
+use crate::builder::{types::F, ConstraintSystemBuilder};
 
 pub fn and(
 	builder: &mut ConstraintSystemBuilder,
@@ -96,11 +102,171 @@ pub fn or(
 	Ok(zout)
 }
 
+/// Rotates each `bits`-wide word of `word` right by the corresponding word of `amount`.
+///
+/// Both inputs are B1 columns holding packed `bits`-wide words, where `bits` is a power of two
+/// between 2 and 64. The rotation is computed by a MUX tree with one level per bit of the
+/// amount: level `i` selects between its input and the input rotated right by `2^i`. Amounts in
+/// `0..2 * bits` are supported, so that rotating by `bits` is the identity.
+pub fn bit_rotation_variable(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	word: OracleId,
+	amount: OracleId,
+	bits: usize,
+) -> Result<OracleId, anyhow::Error> {
+	anyhow::ensure!(
+		bits.is_power_of_two() && (2..=64).contains(&bits),
+		"Word size must be a power of two between 2 and 64. Got {bits}"
+	);
+	let log_bits = bits.trailing_zeros() as usize;
+
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([word, amount])?;
+	anyhow::ensure!(
+		log_rows >= log_bits,
+		"Polynomial must have n_vars >= {log_bits}. Got {log_rows}"
+	);
+
+	// Bit `log_bits` only encodes a rotation by `bits`, all the bits above it must be zero.
+	for index in log_bits + 1..bits {
+		let bit = select_word_bit(builder, format!("amount_bit_{index}"), amount, log_bits, index)?;
+		builder.assert_zero(
+			format!("amount_bit_{index}_zero"),
+			[bit],
+			arith_expr!([bit] = bit).convert_field(),
+		);
+	}
+
+	let mask = u64::MAX >> (64 - bits);
+	// Columns with fewer than 64 rows only fill the low bits of their first u64.
+	let word_bits = 64.min(1 << log_rows);
+	let mut output = word;
+	for level in 0..log_bits {
+		builder.push_namespace(format!("level_{level}"));
+		let offset = 1 << level;
+
+		// Broadcast bit `level` of each amount word to all bits of the word.
+		let selector = builder.add_committed("selector", log_rows, BinaryField1b::TOWER_LEVEL);
+		let selector_rotated = builder.add_shifted(
+			"selector_rotated",
+			selector,
+			1,
+			log_bits,
+			ShiftVariant::CircularLeft,
+		)?;
+		builder.assert_zero(
+			"selector_broadcast",
+			[selector, selector_rotated],
+			arith_expr!([x, y] = x - y).convert_field(),
+		);
+		let rotated = builder.add_shifted(
+			"rotated",
+			output,
+			bits - offset,
+			log_bits,
+			ShiftVariant::CircularLeft,
+		)?;
+		let selected = builder.add_committed("selected", log_rows, BinaryField1b::TOWER_LEVEL);
+
+		if let Some(witness) = builder.witness() {
+			let input = witness.get::<BinaryField1b>(output)?.as_slice::<u64>();
+			let amount = witness.get::<BinaryField1b>(amount)?.as_slice::<u64>();
+			let mut selector = witness.new_column::<BinaryField1b>(selector);
+			let mut selector_rotated = witness.new_column::<BinaryField1b>(selector_rotated);
+			let mut rotated = witness.new_column::<BinaryField1b>(rotated);
+			let mut selected = witness.new_column::<BinaryField1b>(selected);
+			(
+				input,
+				amount,
+				selector.as_mut_slice::<u64>(),
+				selector_rotated.as_mut_slice::<u64>(),
+				rotated.as_mut_slice::<u64>(),
+				selected.as_mut_slice::<u64>(),
+			)
+				.into_par_iter()
+				.for_each(|(input, amount, selector, selector_rotated, rotated, selected)| {
+					*selector = 0;
+					*rotated = 0;
+					*selected = 0;
+					for shift in (0..word_bits).step_by(bits) {
+						let value = (input >> shift) & mask;
+						let value_rotated = ((value >> offset) | (value << (bits - offset))) & mask;
+						let bit = (amount >> (shift + level)) & 1 == 1;
+						*rotated |= value_rotated << shift;
+						if bit {
+							*selector |= mask << shift;
+							*selected |= value_rotated << shift;
+						} else {
+							*selected |= value << shift;
+						}
+					}
+					*selector_rotated = *selector;
+				});
+		}
+
+		// The broadcast selector must agree with the amount on the first bit of each word.
+		let selector_bit = select_word_bit(builder, "selector_bit", selector, log_bits, 0)?;
+		let amount_bit = select_word_bit(builder, "amount_bit", amount, log_bits, level)?;
+		builder.assert_zero(
+			"selector_amount_bit",
+			[selector_bit, amount_bit],
+			arith_expr!([x, y] = x - y).convert_field(),
+		);
+
+		builder.assert_zero(
+			"mux",
+			[output, rotated, selector, selected],
+			arith_expr!([x, rotated, selector, selected] = x + selector * (rotated - x) - selected)
+				.convert_field(),
+		);
+		builder.pop_namespace();
+		output = selected;
+	}
+
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// Projects bit `index` of each `2^log_bits`-wide word of a B1 column.
+fn select_word_bit(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+	log_bits: usize,
+	index: usize,
+) -> Result<OracleId, anyhow::Error> {
+	let log_rows = builder.log_rows([input])?;
+	let query = decompose_index_to_hypercube_point::<F>(log_bits, index);
+	let bit = builder.add_projected(name, input, query, 0)?;
+	if let Some(witness) = builder.witness() {
+		let input = witness.get::<BinaryField1b>(input)?.packed();
+		let mut bit = witness.new_column::<BinaryField1b>(bit);
+		let bit = bit.packed();
+		for i in 0..1 << (log_rows - log_bits) {
+			set_packed_slice(bit, i, get_packed_slice(input, (i << log_bits) | index));
+		}
+	}
+	Ok(bit)
+}
+
 #[cfg(test)]
 mod tests {
+	use binius_core::oracle::OracleId;
 	use binius_field::BinaryField1b;
 
-	use crate::{builder::test_utils::test_circuit, unconstrained::unconstrained};
+	use crate::{
+		builder::{test_utils::test_circuit, ConstraintSystemBuilder},
+		unconstrained::{fill_column_from_fn, fixed_u32, unconstrained},
+	};
+
+	const LOG_WORDS: usize = 6;
+
+	fn words() -> Vec<u32> {
+		(0..1u32 << LOG_WORDS)
+			.map(|i| i.wrapping_mul(0x9E3779B9) ^ 0xDEADBEEF)
+			.collect()
+	}
 
 	#[test]
 	fn test_bitwise() {
@@ -115,4 +281,152 @@ mod tests {
 		})
 		.unwrap();
 	}
+
+	type Gadget = fn(
+		&mut ConstraintSystemBuilder,
+		&str,
+		OracleId,
+		OracleId,
+	) -> Result<OracleId, anyhow::Error>;
+
+	/// Checks that `gadget` agrees with `op` and is commutative and idempotent.
+	fn check_commutative_idempotent(gadget: Gadget, op: fn(u32, u32) -> u32) {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let a_values = words();
+		let b_values = words()
+			.into_iter()
+			.map(|w| w.rotate_left(7))
+			.collect::<Vec<_>>();
+		let a =
+			fixed_u32::<BinaryField1b>(&mut builder, "a", LOG_WORDS + 5, a_values.clone()).unwrap();
+		let b =
+			fixed_u32::<BinaryField1b>(&mut builder, "b", LOG_WORDS + 5, b_values.clone()).unwrap();
+		let ab = gadget(&mut builder, "ab", a, b).unwrap();
+		let ba = gadget(&mut builder, "ba", b, a).unwrap();
+		let aa = gadget(&mut builder, "aa", a, a).unwrap();
+
+		{
+			let witness = builder.witness().unwrap();
+			let ab = witness.get::<BinaryField1b>(ab).unwrap();
+			let ba = witness.get::<BinaryField1b>(ba).unwrap();
+			let aa = witness.get::<BinaryField1b>(aa).unwrap();
+			for (i, (&a, &b)) in a_values.iter().zip(&b_values).enumerate() {
+				assert_eq!(ab.as_slice::<u32>()[i], op(a, b));
+				assert_eq!(ba.as_slice::<u32>()[i], op(a, b));
+				assert_eq!(aa.as_slice::<u32>()[i], a);
+			}
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		binius_core::constraint_system::validate::validate_witness(
+			&constraint_system,
+			&[],
+			&witness,
+		)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_and_commutative_idempotent() {
+		check_commutative_idempotent(
+			|builder, name, a, b| super::and(builder, name, a, b),
+			|a, b| a & b,
+		);
+	}
+
+	#[test]
+	fn test_or_commutative_idempotent() {
+		check_commutative_idempotent(
+			|builder, name, a, b| super::or(builder, name, a, b),
+			|a, b| a | b,
+		);
+	}
+
+	#[test]
+	fn test_bit_rotation_variable() {
+		test_circuit(|builder| {
+			let word = fixed_u32::<BinaryField1b>(builder, "word", LOG_WORDS + 5, words())?;
+			let amounts = (0..1 << LOG_WORDS).map(|i| i % 33).collect();
+			let amount = fixed_u32::<BinaryField1b>(builder, "amount", LOG_WORDS + 5, amounts)?;
+			let _rotated = super::bit_rotation_variable(builder, "rotation", word, amount, 32)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_bit_rotation_variable_values() {
+		for amount in [0, 1, 5, 16, 31, 32] {
+			let allocator = bumpalo::Bump::new();
+			let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+			let word =
+				fixed_u32::<BinaryField1b>(&mut builder, "word", LOG_WORDS + 5, words()).unwrap();
+			let amount_id = fixed_u32::<BinaryField1b>(
+				&mut builder,
+				"amount",
+				LOG_WORDS + 5,
+				vec![amount; 1 << LOG_WORDS],
+			)
+			.unwrap();
+			let rotated =
+				super::bit_rotation_variable(&mut builder, "rotation", word, amount_id, 32)
+					.unwrap();
+
+			let witness = builder.witness().unwrap();
+			let rotated = witness.get::<BinaryField1b>(rotated).unwrap();
+			for (&rotated, word) in rotated.as_slice::<u32>().iter().zip(words()) {
+				assert_eq!(rotated, word.rotate_right(amount));
+			}
+		}
+	}
+
+	#[test]
+	fn test_bit_rotation_variable_small_column() {
+		// Two 8-bit words, so the columns have fewer than 64 rows.
+		const WORDS: [u8; 2] = [0xB5, 0x3C];
+		const AMOUNTS: [u8; 2] = [3, 12];
+		test_circuit(|builder| {
+			let bit = |values: [u8; 2]| move |i: usize| (values[i / 8] >> (i % 8)) & 1 == 1;
+			let word = fill_column_from_fn::<BinaryField1b, _>(builder, "word", 4, bit(WORDS))?;
+			let amount =
+				fill_column_from_fn::<BinaryField1b, _>(builder, "amount", 4, bit(AMOUNTS))?;
+			let rotated = super::bit_rotation_variable(builder, "rotation", word, amount, 8)?;
+			if let Some(witness) = builder.witness() {
+				let rotated = witness.get::<BinaryField1b>(rotated)?;
+				let expected = [
+					WORDS[0].rotate_right(AMOUNTS[0] as u32),
+					WORDS[1].rotate_right(AMOUNTS[1] as u32),
+				];
+				assert_eq!(rotated.as_slice::<u8>()[..2], expected);
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_bit_rotation_variable_amount_out_of_range() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let word =
+			fixed_u32::<BinaryField1b>(&mut builder, "word", LOG_WORDS + 5, words()).unwrap();
+		let amount = fixed_u32::<BinaryField1b>(
+			&mut builder,
+			"amount",
+			LOG_WORDS + 5,
+			vec![64; 1 << LOG_WORDS],
+		)
+		.unwrap();
+		super::bit_rotation_variable(&mut builder, "rotation", word, amount, 32).unwrap();
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		assert!(binius_core::constraint_system::validate::validate_witness(
+			&constraint_system,
+			&[],
+			&witness
+		)
+		.is_err());
+	}
 }
@@ -1,33 +1,63 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, panic::Location, rc::Rc};
 
 use anyhow::{anyhow, ensure};
 use binius_core::{
 	constraint_system::{
-		channel::{ChannelId, Flush, FlushDirection, OracleOrConst},
+		self,
+		channel::{Boundary, ChannelId, Flush, FlushDirection, OracleOrConst},
 		exp::Exp,
-		ConstraintSystem,
+		ComputationBackend, ConstraintSystem, Proof,
 	},
+	fiat_shamir::Challenger,
 	oracle::{
 		ConstraintSetBuilder, Error as OracleError, MultilinearOracleSet, OracleId, ShiftVariant,
 	},
-	polynomial::MultivariatePoly,
-	transparent::step_down::StepDown,
+	polynomial::{Error as PolynomialError, MultivariatePoly},
+	tower::CanonicalTowerFamily,
+	transparent::{
+		constant::Constant, step_down::StepDown, step_up::StepUp, MultilinearExtensionTransparent,
+	},
 	witness::MultilinearExtensionIndex,
 };
 use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
-	BinaryField1b,
+	packed::set_packed_slice,
+	BinaryField16b, BinaryField1b, BinaryField2b, BinaryField32b, BinaryField4b, BinaryField64b,
+	BinaryField8b, ExtensionField, Field, PackedField, TowerField,
 };
+use binius_hash::PseudoCompressionFunction;
 use binius_math::ArithExpr;
 use binius_utils::bail;
+use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
 
 use crate::builder::{
 	types::{F, U},
 	witness,
 };
 
+/// A copy of the constraint system built so far, which can be instantiated any number of times.
+///
+/// See [`ConstraintSystemBuilder::snapshot`].
+#[derive(Clone)]
+pub struct ConstraintSystemSnapshot<'arena> {
+	oracles: MultilinearOracleSet<F>,
+	constraints: ConstraintSetBuilder<F>,
+	non_zero_oracle_ids: Vec<OracleId>,
+	flushes: Vec<Flush<F>>,
+	exponents: Vec<Exp<F>>,
+	next_channel_id: ChannelId,
+	witness: Option<witness::WitnessSnapshot<'arena>>,
+}
+
+impl ConstraintSystemSnapshot<'_> {
+	/// The number of oracles added by every instantiation of the snapshot.
+	pub fn n_oracles(&self) -> usize {
+		self.oracles.size()
+	}
+}
+
 #[derive(Default)]
 pub struct ConstraintSystemBuilder<'arena> {
 	oracles: Rc<RefCell<MultilinearOracleSet<F>>>,
@@ -39,11 +69,15 @@ pub struct ConstraintSystemBuilder<'arena> {
 	witness: Option<witness::Builder<'arena>>,
 	next_channel_id: ChannelId,
 	namespace_path: Vec<String>,
+	tracing: bool,
 }
 
 impl<'arena> ConstraintSystemBuilder<'arena> {
 	pub fn new() -> Self {
-		Self::default()
+		Self {
+			tracing: tracing_from_env(),
+			..Default::default()
+		}
 	}
 
 	pub fn new_with_witness(allocator: &'arena bumpalo::Bump) -> Self {
@@ -51,10 +85,37 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		Self {
 			witness: Some(witness::Builder::new(allocator, oracles.clone())),
 			oracles,
+			tracing: tracing_from_env(),
 			..Default::default()
 		}
 	}
 
+	/// Emits a `tracing` debug event for every committed oracle, zero constraint and flush added
+	/// to the builder, along with the source location of the call.
+	///
+	/// Tracing is enabled by default when the `BINIUS_TRACE_BUILDER` environment variable is set
+	/// to anything other than `0`.
+	///
+	/// Example
+	/// ```
+	/// use binius_circuits::builder::ConstraintSystemBuilder;
+	/// use binius_field::{BinaryField1b, TowerField};
+	///
+	/// let mut builder = ConstraintSystemBuilder::new().with_tracing();
+	/// // Emits an `add_committed` event with the oracle id, name and this source location.
+	/// builder.add_committed("x", 5, BinaryField1b::TOWER_LEVEL);
+	/// ```
+	pub const fn with_tracing(mut self) -> Self {
+		self.tracing = true;
+		self
+	}
+
+	/// Disables the tracing enabled by [`Self::with_tracing`].
+	pub const fn without_tracing(mut self) -> Self {
+		self.tracing = false;
+		self
+	}
+
 	#[allow(clippy::type_complexity)]
 	pub fn build(self) -> Result<ConstraintSystem<F>, anyhow::Error> {
 		let table_constraints = self.constraints.build(&self.oracles.borrow())?;
@@ -91,6 +152,81 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 			.build()
 	}
 
+	/// Takes the witness, builds the constraint system, and then proves and verifies it.
+	///
+	/// Proving uses the canonical tower family with the given hash, compression function,
+	/// challenger and backend, and the proof is verified against
+	/// [`ConstraintSystem::no_base_constraints`]. Returns the proof on success. Fails if the
+	/// builder was created without a witness.
+	pub fn prove_and_verify<Hash, Compress, Challenger_, Backend>(
+		mut self,
+		log_inv_rate: usize,
+		security_bits: usize,
+		boundaries: Vec<Boundary<F>>,
+		backend: &Backend,
+	) -> Result<Proof, anyhow::Error>
+	where
+		Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+		Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+		Challenger_: Challenger + Default,
+		Backend: ComputationBackend,
+	{
+		let witness = Option::take(&mut self.witness)
+			.ok_or_else(|| anyhow!("builder was created without witness"))?
+			.build()?;
+		let constraint_system = self.build()?;
+
+		let proof =
+			constraint_system::prove::<U, CanonicalTowerFamily, Hash, Compress, Challenger_, _>(
+				&constraint_system,
+				log_inv_rate,
+				security_bits,
+				&boundaries,
+				witness,
+				backend,
+			)?;
+
+		constraint_system::verify::<U, CanonicalTowerFamily, Hash, Compress, Challenger_>(
+			&constraint_system.no_base_constraints(),
+			log_inv_rate,
+			security_bits,
+			&boundaries,
+			proof.clone(),
+		)?;
+
+		Ok(proof)
+	}
+
+	/// Builds the constraint system and verifies `proof` against it.
+	///
+	/// This is the verifier-side counterpart of [`Self::prove_and_verify`], and must be called
+	/// with the same protocol parameters. The builder does not need to have a witness.
+	pub fn verify_only<Hash, Compress, Challenger_>(
+		self,
+		log_inv_rate: usize,
+		security_bits: usize,
+		boundaries: Vec<Boundary<F>>,
+		proof: Proof,
+	) -> Result<(), anyhow::Error>
+	where
+		Hash: Digest + BlockSizeUser,
+		Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+		Challenger_: Challenger + Default,
+	{
+		let constraint_system = self.build()?.no_base_constraints();
+
+		constraint_system::verify::<U, CanonicalTowerFamily, Hash, Compress, Challenger_>(
+			&constraint_system,
+			log_inv_rate,
+			security_bits,
+			&boundaries,
+			proof,
+		)?;
+
+		Ok(())
+	}
+
+	#[track_caller]
 	pub fn flush(
 		&mut self,
 		direction: FlushDirection,
@@ -104,6 +240,7 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		self.flush_with_multiplicity(direction, channel_id, count, oracle_ids, 1)
 	}
 
+	#[track_caller]
 	pub fn flush_with_multiplicity(
 		&mut self,
 		direction: FlushDirection,
@@ -147,6 +284,7 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		self.flush_custom(direction, channel_id, selector, oracle_ids, multiplicity)
 	}
 
+	#[track_caller]
 	pub fn flush_custom(
 		&mut self,
 		direction: FlushDirection,
@@ -173,7 +311,19 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 			non_const_oracles
 		);
 
-		let oracles = oracle_ids.into_iter().collect();
+		let oracles = oracle_ids.into_iter().collect::<Vec<_>>();
+		if self.tracing {
+			tracing::debug!(
+				?direction,
+				channel_id,
+				selector,
+				oracles = ?oracles,
+				multiplicity,
+				namespace = self.namespace_path.join("::"),
+				location = %Location::caller(),
+				"flush"
+			);
+		}
 		self.flushes.push(Flush {
 			channel_id,
 			direction,
@@ -185,6 +335,7 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		Ok(())
 	}
 
+	#[track_caller]
 	pub fn send(
 		&mut self,
 		channel_id: ChannelId,
@@ -197,6 +348,7 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		self.flush(FlushDirection::Push, channel_id, count, oracle_ids)
 	}
 
+	#[track_caller]
 	pub fn receive(
 		&mut self,
 		channel_id: ChannelId,
@@ -209,16 +361,61 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		self.flush(FlushDirection::Pull, channel_id, count, oracle_ids)
 	}
 
+	/// Constrains `composition` to evaluate to zero on every row of the oracles `oracle_ids`.
+	///
+	/// The variables of `composition` index into `oracle_ids`, which must all have the same
+	/// number of variables.
+	#[track_caller]
 	pub fn assert_zero(
 		&mut self,
 		name: impl ToString,
 		oracle_ids: impl IntoIterator<Item = OracleId>,
 		composition: ArithExpr<F>,
 	) {
+		let name = name.to_string();
+		let oracle_ids = oracle_ids.into_iter().collect::<Vec<_>>();
+		if self.tracing {
+			tracing::debug!(
+				name,
+				oracle_ids = ?oracle_ids,
+				namespace = self.namespace_path.join("::"),
+				location = %Location::caller(),
+				"assert_zero"
+			);
+		}
 		self.constraints
 			.add_zerocheck(name, oracle_ids, composition);
 	}
 
+	/// Constrains every value of `x` to be 0 or 1, with the constraint `x * (x - 1) = 0`.
+	///
+	/// This holds trivially for B1 oracles, and restricts oracles of any other tower level.
+	#[track_caller]
+	pub fn assert_boolean(&mut self, name: impl ToString, x: OracleId) {
+		let var = ArithExpr::Var(0);
+		self.assert_zero(name, [x], var.clone() * (var - ArithExpr::Const(F::ONE)));
+	}
+
+	/// Constrains the oracles `a` and `b` to be equal, with the constraint `a - b = 0`.
+	///
+	/// Fails if the oracles do not have the same number of variables.
+	#[track_caller]
+	pub fn assert_eq(
+		&mut self,
+		name: impl ToString,
+		a: OracleId,
+		b: OracleId,
+	) -> Result<(), anyhow::Error> {
+		self.log_rows([a, b])?;
+		self.assert_zero(name, [a, b], ArithExpr::Var(0) - ArithExpr::Var(1));
+		Ok(())
+	}
+
+	/// Returns the number of constraints added so far, which is useful to budget circuit size.
+	pub fn constraints_count(&self) -> usize {
+		self.constraints.constraints_count()
+	}
+
 	pub fn assert_not_zero(&mut self, oracle_id: OracleId) {
 		self.non_zero_oracle_ids.push(oracle_id);
 	}
@@ -229,15 +426,27 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		channel_id
 	}
 
+	#[track_caller]
 	pub fn add_committed(
 		&mut self,
 		name: impl ToString,
 		n_vars: usize,
 		tower_level: usize,
 	) -> OracleId {
+		let name = self.scoped_name(name);
+		if self.tracing {
+			tracing::debug!(
+				oracle_id = self.oracles.borrow().size(),
+				name,
+				n_vars,
+				tower_level,
+				location = %Location::caller(),
+				"add_committed"
+			);
+		}
 		self.oracles
 			.borrow_mut()
-			.add_named(self.scoped_name(name))
+			.add_named(name)
 			.committed(n_vars, tower_level)
 	}
 
@@ -410,6 +619,175 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 			.transparent(poly)
 	}
 
+	/// Adds a transparent oracle that is 1 on the rows in `low..high` and 0 elsewhere.
+	///
+	/// The oracle is the linear combination `StepDown(high) + StepUp(low) + 1` of two transparent
+	/// step oracles, which are added in the `name` namespace.
+	pub fn add_transparent_range(
+		&mut self,
+		name: impl ToString,
+		n_vars: usize,
+		low: usize,
+		high: usize,
+	) -> Result<OracleId, OracleError> {
+		if low > high {
+			bail!(PolynomialError::ArgumentRangeError {
+				arg: "low".into(),
+				range: 0..high + 1,
+			});
+		}
+		let name = name.to_string();
+
+		self.push_namespace(&name);
+		let step_down = StepDown::new(n_vars, high)?;
+		let step_down_id = self.add_transparent("step_down", step_down.clone())?;
+		let step_up = StepUp::new(n_vars, low)?;
+		let step_up_id = self.add_transparent("step_up", step_up.clone())?;
+		self.pop_namespace();
+
+		let range = self.add_linear_combination_with_offset(
+			name,
+			n_vars,
+			F::ONE,
+			[(step_down_id, F::ONE), (step_up_id, F::ONE)],
+		)?;
+
+		if let Some(witness) = self.witness() {
+			step_down.populate(witness.new_column::<BinaryField1b>(step_down_id).packed());
+			step_up.populate(witness.new_column::<BinaryField1b>(step_up_id).packed());
+			let mut range = witness.new_column::<BinaryField1b>(range);
+			let range = range.packed();
+			for i in low..high {
+				set_packed_slice(range, i, BinaryField1b::ONE);
+			}
+		}
+
+		Ok(range)
+	}
+
+	/// Adds a transparent oracle that is `value` on every row.
+	///
+	/// The oracle has the tower level of `FS`.
+	pub fn add_transparent_constant<FS>(
+		&mut self,
+		name: impl ToString,
+		n_vars: usize,
+		value: FS,
+	) -> Result<OracleId, OracleError>
+	where
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+		FS: TowerField,
+	{
+		let id = self.add_transparent(name, Constant::new(n_vars, value))?;
+		if let Some(witness) = self.witness() {
+			witness
+				.new_column::<FS>(id)
+				.packed()
+				.fill(<PackedType<U, FS>>::broadcast(value));
+		}
+		Ok(id)
+	}
+
+	/// Adds a constant transparent oracle at the smallest tower level containing `value`.
+	pub fn add_const(
+		&mut self,
+		name: impl ToString,
+		n_vars: usize,
+		value: F,
+	) -> Result<OracleId, anyhow::Error> {
+		self.add_const_tower_level(name, n_vars, value, value.min_tower_level())
+	}
+
+	/// Adds a constant transparent oracle at the given tower level.
+	///
+	/// Fails if `value` does not lie in the subfield of that tower level.
+	pub fn add_const_tower_level(
+		&mut self,
+		name: impl ToString,
+		n_vars: usize,
+		value: F,
+		tower_level: usize,
+	) -> Result<OracleId, anyhow::Error> {
+		fn subfield<FS: TryFrom<F>>(value: F, tower_level: usize) -> Result<FS, anyhow::Error> {
+			FS::try_from(value)
+				.map_err(|_| anyhow!("{value} is not in the subfield of tower level {tower_level}"))
+		}
+
+		let id = match tower_level {
+			0 => self.add_transparent_constant(
+				name,
+				n_vars,
+				subfield::<BinaryField1b>(value, tower_level)?,
+			)?,
+			1 => self.add_transparent_constant(
+				name,
+				n_vars,
+				subfield::<BinaryField2b>(value, tower_level)?,
+			)?,
+			2 => self.add_transparent_constant(
+				name,
+				n_vars,
+				subfield::<BinaryField4b>(value, tower_level)?,
+			)?,
+			3 => self.add_transparent_constant(
+				name,
+				n_vars,
+				subfield::<BinaryField8b>(value, tower_level)?,
+			)?,
+			4 => self.add_transparent_constant(
+				name,
+				n_vars,
+				subfield::<BinaryField16b>(value, tower_level)?,
+			)?,
+			5 => self.add_transparent_constant(
+				name,
+				n_vars,
+				subfield::<BinaryField32b>(value, tower_level)?,
+			)?,
+			6 => self.add_transparent_constant(
+				name,
+				n_vars,
+				subfield::<BinaryField64b>(value, tower_level)?,
+			)?,
+			7 => self.add_transparent_constant(name, n_vars, value)?,
+			_ => bail!(anyhow!("Unsupported tower level {tower_level}")),
+		};
+		Ok(id)
+	}
+
+	/// Adds a transparent oracle whose value on the row `i` is the integer `i` as a B32 element.
+	///
+	/// The oracle is the multilinear extension of the precomputed row indices, which makes it
+	/// usable as a counter or an address column.
+	pub fn add_identity(
+		&mut self,
+		name: impl ToString,
+		n_vars: usize,
+	) -> Result<OracleId, anyhow::Error> {
+		ensure!(n_vars <= 32, "The row indices of {n_vars} variables do not fit in B32");
+
+		let mut values = vec![
+			PackedType::<U, BinaryField32b>::default();
+			(1usize << n_vars).div_ceil(PackedType::<U, BinaryField32b>::WIDTH)
+		];
+		for i in 0..1 << n_vars {
+			set_packed_slice(&mut values, i, BinaryField32b::new(i as u32));
+		}
+		let mle = MultilinearExtensionTransparent::<_, PackedType<U, F>, _>::from_values_and_mu(
+			values.clone(),
+			n_vars,
+		)?;
+		let id = self.add_transparent(name, mle)?;
+		if let Some(witness) = self.witness() {
+			witness
+				.new_column::<BinaryField32b>(id)
+				.packed()
+				.copy_from_slice(&values);
+		}
+		Ok(id)
+	}
+
 	pub fn add_zero_padded(
 		&mut self,
 		name: impl ToString,
@@ -484,4 +862,239 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		}
 		Ok(log_rows)
 	}
+
+	/// Returns the ids of all oracles registered so far, in the order they were added, skipping
+	/// removed oracles.
+	pub fn all_oracle_ids(&self) -> impl Iterator<Item = OracleId> {
+		self.oracles.borrow().ids().collect::<Vec<_>>().into_iter()
+	}
+
+	/// Returns the ids and fully scoped names of all named oracles registered so far.
+	///
+	/// Example
+	/// ```
+	/// use binius_circuits::builder::ConstraintSystemBuilder;
+	/// use binius_field::{BinaryField1b, TowerField};
+	///
+	/// let mut builder = ConstraintSystemBuilder::new();
+	/// builder.push_namespace("a");
+	/// let x = builder.add_committed("x", 4, BinaryField1b::TOWER_LEVEL);
+	/// builder.pop_namespace();
+	/// let y = builder.add_committed("y", 4, BinaryField1b::TOWER_LEVEL);
+	///
+	/// assert_eq!(builder.all_oracle_ids().collect::<Vec<_>>(), vec![x, y]);
+	/// assert_eq!(
+	///     builder.oracle_names(),
+	///     vec![(x, "a::x".to_string()), (y, "y".to_string())]
+	/// );
+	/// ```
+	pub fn oracle_names(&self) -> Vec<(OracleId, String)> {
+		self.oracles
+			.borrow()
+			.names()
+			.map(|(id, name)| (id, name.to_owned()))
+			.collect()
+	}
+
+	/// Captures the oracles, constraints, flushes, exponents and witness columns added so far.
+	///
+	/// The snapshot can be instantiated repeatedly with [`Self::restore_with_offset`], which
+	/// avoids re-running the circuit construction for identical sub-circuits. The witness columns
+	/// are shared with the snapshot rather than copied.
+	pub fn snapshot(&self) -> ConstraintSystemSnapshot<'arena> {
+		ConstraintSystemSnapshot {
+			oracles: self.oracles.borrow().clone(),
+			constraints: self.constraints.clone(),
+			non_zero_oracle_ids: self.non_zero_oracle_ids.clone(),
+			flushes: self.flushes.clone(),
+			exponents: self.exponents.clone(),
+			next_channel_id: self.next_channel_id,
+			witness: self.witness.as_ref().map(witness::Builder::snapshot),
+		}
+	}
+
+	/// Adds a new instance of the snapshotted circuit, and returns the ids of its oracles.
+	///
+	/// The oracle ids of the snapshot are shifted past the oracles already in the builder, and
+	/// the returned ids are in the order of the snapshot, so the `i`-th oracle of the snapshot
+	/// becomes `result[i]`. Channel ids are kept as is, so all instances flush into the same
+	/// channels. If both the snapshot and this builder have a witness, the witness columns of the
+	/// snapshot are added for the new oracles; otherwise they are left to the caller.
+	pub fn restore_with_offset(
+		&mut self,
+		snapshot: ConstraintSystemSnapshot<'arena>,
+	) -> Vec<OracleId> {
+		let ConstraintSystemSnapshot {
+			oracles,
+			constraints,
+			non_zero_oracle_ids,
+			flushes,
+			exponents,
+			next_channel_id,
+			witness,
+		} = snapshot;
+
+		let new_ids = self.oracles.borrow_mut().append(&oracles);
+		if let (Some(builder), Some(witness)) = (&self.witness, witness) {
+			builder.restore(witness, &new_ids);
+		}
+		let remap = |oracle_or_const: OracleOrConst<F>| match oracle_or_const {
+			OracleOrConst::Oracle(id) => OracleOrConst::Oracle(new_ids[id]),
+			constant => constant,
+		};
+
+		if let Some(&offset) = new_ids.first() {
+			self.constraints.append_with_offset(constraints, offset);
+		}
+		self.non_zero_oracle_ids
+			.extend(non_zero_oracle_ids.into_iter().map(|id| new_ids[id]));
+		self.flushes.extend(flushes.into_iter().map(|flush| Flush {
+			oracles: flush.oracles.into_iter().map(remap).collect(),
+			selector: flush.selector.map(|id| new_ids[id]),
+			..flush
+		}));
+		self.exponents.extend(exponents.into_iter().map(|exp| Exp {
+			bits_ids: exp.bits_ids.into_iter().map(|id| new_ids[id]).collect(),
+			base: remap(exp.base),
+			exp_result_id: new_ids[exp.exp_result_id],
+		}));
+		self.next_channel_id = self.next_channel_id.max(next_channel_id);
+
+		new_ids
+	}
+}
+
+fn tracing_from_env() -> bool {
+	std::env::var("BINIUS_TRACE_BUILDER").is_ok_and(|value| value != "0")
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_field::{BinaryField1b, BinaryField32b, BinaryField8b, Field, TowerField};
+	use binius_math::MultilinearPoly;
+
+	use super::ConstraintSystemBuilder;
+	use crate::{builder::types::F, unconstrained::fill_column_from_fn};
+
+	/// Checks that the witness of the constant `id` is `value` on the whole hypercube.
+	fn check_const(mut builder: ConstraintSystemBuilder, id: usize, n_vars: usize, value: F) {
+		let witness = builder.take_witness().unwrap();
+		let poly = witness.get_multilin_poly(id).unwrap();
+		for i in 0..1 << n_vars {
+			assert_eq!(poly.evaluate_on_hypercube(i).unwrap(), value);
+		}
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_add_const() {
+		for value in [
+			F::ZERO,
+			F::ONE,
+			F::from(BinaryField8b::new(0x2d)),
+			F::from(BinaryField32b::new(0xdeadbeef)),
+			F::new(1 << 100u32),
+		] {
+			let allocator = bumpalo::Bump::new();
+			let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+			let id = builder.add_const("const", 5, value).unwrap();
+			assert_eq!(builder.oracles.borrow().tower_level(id), value.min_tower_level());
+			check_const(builder, id, 5, value);
+		}
+	}
+
+	#[test]
+	fn test_add_identity() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let id = builder.add_identity("identity", 8).unwrap();
+		let witness = builder.take_witness().unwrap();
+		let poly = witness.get_multilin_poly(id).unwrap();
+		assert_eq!(poly.evaluate_on_hypercube(42).unwrap(), F::from(42u128));
+		for i in 0..1 << 8 {
+			assert_eq!(poly.evaluate_on_hypercube(i).unwrap(), F::from(i as u128));
+		}
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_add_const_tower_level() {
+		let value = F::from(BinaryField8b::new(0x2d));
+		for tower_level in 3..=7 {
+			let allocator = bumpalo::Bump::new();
+			let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+			let id = builder
+				.add_const_tower_level("const", 5, value, tower_level)
+				.unwrap();
+			assert_eq!(builder.oracles.borrow().tower_level(id), tower_level);
+			check_const(builder, id, 5, value);
+		}
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		assert!(builder.add_const_tower_level("const", 5, value, 0).is_err());
+		assert!(builder.add_const_tower_level("const", 5, value, 8).is_err());
+	}
+
+	#[test]
+	fn test_assert_eq() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let a = fill_column_from_fn::<BinaryField8b, _>(&mut builder, "a", 8, |i| {
+			BinaryField8b::new(i as u8)
+		})
+		.unwrap();
+		let b = fill_column_from_fn::<BinaryField8b, _>(&mut builder, "b", 8, |i| {
+			BinaryField8b::new(i as u8)
+		})
+		.unwrap();
+		let short = fill_column_from_fn::<BinaryField8b, _>(&mut builder, "short", 7, |i| {
+			BinaryField8b::new(i as u8)
+		})
+		.unwrap();
+		builder.assert_eq("a = b", a, b).unwrap();
+		assert!(builder.assert_eq("a = short", a, short).is_err());
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let a = fill_column_from_fn::<BinaryField8b, _>(&mut builder, "a", 8, |i| {
+			BinaryField8b::new(i as u8)
+		})
+		.unwrap();
+		let c = fill_column_from_fn::<BinaryField8b, _>(&mut builder, "c", 8, |i| {
+			BinaryField8b::new(i as u8 ^ 1)
+		})
+		.unwrap();
+		builder.assert_eq("a = c", a, c).unwrap();
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		assert!(validate_witness(&constraint_system, &[], &witness).is_err());
+	}
+
+	#[test]
+	fn test_assert_boolean() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let bits = fill_column_from_fn::<BinaryField1b, _>(&mut builder, "bits", 8, |i| i % 3 == 0)
+			.unwrap();
+		builder.assert_boolean("bits", bits);
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let bytes = fill_column_from_fn::<BinaryField8b, _>(&mut builder, "bytes", 8, |i| {
+			BinaryField8b::new(if i == 5 { 2 } else { (i % 2) as u8 })
+		})
+		.unwrap();
+		builder.assert_boolean("bytes", bytes);
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		assert!(validate_witness(&constraint_system, &[], &witness).is_err());
+	}
 }
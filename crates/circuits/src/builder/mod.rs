@@ -5,4 +5,4 @@ pub mod test_utils;
 pub mod types;
 pub mod witness;
 
-pub use constraint_system::ConstraintSystemBuilder;
+pub use constraint_system::{ConstraintSystemBuilder, ConstraintSystemSnapshot};
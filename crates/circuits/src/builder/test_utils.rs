@@ -4,6 +4,12 @@ use binius_core::constraint_system::{channel::Boundary, validate::validate_witne
 
 use super::{types::F, ConstraintSystemBuilder};
 
+/// The log inverse Reed–Solomon rate used by the tests and benchmarks.
+pub const LOG_INV_RATE: usize = 1;
+
+/// The security level used by the tests and benchmarks.
+pub const SECURITY_BITS: usize = 100;
+
 pub fn test_circuit(
 	build_circuit: fn(&mut ConstraintSystemBuilder) -> Result<Vec<Boundary<F>>, anyhow::Error>,
 ) -> Result<(), anyhow::Error> {
@@ -21,3 +27,63 @@ pub fn test_circuit(
 	validate_witness(&verifier_constraint_system, &verifier_boundaries, &prover_witness)?;
 	Ok(())
 }
+
+/// Proves the circuit built by `build_circuit` at [`LOG_INV_RATE`] and [`SECURITY_BITS`], and
+/// verifies the proof against the circuit built again without a witness.
+///
+/// This is [`test_circuit`] with a full proof instead of a validation of the witness.
+#[cfg(test)]
+pub fn prove_verify_circuit(
+	build_circuit: impl Fn(&mut ConstraintSystemBuilder) -> Result<Vec<Boundary<F>>, anyhow::Error>,
+) -> Result<(), anyhow::Error> {
+	use binius_core::fiat_shamir::HasherChallenger;
+	use binius_hal::make_portable_backend;
+	use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+
+	let allocator = bumpalo::Bump::new();
+	let mut prover_builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+	let prover_boundaries = build_circuit(&mut prover_builder)?;
+	let proof = prover_builder
+		.prove_and_verify::<Groestl256, Groestl256ByteCompression, HasherChallenger<Groestl256>, _>(
+			LOG_INV_RATE,
+			SECURITY_BITS,
+			prover_boundaries,
+			&make_portable_backend(),
+		)?;
+
+	let mut verifier_builder = ConstraintSystemBuilder::new();
+	let verifier_boundaries = build_circuit(&mut verifier_builder)?;
+	verifier_builder
+		.verify_only::<Groestl256, Groestl256ByteCompression, HasherChallenger<Groestl256>>(
+			LOG_INV_RATE,
+			SECURITY_BITS,
+			verifier_boundaries,
+			proof,
+		)
+}
+
+/// Returns `n_words` pseudo-random words, determined by `seed`.
+#[cfg(test)]
+pub fn pseudo_random_words<W: bytemuck::Pod>(n_words: usize, seed: u64) -> Vec<W> {
+	use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+	let mut words = vec![W::zeroed(); n_words];
+	StdRng::seed_from_u64(seed).fill_bytes(bytemuck::cast_slice_mut(&mut words));
+	words
+}
+
+/// Returns `n_words` pairs of operands for a gadget on two columns of words: the `edge_cases`
+/// in the first rows, followed by pseudo-random words.
+#[cfg(test)]
+pub fn test_operands<W: bytemuck::Pod>(
+	n_words: usize,
+	edge_cases: impl IntoIterator<Item = (W, W)>,
+) -> (Vec<W>, Vec<W>) {
+	let mut x = pseudo_random_words(n_words, 0);
+	let mut y = pseudo_random_words(n_words, 1);
+	for (row, (x_edge, y_edge)) in edge_cases.into_iter().enumerate() {
+		x[row] = x_edge;
+		y[row] = y_edge;
+	}
+	(x, y)
+}
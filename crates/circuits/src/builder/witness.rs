@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{cell::RefCell, marker::PhantomData, rc::Rc};
+use std::{cell::RefCell, marker::PhantomData, rc::Rc, sync::Arc};
 
 use anyhow::{anyhow, Error};
 use binius_core::{
@@ -27,8 +27,9 @@ pub struct Builder<'arena> {
 	entries: Rc<RefCell<Vec<Option<WitnessBuilderEntry<'arena>>>>>,
 }
 
+#[derive(Clone)]
 struct WitnessBuilderEntry<'arena> {
-	witness: Result<MultilinearWitness<'arena, PackedType<U, F>>, binius_math::Error>,
+	witness: Result<MultilinearWitness<'arena, PackedType<U, F>>, Arc<binius_math::Error>>,
 	tower_level: usize,
 	nonzero_scalars_prefix: usize,
 	data: &'arena [U],
@@ -159,7 +160,8 @@ impl<'arena> Builder<'arena> {
 			nonzero_scalars_prefix: entry.nonzero_scalars_prefix,
 			tower_level: FS::TOWER_LEVEL,
 			witness: MultilinearExtension::new(entry.log_rows, entry.packed())
-				.map(|x| x.specialize_arc_dyn()),
+				.map(|x| x.specialize_arc_dyn())
+				.map_err(Arc::new),
 		});
 		Ok(())
 	}
@@ -176,8 +178,32 @@ impl<'arena> Builder<'arena> {
 		result.update_multilin_poly_with_nonzero_scalars_prefixes(entries)?;
 		Ok(result)
 	}
+
+	/// Captures the columns populated so far. The column data is shared, not copied.
+	pub(super) fn snapshot(&self) -> WitnessSnapshot<'arena> {
+		WitnessSnapshot(self.entries.borrow().clone())
+	}
+
+	/// Adds the columns of `snapshot`, with the column of the oracle `id` moved to `new_ids[id]`.
+	pub(super) fn restore(&self, snapshot: WitnessSnapshot<'arena>, new_ids: &[OracleId]) {
+		let mut entries = self.entries.borrow_mut();
+		for (id, entry) in snapshot.0.into_iter().enumerate() {
+			let Some(entry) = entry else {
+				continue;
+			};
+			let new_id = new_ids[id];
+			if new_id >= entries.len() {
+				entries.resize_with(new_id + 1, || None);
+			}
+			entries[new_id] = Some(entry);
+		}
+	}
 }
 
+/// The witness columns captured by [`Builder::snapshot`], indexed by oracle id.
+#[derive(Clone)]
+pub(super) struct WitnessSnapshot<'arena>(Vec<Option<WitnessBuilderEntry<'arena>>>);
+
 #[derive(Debug, Clone, Copy)]
 pub struct WitnessEntry<'arena, FS: TowerField>
 where
@@ -285,7 +311,8 @@ where
 				self.log_rows,
 				PackedType::<U, FS>::from_underliers_ref(data),
 			)
-			.map(|x| x.specialize_arc_dyn()),
+			.map(|x| x.specialize_arc_dyn())
+			.map_err(Arc::new),
 		})
 	}
 }
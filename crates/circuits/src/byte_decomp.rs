@@ -0,0 +1,154 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Decomposition of B32 columns into B8 byte columns, and the inverse recomposition.
+//!
+//! The byte `j` of a B32 value is its coordinate along the `j`-th basis element of B32 over B8,
+//! whose integer representation is `256^j`. A recomposition is therefore a linear combination of
+//! the byte columns with the coefficients `1, 256, 65536, 2^24` read as tower field elements.
+
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField32b, BinaryField8b, TowerField};
+
+use crate::builder::{types::F, ConstraintSystemBuilder};
+
+type B8 = BinaryField8b;
+type B32 = BinaryField32b;
+
+/// The number of bytes in a B32 value.
+const MAX_BYTES: usize = 4;
+
+/// Splits the B32 column `x` into `n_bytes` committed B8 columns, least significant byte first.
+///
+/// The bytes are recomposed and constrained to equal `x`, so `x` must fit in `n_bytes` bytes.
+pub fn byte_decompose(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	x: OracleId,
+	n_bytes: usize,
+) -> Result<Vec<OracleId>, anyhow::Error> {
+	anyhow::ensure!(
+		(1..=MAX_BYTES).contains(&n_bytes),
+		"Number of bytes must be between 1 and {MAX_BYTES}. Got {n_bytes}"
+	);
+
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([x])?;
+	let bytes = (0..n_bytes)
+		.map(|j| builder.add_committed(format!("byte_{j}"), log_rows, B8::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+
+	if let Some(witness) = builder.witness() {
+		let x = witness.get::<B32>(x)?.as_slice::<u8>();
+		for (j, &byte) in bytes.iter().enumerate() {
+			let mut byte = witness.new_column::<B8>(byte);
+			for (byte, x) in byte
+				.as_mut_slice::<u8>()
+				.iter_mut()
+				.zip(x.chunks_exact(MAX_BYTES))
+			{
+				*byte = x[j];
+			}
+		}
+	}
+
+	let recomposed = byte_recompose(builder, "recomposed", &bytes)?;
+	builder.assert_eq("recomposition", x, recomposed)?;
+	builder.pop_namespace();
+	Ok(bytes)
+}
+
+/// Recomposes B8 byte columns, least significant byte first, into a B32 column.
+///
+/// The output is a linear combination of the bytes, so nothing is committed.
+pub fn byte_recompose(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	bytes: &[OracleId],
+) -> Result<OracleId, anyhow::Error> {
+	anyhow::ensure!(
+		(1..=MAX_BYTES).contains(&bytes.len()),
+		"Number of bytes must be between 1 and {MAX_BYTES}. Got {}",
+		bytes.len()
+	);
+
+	let log_rows = builder.log_rows(bytes.iter().copied())?;
+	let coefficients = (0..bytes.len())
+		.map(|j| <F as TowerField>::basis(B8::TOWER_LEVEL, j))
+		.collect::<Result<Vec<_>, _>>()?;
+	let recomposed =
+		builder.add_linear_combination(name, log_rows, bytes.iter().copied().zip(coefficients))?;
+
+	if let Some(witness) = builder.witness() {
+		let mut recomposed = witness.new_column::<B32>(recomposed);
+		let recomposed = recomposed.as_mut_slice::<u8>();
+		for (j, &byte) in bytes.iter().enumerate() {
+			let byte = witness.get::<B8>(byte)?.as_slice::<u8>();
+			for (recomposed, &byte) in recomposed.chunks_exact_mut(MAX_BYTES).zip(byte) {
+				recomposed[j] = byte;
+			}
+		}
+	}
+
+	Ok(recomposed)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField32b, BinaryField8b};
+
+	use super::{byte_decompose, byte_recompose};
+	use crate::{
+		builder::test_utils::{pseudo_random_words, test_circuit},
+		unconstrained::fixed_u32,
+	};
+
+	const LOG_SIZE: usize = 8;
+
+	fn values() -> Vec<u32> {
+		pseudo_random_words(1 << LOG_SIZE, 0)
+	}
+
+	#[test]
+	fn test_byte_decompose() {
+		test_circuit(|builder| {
+			let x = fixed_u32::<BinaryField32b>(builder, "x", LOG_SIZE, values())?;
+			let bytes = byte_decompose(builder, "decompose", x, 4)?;
+			let recomposed = byte_recompose(builder, "recompose", &bytes)?;
+
+			if let Some(witness) = builder.witness() {
+				for (j, &byte) in bytes.iter().enumerate() {
+					let byte = witness.get::<BinaryField8b>(byte)?.as_slice::<u8>();
+					for (&byte, value) in byte.iter().zip(values()) {
+						assert_eq!(byte, value.to_le_bytes()[j]);
+					}
+				}
+				let recomposed = witness.get::<BinaryField32b>(recomposed)?.as_slice::<u32>();
+				assert_eq!(recomposed, values());
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_byte_decompose_partial() {
+		test_circuit(|builder| {
+			let values = values().into_iter().map(|value| value & 0xffff).collect();
+			let x = fixed_u32::<BinaryField32b>(builder, "x", LOG_SIZE, values)?;
+			byte_decompose(builder, "decompose", x, 2)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_byte_decompose_too_narrow() {
+		// The upper bytes are dropped, so the recomposition does not match.
+		assert!(test_circuit(|builder| {
+			let x = fixed_u32::<BinaryField32b>(builder, "x", LOG_SIZE, values())?;
+			byte_decompose(builder, "decompose", x, 3)?;
+			Ok(vec![])
+		})
+		.is_err());
+	}
+}
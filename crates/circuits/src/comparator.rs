@@ -0,0 +1,254 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Comparisons of u32 values.
+//!
+//! The operands are columns of bits, where each block of 32 consecutive bits holds one value,
+//! least significant bit first. The results are B1 columns with one bit per value, which is 1 iff
+//! the comparison holds.
+
+use binius_core::oracle::{OracleId, ShiftVariant};
+use binius_field::{
+	packed::{get_packed_slice, set_packed_slice},
+	BinaryField1b, Field, TowerField,
+};
+use binius_macros::arith_expr;
+use binius_maybe_rayon::prelude::*;
+
+use crate::{
+	arithmetic::{
+		self,
+		u32::{select_bit, LOG_U32_BITS},
+	},
+	bitwise,
+	builder::{types::F, ConstraintSystemBuilder},
+};
+
+type B1 = BinaryField1b;
+
+/// Compares `a < b`.
+pub fn u32_lt(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	a: OracleId,
+	b: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let ge = u32_ge(builder, "ge", a, b)?;
+	let lt = not(builder, "lt", ge)?;
+	builder.pop_namespace();
+	Ok(lt)
+}
+
+/// Compares `a <= b`.
+///
+/// This is [`arithmetic::u32::le`], which checks that `b - a` does not borrow.
+pub fn u32_le(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	a: OracleId,
+	b: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	arithmetic::u32::le(builder, name, a, b)
+}
+
+/// Compares `a > b`.
+pub fn u32_gt(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	a: OracleId,
+	b: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	u32_lt(builder, name, b, a)
+}
+
+/// Compares `a >= b`.
+pub fn u32_ge(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	a: OracleId,
+	b: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	u32_le(builder, name, b, a)
+}
+
+/// Compares `a == b`.
+///
+/// The result is the negation of the OR of the bits of `a XOR b`.
+pub fn u32_eq(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	a: OracleId,
+	b: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let diff = bitwise::xor(builder, "diff", a, b)?;
+	let any = any_bit(builder, diff)?;
+	let eq = not(builder, "eq", any)?;
+	builder.pop_namespace();
+	Ok(eq)
+}
+
+/// Computes the OR of the bits of each u32 value.
+///
+/// The running OR from the least significant bit is committed and constrained by
+/// `prefix = x + shifted + x * shifted`, where `shifted` is the running OR up to the previous bit.
+/// The OR of all bits is the running OR at the most significant bit.
+fn any_bit(builder: &mut ConstraintSystemBuilder, x: OracleId) -> Result<OracleId, anyhow::Error> {
+	let log_rows = builder.log_rows([x])?;
+	let prefix = builder.add_committed("prefix_or", log_rows, B1::TOWER_LEVEL);
+	let prefix_shift = builder.add_shifted(
+		"prefix_or_shift",
+		prefix,
+		1,
+		LOG_U32_BITS,
+		ShiftVariant::LogicalLeft,
+	)?;
+
+	if let Some(witness) = builder.witness() {
+		(
+			witness.get::<B1>(x)?.as_slice::<u32>(),
+			witness.new_column::<B1>(prefix).as_mut_slice::<u32>(),
+			witness.new_column::<B1>(prefix_shift).as_mut_slice::<u32>(),
+		)
+			.into_par_iter()
+			.for_each(|(x, prefix, prefix_shift)| {
+				let mut or = *x;
+				for shift in [1, 2, 4, 8, 16] {
+					or |= or << shift;
+				}
+				*prefix = or;
+				*prefix_shift = or << 1;
+			});
+	}
+
+	builder.assert_zero(
+		"prefix_or",
+		[x, prefix_shift, prefix],
+		arith_expr!([x, shifted, prefix] = x + shifted + x * shifted - prefix).convert_field(),
+	);
+	select_bit(builder, "any", prefix, 31)
+}
+
+/// Negates a B1 column.
+fn not(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	x: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	let log_rows = builder.log_rows([x])?;
+	let out = builder.add_linear_combination_with_offset(name, log_rows, F::ONE, [(x, F::ONE)])?;
+
+	if let Some(witness) = builder.witness() {
+		let x = witness.get::<B1>(x)?;
+		let mut out = witness.new_column::<B1>(out);
+		let out = out.packed();
+		for i in 0..1 << log_rows {
+			set_packed_slice(out, i, get_packed_slice(x.packed(), i) + B1::ONE);
+		}
+	}
+
+	Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::{constraint_system::channel::Boundary, oracle::OracleId};
+	use binius_field::{packed::get_packed_slice, BinaryField1b};
+
+	use super::{u32_eq, u32_ge, u32_gt, u32_le, u32_lt};
+	use crate::{
+		builder::{
+			test_utils::{test_circuit, test_operands},
+			types::F,
+			ConstraintSystemBuilder,
+		},
+		unconstrained::fixed_u32,
+	};
+
+	const LOG_SIZE: usize = 10;
+
+	/// The boundary cases, followed by pseudo-random pairs of operands.
+	const EDGE_CASES: [(u32, u32); 5] = [(0, 1), (1, 0), (u32::MAX, 0), (0, u32::MAX), (5, 5)];
+
+	type Comparison = fn(
+		&mut ConstraintSystemBuilder,
+		&str,
+		OracleId,
+		OracleId,
+	) -> Result<OracleId, anyhow::Error>;
+
+	fn operands() -> (Vec<u32>, Vec<u32>) {
+		let (a, _) = test_operands(1 << (LOG_SIZE - 5), EDGE_CASES);
+		let b = a
+			.iter()
+			.enumerate()
+			.map(|(i, &a)| match EDGE_CASES.get(i) {
+				Some(&(_, b)) => b,
+				// Every fourth pair is equal, the others differ at most in their two lowest bits.
+				None if i % 4 == 0 => a,
+				None => a ^ (i % 3) as u32,
+			})
+			.collect();
+		(a, b)
+	}
+
+	fn check(
+		builder: &mut ConstraintSystemBuilder,
+		comparison: Comparison,
+		expected: fn(u32, u32) -> bool,
+	) -> Result<Vec<Boundary<F>>, anyhow::Error> {
+		let (a_values, b_values) = operands();
+		let a = fixed_u32::<BinaryField1b>(builder, "a", LOG_SIZE, a_values.clone())?;
+		let b = fixed_u32::<BinaryField1b>(builder, "b", LOG_SIZE, b_values.clone())?;
+		let out = comparison(builder, "comparison", a, b)?;
+
+		if let Some(witness) = builder.witness() {
+			let out = witness.get::<BinaryField1b>(out)?;
+			for (row, (&a, &b)) in a_values.iter().zip(&b_values).enumerate() {
+				let expected = BinaryField1b::from(expected(a, b));
+				assert_eq!(get_packed_slice(out.packed(), row), expected, "{a} vs {b}");
+			}
+		}
+		Ok(vec![])
+	}
+
+	#[test]
+	fn test_u32_lt() {
+		test_circuit(|builder| {
+			check(builder, |builder, name, a, b| u32_lt(builder, name, a, b), |a, b| a < b)
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_u32_le() {
+		test_circuit(|builder| {
+			check(builder, |builder, name, a, b| u32_le(builder, name, a, b), |a, b| a <= b)
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_u32_gt() {
+		test_circuit(|builder| {
+			check(builder, |builder, name, a, b| u32_gt(builder, name, a, b), |a, b| a > b)
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_u32_ge() {
+		test_circuit(|builder| {
+			check(builder, |builder, name, a, b| u32_ge(builder, name, a, b), |a, b| a >= b)
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_u32_eq() {
+		test_circuit(|builder| {
+			check(builder, |builder, name, a, b| u32_eq(builder, name, a, b), |a, b| a == b)
+		})
+		.unwrap();
+	}
+}
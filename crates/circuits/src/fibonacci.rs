@@ -0,0 +1,178 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Fibonacci sequence over u32 with wrapping addition.
+//!
+//! Every row of the table holds a pair of consecutive Fibonacci numbers `(a, b)` and computes
+//! `c = a + b`. The row pulls `(a, b)` from a channel and pushes `(b, c)` back into it. The
+//! boundaries push the initial pair `(fib[0], fib[1]) = (0, 1)` and pull the final pair
+//! `(fib[count - 2], fib[count - 1])`, so the channel only balances if the table contains the
+//! whole sequence.
+
+use binius_core::{
+	constraint_system::{
+		channel::{Boundary, ChannelId, FlushDirection, OracleOrConst},
+		ConstraintSystem,
+	},
+	witness::MultilinearExtensionIndex,
+};
+use binius_field::{as_packed_field::PackedType, BinaryField1b, BinaryField32b, TowerField};
+use binius_utils::checked_arithmetics::log2_ceil_usize;
+
+use crate::{
+	arithmetic,
+	builder::{
+		types::{F, U},
+		ConstraintSystemBuilder,
+	},
+};
+
+/// The witness and boundaries of a Fibonacci circuit.
+pub struct FibonacciWitness<'arena> {
+	pub witness: MultilinearExtensionIndex<'arena, PackedType<U, F>>,
+	pub boundaries: Vec<Boundary<F>>,
+}
+
+/// Returns the first `count` Fibonacci numbers, computed with wrapping u32 addition.
+///
+/// ```
+/// assert_eq!(
+///     binius_circuits::fibonacci::fibonacci_sequence(8),
+///     vec![0, 1, 1, 2, 3, 5, 8, 13]
+/// )
+/// ```
+pub fn fibonacci_sequence(count: u32) -> Vec<u32> {
+	let mut sequence = Vec::with_capacity(count as usize);
+	let (mut a, mut b) = (0u32, 1u32);
+	for _ in 0..count {
+		sequence.push(a);
+		(a, b) = (b, a.wrapping_add(b));
+	}
+	sequence
+}
+
+/// Adds the Fibonacci table proving the first `count` numbers of the sequence.
+///
+/// Returns the boundaries encoding the initial and terminal conditions. `count` must be at
+/// least 3, so that the table has at least one row.
+pub fn fibonacci(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	count: u32,
+) -> Result<Vec<Boundary<F>>, anyhow::Error> {
+	anyhow::ensure!(count >= 3, "Fibonacci sequence must have at least 3 elements. Got {count}");
+
+	let sequence = fibonacci_sequence(count);
+	let n_rows = count as usize - 2;
+	let log_1b_rows = 5 + log2_ceil_usize(n_rows);
+
+	builder.push_namespace(name);
+	let channel = builder.add_channel();
+
+	let a = builder.add_committed("a", log_1b_rows, BinaryField1b::TOWER_LEVEL);
+	let b = builder.add_committed("b", log_1b_rows, BinaryField1b::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		witness.new_column::<BinaryField1b>(a).as_mut_slice::<u32>()[..n_rows]
+			.copy_from_slice(&sequence[..n_rows]);
+		witness.new_column::<BinaryField1b>(b).as_mut_slice::<u32>()[..n_rows]
+			.copy_from_slice(&sequence[1..n_rows + 1]);
+	}
+
+	let c = arithmetic::u32::add(builder, "c", a, b, arithmetic::Flags::Unchecked)?;
+
+	let a_packed = arithmetic::u32::packed(builder, "a_packed", a)?;
+	let b_packed = arithmetic::u32::packed(builder, "b_packed", b)?;
+	let c_packed = arithmetic::u32::packed(builder, "c_packed", c)?;
+
+	builder.receive(
+		channel,
+		n_rows,
+		[
+			OracleOrConst::Oracle(a_packed),
+			OracleOrConst::Oracle(b_packed),
+		],
+	)?;
+	builder.send(
+		channel,
+		n_rows,
+		[
+			OracleOrConst::Oracle(b_packed),
+			OracleOrConst::Oracle(c_packed),
+		],
+	)?;
+	builder.pop_namespace();
+
+	Ok(boundaries(channel, sequence[n_rows], sequence[n_rows + 1]))
+}
+
+fn boundaries(channel_id: ChannelId, second_to_last: u32, last: u32) -> Vec<Boundary<F>> {
+	let pair = |a: u32, b: u32| vec![BinaryField32b::new(a).into(), BinaryField32b::new(b).into()];
+	vec![
+		Boundary {
+			channel_id,
+			direction: FlushDirection::Push,
+			values: pair(0, 1),
+			multiplicity: 1,
+		},
+		Boundary {
+			channel_id,
+			direction: FlushDirection::Pull,
+			values: pair(second_to_last, last),
+			multiplicity: 1,
+		},
+	]
+}
+
+/// Generates the witness of the Fibonacci circuit for the first `count` numbers.
+pub fn fibonacci_witness(
+	count: u32,
+	allocator: &bumpalo::Bump,
+) -> Result<FibonacciWitness<'_>, anyhow::Error> {
+	let mut builder = ConstraintSystemBuilder::new_with_witness(allocator);
+	let boundaries = fibonacci(&mut builder, "fibonacci", count)?;
+	let witness = builder.take_witness()?;
+	Ok(FibonacciWitness {
+		witness,
+		boundaries,
+	})
+}
+
+/// Builds the constraint system and boundaries of the Fibonacci circuit for the first `count`
+/// numbers.
+pub fn fibonacci_constraint_system(
+	count: u32,
+) -> Result<(ConstraintSystem<F>, Vec<Boundary<F>>), anyhow::Error> {
+	let mut builder = ConstraintSystemBuilder::new();
+	let boundaries = fibonacci(&mut builder, "fibonacci", count)?;
+	Ok((builder.build()?, boundaries))
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::validate::validate_witness;
+
+	use super::{fibonacci_constraint_system, fibonacci_witness};
+	use crate::builder::test_utils::test_circuit;
+
+	#[test]
+	fn test_fibonacci() {
+		test_circuit(|builder| super::fibonacci(builder, "fibonacci", 50)).unwrap();
+	}
+
+	#[test]
+	fn test_fibonacci_witness() {
+		let allocator = bumpalo::Bump::new();
+		let witness = fibonacci_witness(100, &allocator).unwrap();
+		let (constraint_system, boundaries) = fibonacci_constraint_system(100).unwrap();
+		assert_eq!(witness.boundaries, boundaries);
+		validate_witness(&constraint_system, &boundaries, &witness.witness).unwrap();
+	}
+
+	#[test]
+	fn test_fibonacci_wrong_terminal_value() {
+		let allocator = bumpalo::Bump::new();
+		let witness = fibonacci_witness(100, &allocator).unwrap();
+		let (constraint_system, mut boundaries) = fibonacci_constraint_system(100).unwrap();
+		boundaries[1].values.swap(0, 1);
+		assert!(validate_witness(&constraint_system, &boundaries, &witness.witness).is_err());
+	}
+}
@@ -25,9 +25,35 @@ use crate::{
 #[derive(Default, Clone, Copy)]
 pub struct KeccakfState(pub [u64; STATE_SIZE]);
 
+/// The oracles of the 25 lanes of a Keccak-f state.
+///
+/// Lanes are stored in the standard Keccak order, where lane `(x, y)` is at index `x + 5 * y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeccakState(pub [OracleId; STATE_SIZE]);
+
+impl KeccakState {
+	pub const fn from_oracles(ids: [OracleId; STATE_SIZE]) -> Self {
+		Self(ids)
+	}
+
+	/// Returns the oracle of the lane at column `x` and row `y`.
+	///
+	/// ## Preconditions
+	///
+	/// * `x` and `y` must be less than 5
+	pub fn lane(&self, x: usize, y: usize) -> OracleId {
+		assert!(x < 5 && y < 5, "lane coordinates ({x}, {y}) out of range");
+		self.0[x + 5 * y]
+	}
+
+	pub const fn into_oracles(self) -> [OracleId; STATE_SIZE] {
+		self.0
+	}
+}
+
 pub struct KeccakfOracles {
-	pub input: [OracleId; STATE_SIZE],
-	pub output: [OracleId; STATE_SIZE],
+	pub input: KeccakState,
+	pub output: KeccakState,
 }
 
 pub fn keccakf(
@@ -437,7 +463,10 @@ pub fn keccakf(
 		)
 	}
 
-	Ok(KeccakfOracles { input, output })
+	Ok(KeccakfOracles {
+		input: KeccakState::from_oracles(input),
+		output: KeccakState::from_oracles(output),
+	})
 }
 
 #[inline]
@@ -512,7 +541,7 @@ const KECCAKF_RC: [u64; ROUNDS_PER_PERMUTATION] = [
 mod tests {
 	use rand::{rngs::StdRng, Rng, SeedableRng};
 
-	use super::{keccakf, KeccakfState};
+	use super::{keccakf, KeccakState, KeccakfState};
 	use crate::builder::test_utils::test_circuit;
 
 	#[test]
@@ -526,4 +555,13 @@ mod tests {
 		})
 		.unwrap();
 	}
+
+	#[test]
+	fn test_keccak_state_lane() {
+		let state = KeccakState::from_oracles(std::array::from_fn(|xy| xy));
+		assert_eq!(state.lane(0, 0), 0);
+		assert_eq!(state.lane(2, 3), 17);
+		assert_eq!(state.lane(3, 2), 13);
+		assert_eq!(state.into_oracles()[24], state.lane(4, 4));
+	}
 }
@@ -9,13 +9,12 @@ use binius_field::{
 
 use super::{lasso::lasso, u32add::SeveralU32add};
 use crate::{
-	arithmetic::u32::u32const_repeating,
 	builder::{
 		types::{F, U},
 		ConstraintSystemBuilder,
 	},
 	pack::pack,
-	sha256::{rotate_and_xor, RotateRightType, INIT, ROUND_CONSTS_K},
+	sha256::{rotate_and_xor, sha256_iv_oracles, sha256_round_constants_oracle, RotateRightType},
 };
 
 pub const CH_MAJ_T_LOG_SIZE: usize = 12;
@@ -224,12 +223,11 @@ pub fn sha256(
 		)?;
 	}
 
-	let init_oracles = INIT.map(|val| u32const_repeating(n_vars, builder, val, "INIT").unwrap());
+	let init_oracles = sha256_iv_oracles(builder, n_vars)?;
 
 	let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = init_oracles;
 
-	let k = ROUND_CONSTS_K
-		.map(|val| u32const_repeating(n_vars, builder, val, "ROUND_CONSTS_K").unwrap());
+	let k = sha256_round_constants_oracle(builder, n_vars)?;
 
 	for i in 0..64 {
 		let sigma1 = rotate_and_xor(
@@ -12,14 +12,30 @@ pub mod arithmetic;
 pub mod bitwise;
 pub mod blake3;
 pub mod builder;
+pub mod byte_decomp;
 pub mod collatz;
+pub mod comparator;
+pub mod fibonacci;
 pub mod keccakf;
 pub mod lasso;
+pub mod lookup;
+pub mod memory;
+pub mod merkle;
+pub mod mux;
 mod pack;
 pub mod plain_lookup;
+pub mod prelude;
+pub mod range;
 pub mod sha256;
+pub mod sort;
 pub mod transparent;
+pub mod u16add;
 pub mod u32fib;
+pub mod u32mul;
+pub mod u32sub;
+pub mod u64add;
+pub mod u8mul;
+pub mod uadd;
 pub mod unconstrained;
 pub mod vision;
 
@@ -29,15 +45,18 @@ mod tests {
 		constraint_system::{
 			self,
 			channel::{validate_witness, Boundary, FlushDirection, OracleOrConst},
+			ConstraintSystem, Proof,
 		},
 		fiat_shamir::HasherChallenger,
-		oracle::ShiftVariant,
+		oracle::{OracleId, ShiftVariant},
 		polynomial::ArithCircuitPoly,
 		tower::CanonicalTowerFamily,
+		witness::MultilinearExtensionIndex,
 	};
 	use binius_field::{
-		arch::OptimalUnderlier, as_packed_field::PackedType, underlier::WithUnderlier,
-		BinaryField128b, BinaryField1b, BinaryField64b, BinaryField8b, Field, TowerField,
+		arch::OptimalUnderlier, as_packed_field::PackedType, packed::get_packed_slice,
+		underlier::WithUnderlier, BinaryField128b, BinaryField1b, BinaryField64b, BinaryField8b,
+		Field, TowerField,
 	};
 	use binius_hal::make_portable_backend;
 	use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
@@ -49,14 +68,62 @@ mod tests {
 	type B64 = BinaryField64b;
 
 	use crate::{
+		arithmetic,
 		builder::{
-			test_utils::test_circuit,
+			test_utils::{test_circuit, LOG_INV_RATE, SECURITY_BITS},
 			types::{F, U},
 			ConstraintSystemBuilder,
 		},
 		unconstrained::unconstrained,
 	};
 
+	/// Adds an unchecked u32 addition of two unconstrained columns of `1 << n_vars` bits, the
+	/// circuit shared by the proving tests. Returns the first operand and the overflow flag.
+	fn u32_add_circuit(
+		builder: &mut ConstraintSystemBuilder,
+		n_vars: usize,
+	) -> Result<(OracleId, OracleId), anyhow::Error> {
+		let a = unconstrained::<BinaryField1b>(builder, "a", n_vars)?;
+		let b = unconstrained::<BinaryField1b>(builder, "b", n_vars)?;
+		let (_, overflow) =
+			arithmetic::u32::add_with_overflow(builder, "sum", a, b, arithmetic::Flags::Unchecked)?;
+		Ok((a, overflow.into()))
+	}
+
+	fn prove(
+		constraint_system: &ConstraintSystem<F>,
+		boundaries: &[Boundary<F>],
+		witness: MultilinearExtensionIndex<PackedType<U, F>>,
+	) -> Proof {
+		constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(
+			constraint_system,
+			LOG_INV_RATE,
+			SECURITY_BITS,
+			boundaries,
+			witness,
+			&make_portable_backend(),
+		)
+		.unwrap()
+	}
+
+	fn verify(constraint_system: &ConstraintSystem<F>, boundaries: &[Boundary<F>], proof: Proof) {
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(constraint_system, LOG_INV_RATE, SECURITY_BITS, boundaries, proof)
+		.unwrap();
+	}
+
 	#[test]
 	fn test_boundaries() {
 		// Proving Collatz Orbits
@@ -148,26 +215,282 @@ mod tests {
 
 		let constraint_system = builder.build().unwrap();
 
-		let backend = make_portable_backend();
+		let proof = prove(&constraint_system, &boundaries, witness);
+		verify(&constraint_system, &boundaries, proof);
+	}
+
+	#[test]
+	fn test_prove_and_verify() {
+		let allocator = bumpalo::Bump::new();
+		let mut prover_builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		u32_add_circuit(&mut prover_builder, 7).unwrap();
+		let proof = prover_builder
+			.prove_and_verify::<Groestl256, Groestl256ByteCompression, HasherChallenger<Groestl256>, _>(
+				LOG_INV_RATE,
+				SECURITY_BITS,
+				vec![],
+				&make_portable_backend(),
+			)
+			.unwrap();
+
+		let mut verifier_builder = ConstraintSystemBuilder::new();
+		u32_add_circuit(&mut verifier_builder, 7).unwrap();
+		verifier_builder
+			.verify_only::<Groestl256, Groestl256ByteCompression, HasherChallenger<Groestl256>>(
+				LOG_INV_RATE,
+				SECURITY_BITS,
+				vec![],
+				proof,
+			)
+			.unwrap();
+	}
+
+	#[test]
+	fn test_verify_batch() {
+		let proofs = (0..3)
+			.map(|_| {
+				let allocator = bumpalo::Bump::new();
+				let mut prover_builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+				u32_add_circuit(&mut prover_builder, 7).unwrap();
+				prover_builder
+					.prove_and_verify::<Groestl256, Groestl256ByteCompression, HasherChallenger<Groestl256>, _>(
+						LOG_INV_RATE,
+						SECURITY_BITS,
+						vec![],
+						&make_portable_backend(),
+					)
+					.unwrap()
+			})
+			.collect::<Vec<_>>();
 
-		let proof = constraint_system::prove::<
+		let mut verifier_builder = ConstraintSystemBuilder::new();
+		u32_add_circuit(&mut verifier_builder, 7).unwrap();
+		let constraint_system = verifier_builder.build().unwrap();
+
+		let verify_batch = |boundaries: &[Boundary<F>]| {
+			constraint_system::verify_batch::<
+				U,
+				CanonicalTowerFamily,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+			>(
+				&constraint_system,
+				LOG_INV_RATE,
+				SECURITY_BITS,
+				proofs.iter().map(|proof| (boundaries, proof.clone())),
+			)
+		};
+		verify_batch(&[]).unwrap();
+
+		// The boundaries are observed by the transcript, so changing them invalidates all proofs.
+		let boundary = Boundary {
+			values: vec![F::ONE],
+			channel_id: 0,
+			direction: FlushDirection::Push,
+			multiplicity: 1,
+		};
+		assert!(verify_batch(&[boundary]).is_err());
+	}
+
+	#[test]
+	fn test_reindex_oracles() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let removed = unconstrained::<BinaryField1b>(&mut builder, "removed", 7).unwrap();
+		let (a, overflow) = u32_add_circuit(&mut builder, 7).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		let mut with_gap = constraint_system.clone();
+		with_gap.oracles.remove_oracle(removed).unwrap();
+		let (reindexed, new_ids) = with_gap.clone().reindex_oracles();
+		assert_eq!(new_ids.len(), with_gap.oracles.size());
+		assert_eq!(new_ids[removed], None);
+		assert_eq!(new_ids[a], Some(a - 1));
+		// The overflow flag of an unchecked addition is not constrained by anything, but only
+		// removed oracles are dropped.
+		assert_eq!(new_ids[overflow], Some(overflow - 1));
+		assert_eq!(reindexed.oracles.size(), with_gap.oracles.size() - 1);
+
+		let mut reindexed_witness = MultilinearExtensionIndex::new();
+		reindexed_witness
+			.update_multilin_poly(with_gap.oracles.ids().map(|id| {
+				(
+					new_ids[id].expect("new id of a kept oracle"),
+					witness
+						.get_multilin_poly(id)
+						.expect("witness of a kept oracle"),
+				)
+			}))
+			.unwrap();
+
+		for (constraint_system, witness) in
+			[(constraint_system, witness), (reindexed, reindexed_witness)]
+		{
+			let proof = prove(&constraint_system, &[], witness);
+			verify(&constraint_system, &[], proof);
+		}
+	}
+
+	#[test]
+	fn test_remove_unreferenced_oracles() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let unused = unconstrained::<BinaryField1b>(&mut builder, "unused", 7).unwrap();
+		let (a, overflow) = u32_add_circuit(&mut builder, 7).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		let (reduced, new_ids) = constraint_system
+			.clone()
+			.remove_unreferenced_oracles()
+			.unwrap();
+		assert_eq!(new_ids.len(), constraint_system.oracles.size());
+		assert_eq!(new_ids[unused], None);
+		// The overflow flag of an unchecked addition is not constrained by anything.
+		assert_eq!(new_ids[overflow], None);
+		assert_eq!(new_ids[a], Some(0));
+		assert_eq!(reduced.oracles.size(), constraint_system.oracles.size() - 2);
+
+		let mut reduced_witness = MultilinearExtensionIndex::new();
+		reduced_witness
+			.update_multilin_poly(
+				new_ids.iter().enumerate().filter_map(|(id, new_id)| {
+					Some(((*new_id)?, witness.get_multilin_poly(id).ok()?))
+				}),
+			)
+			.unwrap();
+
+		let proof = prove(&reduced, &[], reduced_witness);
+		verify(&reduced, &[], proof);
+	}
+
+	#[test]
+	fn test_prove_with_stats() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		u32_add_circuit(&mut builder, 12).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		let (proof, stats) = constraint_system::prove_with_stats::<
 			U,
 			CanonicalTowerFamily,
 			Groestl256,
 			Groestl256ByteCompression,
 			HasherChallenger<Groestl256>,
 			_,
-		>(&constraint_system, 1, 10, &boundaries, witness, &backend)
+		>(
+			&constraint_system,
+			LOG_INV_RATE,
+			SECURITY_BITS,
+			&[],
+			witness,
+			&make_portable_backend(),
+		)
 		.unwrap();
 
-		constraint_system::verify::<
-			U,
-			CanonicalTowerFamily,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
-		>(&constraint_system, 1, 10, &boundaries, proof)
+		assert_eq!(stats.proof_bytes, proof.get_proof_size());
+		assert!(
+			stats.witness_loading_ms + stats.gkr_ms + stats.sumcheck_ms + stats.pcs_ms
+				<= stats.prove_ms,
+			"{stats:?}"
+		);
+
+		verify(&constraint_system, &[], proof);
+	}
+
+	#[test]
+	fn test_prove_and_verify_without_witness() {
+		let mut builder = ConstraintSystemBuilder::new();
+		builder.add_committed("a", 7, BinaryField1b::TOWER_LEVEL);
+		let err = builder
+			.prove_and_verify::<Groestl256, Groestl256ByteCompression, HasherChallenger<Groestl256>, _>(
+				LOG_INV_RATE,
+				SECURITY_BITS,
+				vec![],
+				&make_portable_backend(),
+			)
+			.unwrap_err();
+		assert_eq!(err.to_string(), "builder was created without witness");
+	}
+
+	#[test]
+	fn test_add_transparent_range() {
+		test_circuit(|builder| {
+			let n_vars = 7;
+			for (low, high) in [(5, 37), (0, 1 << n_vars), (9, 9)] {
+				let range = builder.add_transparent_range("range", n_vars, low, high)?;
+				if let Some(witness) = builder.witness() {
+					let values = witness.get::<BinaryField1b>(range)?;
+					for i in [0, 4, 5, 8, 9, 36, 37, 127] {
+						let expected = if (low..high).contains(&i) {
+							BinaryField1b::ONE
+						} else {
+							BinaryField1b::ZERO
+						};
+						assert_eq!(get_packed_slice(values.packed(), i), expected);
+					}
+				}
+			}
+			assert!(builder
+				.add_transparent_range("range", n_vars, 10, 9)
+				.is_err());
+			assert!(builder
+				.add_transparent_range("range", n_vars, 0, 129)
+				.is_err());
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_add_transparent_constant() {
+		test_circuit(|builder| {
+			let value = BinaryField8b::new(0x2a);
+			let constant = builder.add_transparent_constant("constant", 6, value)?;
+			if let Some(witness) = builder.witness() {
+				let values = witness
+					.get::<BinaryField8b>(constant)?
+					.as_slice::<BinaryField8b>();
+				assert!(values.iter().all(|v| *v == value));
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_fill_virtual_oracles() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let a = unconstrained::<BinaryField1b>(&mut builder, "a", 10).unwrap();
+		let shifted = builder
+			.add_shifted("shifted", a, 3, 5, ShiftVariant::LogicalRight)
+			.unwrap();
+		let packed = builder.add_packed("packed", a, 5).unwrap();
+		let sum = builder
+			.add_linear_combination_with_offset("sum", 10, F::ONE, [(a, F::ONE), (shifted, F::ONE)])
+			.unwrap();
+		let scaled = builder
+			.add_linear_combination("scaled", 5, [(packed, BinaryField8b::new(3).into())])
+			.unwrap();
+		let padded = builder.add_zero_padded("padded", scaled, 7).unwrap();
+		let repeated = builder.add_repeating("repeated", packed, 2).unwrap();
+
+		let mut witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		binius_core::witness::fill_virtual_oracles::<U, CanonicalTowerFamily>(
+			&mut witness,
+			&constraint_system.oracles,
+		)
 		.unwrap();
+
+		for id in [shifted, packed, sum, scaled, padded, repeated] {
+			assert!(witness.has(id));
+		}
+		constraint_system::validate::validate_witness(&constraint_system, &[], &witness).unwrap();
 	}
 
 	#[test]
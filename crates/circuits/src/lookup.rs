@@ -0,0 +1,155 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Lookups of B32 indices into constant tables of B128 values.
+//!
+//! The table lookup gadgets built on [`plain_lookup`] are re-exported here.
+
+use anyhow::{anyhow, ensure, Result};
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField32b, TowerField};
+
+pub use crate::plain_lookup::{multi_table_lookup, range_check_u8_table};
+use crate::{
+	builder::{types::F, ConstraintSystemBuilder},
+	plain_lookup::plain_lookup,
+	transparent,
+};
+
+/// A gadget computing `table[input]` for every row of the [`BinaryField32b`] column `input`.
+///
+/// Returns the committed column of looked up values. The table length must be a power of two,
+/// and the prover fails if an input row is not a valid table index.
+///
+/// The table is materialized as two transparent columns of indices and values, and the
+/// `(input, output)` pairs are checked against the `(index, value)` pairs with [`plain_lookup`],
+/// which pulls every table entry with its committed multiplicity.
+pub fn lookup<const LOG_MAX_MULTIPLICITY: usize>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	table: &[F],
+	input: OracleId,
+) -> Result<OracleId> {
+	ensure!(
+		table.len().is_power_of_two(),
+		"Table length must be a power of two. Got {}",
+		table.len()
+	);
+
+	builder.push_namespace(name);
+
+	let log_rows = builder.log_rows([input])?;
+	let indices = (0..table.len())
+		.map(|i| F::new(i as u128))
+		.collect::<Vec<_>>();
+	let table_index = transparent::make_transparent(builder, "table_index", &indices)?;
+	let table_value = transparent::make_transparent(builder, "table_value", table)?;
+	let output = builder.add_committed("output", log_rows, F::TOWER_LEVEL);
+
+	let multiplicities = if let Some(witness) = builder.witness() {
+		let mut multiplicities = vec![0; table.len()];
+		let mut output_column = witness.new_column::<F>(output);
+		for (output, &index) in output_column
+			.as_mut_slice::<F>()
+			.iter_mut()
+			.zip(witness.get::<BinaryField32b>(input)?.as_slice::<u32>())
+		{
+			let count = multiplicities
+				.get_mut(index as usize)
+				.ok_or_else(|| anyhow!("index {index} is out of the table bounds"))?;
+			*count += 1;
+			*output = table[index as usize];
+		}
+		Some(multiplicities)
+	} else {
+		None
+	};
+
+	plain_lookup::<F, LOG_MAX_MULTIPLICITY>(
+		builder,
+		"lookup",
+		&[1 << log_rows],
+		&[[input, output]],
+		&[table_index, table_value],
+		multiplicities,
+	)?;
+
+	builder.pop_namespace();
+	Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField32b;
+
+	use super::lookup;
+	use crate::{
+		builder::{
+			test_utils::{pseudo_random_words, test_circuit},
+			types::F,
+		},
+		unconstrained::fill_column_from_fn,
+	};
+
+	const LOG_SIZE: usize = 10;
+	const LOG_MAX_MULTIPLICITY: usize = 11;
+
+	#[test]
+	fn test_lookup_identity() {
+		test_circuit(|builder| {
+			let table = (0..256).map(F::new).collect::<Vec<_>>();
+			let bytes = pseudo_random_words::<u8>(1 << LOG_SIZE, 0);
+			let input =
+				fill_column_from_fn::<BinaryField32b, _>(builder, "input", LOG_SIZE, |i| {
+					BinaryField32b::new(bytes[i] as u32)
+				})?;
+			let output = lookup::<LOG_MAX_MULTIPLICITY>(builder, "lookup", &table, input)?;
+
+			if let Some(witness) = builder.witness() {
+				let input = witness.get::<BinaryField32b>(input)?.as_slice::<u32>();
+				let output = witness.get::<F>(output)?.as_slice::<F>();
+				for (&input, &output) in input.iter().zip(output) {
+					assert_eq!(output, F::new(input as u128));
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_lookup_custom() {
+		test_circuit(|builder| {
+			let table = (0..16u128)
+				.map(|i| F::new(i * i * 0x1234_5678_9abc_def0 + 7))
+				.collect::<Vec<_>>();
+			let input =
+				fill_column_from_fn::<BinaryField32b, _>(builder, "input", LOG_SIZE, |i| {
+					BinaryField32b::new((i % 13) as u32)
+				})?;
+			let output = lookup::<LOG_MAX_MULTIPLICITY>(builder, "lookup", &table, input)?;
+
+			if let Some(witness) = builder.witness() {
+				let output = witness.get::<F>(output)?.as_slice::<F>();
+				for (i, &output) in output.iter().enumerate() {
+					assert_eq!(output, table[i % 13]);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_lookup_out_of_bounds() {
+		assert!(test_circuit(|builder| {
+			let table = (0..16).map(F::new).collect::<Vec<_>>();
+			let input =
+				fill_column_from_fn::<BinaryField32b, _>(builder, "input", LOG_SIZE, |i| {
+					BinaryField32b::new(i as u32)
+				})?;
+			lookup::<LOG_MAX_MULTIPLICITY>(builder, "lookup", &table, input)?;
+			Ok(vec![])
+		})
+		.is_err());
+	}
+}
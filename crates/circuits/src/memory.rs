@@ -0,0 +1,244 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Consistency checks of read/write memory traces.
+//!
+//! A trace is a list of accesses `(address, timestamp, value, is_write)`. It is consistent if every
+//! read returns the value of the latest access to the same address with a smaller timestamp.
+//!
+//! The accesses are committed a second time sorted by address, then by timestamp, and a channel
+//! checks that the sorted accesses are a permutation of the trace. In the sorted trace, all the
+//! accesses to an address are consecutive and in chronological order, so it suffices to compare
+//! every access with the next one.
+
+use anyhow::ensure;
+use binius_core::{
+	constraint_system::channel::OracleOrConst,
+	oracle::{OracleId, ShiftVariant},
+};
+use binius_field::{
+	packed::{get_packed_slice, set_packed_slice},
+	BinaryField1b, TowerField,
+};
+use binius_macros::arith_expr;
+
+use crate::{
+	arithmetic::{self, u32::LOG_U32_BITS},
+	builder::{types::F, ConstraintSystemBuilder},
+	comparator::{u32_eq, u32_lt},
+	sort::{is_sorted_u32, next_row_u32},
+	transparent::step_down,
+};
+
+type B1 = BinaryField1b;
+
+/// Constrains a memory trace to be consistent.
+///
+/// `address`, `timestamp` and `value` are u32 columns, and `is_write` is a B1 column with one bit
+/// per access. The timestamps of the accesses to an address must be distinct. A read that is the
+/// first access to its address is unconstrained, which models arbitrary initial memory.
+pub fn check_memory(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	address: OracleId,
+	timestamp: OracleId,
+	value: OracleId,
+	is_write: OracleId,
+) -> Result<(), anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([address, timestamp, value])?;
+	ensure!(log_rows >= LOG_U32_BITS, "Polynomial must have n_vars >= 5. Got {log_rows}");
+	let log_count = log_rows - LOG_U32_BITS;
+	ensure!(builder.log_rows([is_write])? == log_count, "is_write must have one bit per access");
+
+	let sorted_address = builder.add_committed("sorted_address", log_rows, B1::TOWER_LEVEL);
+	let sorted_timestamp = builder.add_committed("sorted_timestamp", log_rows, B1::TOWER_LEVEL);
+	let sorted_value = builder.add_committed("sorted_value", log_rows, B1::TOWER_LEVEL);
+	let sorted_is_write = builder.add_committed("sorted_is_write", log_count, B1::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let address = witness.get::<B1>(address)?.as_slice::<u32>();
+		let timestamp = witness.get::<B1>(timestamp)?.as_slice::<u32>();
+		let value = witness.get::<B1>(value)?.as_slice::<u32>();
+		let is_write = witness.get::<B1>(is_write)?;
+
+		let mut order = (0..1 << log_count).collect::<Vec<_>>();
+		order.sort_by_key(|&i| (address[i], timestamp[i]));
+
+		let mut sorted_address = witness.new_column::<B1>(sorted_address);
+		let mut sorted_timestamp = witness.new_column::<B1>(sorted_timestamp);
+		let mut sorted_value = witness.new_column::<B1>(sorted_value);
+		let mut sorted_is_write = witness.new_column::<B1>(sorted_is_write);
+		let sorted_address = sorted_address.as_mut_slice::<u32>();
+		let sorted_timestamp = sorted_timestamp.as_mut_slice::<u32>();
+		let sorted_value = sorted_value.as_mut_slice::<u32>();
+		let sorted_is_write = sorted_is_write.packed();
+		for (j, &i) in order.iter().enumerate() {
+			sorted_address[j] = address[i];
+			sorted_timestamp[j] = timestamp[i];
+			sorted_value[j] = value[i];
+			set_packed_slice(sorted_is_write, j, get_packed_slice(is_write.packed(), i));
+		}
+	}
+
+	// The sorted trace is a permutation of the trace.
+	let channel = builder.add_channel();
+	let accesses = pack_accesses(builder, "access", [address, timestamp, value], is_write)?;
+	let sorted_accesses = pack_accesses(
+		builder,
+		"sorted_access",
+		[sorted_address, sorted_timestamp, sorted_value],
+		sorted_is_write,
+	)?;
+	builder.send(channel, 1 << log_count, accesses)?;
+	builder.receive(channel, 1 << log_count, sorted_accesses)?;
+
+	// The sorted trace is sorted by address, then by timestamp.
+	is_sorted_u32(builder, "address_sorted", sorted_address)?;
+	let next_address = next_row_u32(builder, "next_address", sorted_address)?;
+	let next_timestamp = next_row_u32(builder, "next_timestamp", sorted_timestamp)?;
+	let next_value = next_row_u32(builder, "next_value", sorted_value)?;
+	let next_is_write = builder.add_shifted(
+		"next_is_write",
+		sorted_is_write,
+		1,
+		log_count,
+		ShiftVariant::LogicalRight,
+	)?;
+	if let Some(witness) = builder.witness() {
+		let sorted_is_write = witness.get::<B1>(sorted_is_write)?;
+		let mut next_is_write = witness.new_column::<B1>(next_is_write);
+		let next_is_write = next_is_write.packed();
+		for j in 0..(1 << log_count) - 1 {
+			set_packed_slice(next_is_write, j, get_packed_slice(sorted_is_write.packed(), j + 1));
+		}
+	}
+
+	let same_address = u32_eq(builder, "same_address", sorted_address, next_address)?;
+	let later = u32_lt(builder, "later", sorted_timestamp, next_timestamp)?;
+	let same_value = u32_eq(builder, "same_value", sorted_value, next_value)?;
+
+	// The last access is compared with the zero padding of the shifted columns.
+	let enabled = step_down(builder, "enabled", log_count, (1 << log_count) - 1)?;
+	builder.assert_zero(
+		"chronological",
+		[enabled, same_address, later],
+		arith_expr!(F[enabled, same_address, later] = enabled * same_address * (later - 1)),
+	);
+	builder.assert_zero(
+		"read_consistency",
+		[enabled, same_address, next_is_write, same_value],
+		arith_expr!(
+			F[enabled, same_address, next_is_write, same_value] =
+				enabled * same_address * (next_is_write - 1) * (same_value - 1)
+		),
+	);
+
+	builder.pop_namespace();
+	Ok(())
+}
+
+/// Packs the u32 columns of the accesses into B32 columns, which can be flushed with the
+/// `is_write` bits.
+fn pack_accesses(
+	builder: &mut ConstraintSystemBuilder,
+	name: &str,
+	columns: [OracleId; 3],
+	is_write: OracleId,
+) -> Result<Vec<OracleOrConst<F>>, anyhow::Error> {
+	let mut packed = Vec::with_capacity(4);
+	for (column, field) in columns.into_iter().zip(["address", "timestamp", "value"]) {
+		let column = arithmetic::u32::packed(builder, format!("{name}_{field}"), column)?;
+		packed.push(OracleOrConst::Oracle(column));
+	}
+	packed.push(OracleOrConst::Oracle(is_write));
+	Ok(packed)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::HashMap;
+
+	use binius_field::BinaryField1b;
+
+	use super::check_memory;
+	use crate::{
+		builder::{test_utils::test_circuit, ConstraintSystemBuilder},
+		unconstrained::{fill_column_from_fn, fixed_u32},
+	};
+
+	const LOG_COUNT: usize = 5;
+
+	/// A trace of writes and reads over a few addresses, with the value of the `tampered_read`-th
+	/// read of a written address changed.
+	///
+	/// The accesses are listed out of chronological order, so that sorting them is not trivial.
+	fn trace(tampered_read: Option<usize>) -> (Vec<u32>, Vec<u32>, Vec<u32>, Vec<bool>) {
+		let mut memory = HashMap::new();
+		let mut reads = 0;
+		let accesses = (0..1u32 << LOG_COUNT)
+			.map(|timestamp| {
+				let address = (timestamp * 7) % 5 + 0x1000;
+				let is_write = timestamp % 3 == 0;
+				let value = if is_write {
+					memory.insert(address, timestamp * 1000 + 17);
+					timestamp * 1000 + 17
+				} else if let Some(&value) = memory.get(&address) {
+					reads += 1;
+					if Some(reads) == tampered_read {
+						value + 1
+					} else {
+						value
+					}
+				} else {
+					// The initial memory is arbitrary.
+					0xdead
+				};
+				(address, timestamp, value, is_write)
+			})
+			.collect::<Vec<_>>();
+
+		let mut trace = (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+		for row in 0..1 << LOG_COUNT {
+			let (address, timestamp, value, is_write) = accesses[(row * 13) % (1 << LOG_COUNT)];
+			trace.0.push(address);
+			trace.1.push(timestamp);
+			trace.2.push(value);
+			trace.3.push(is_write);
+		}
+		trace
+	}
+
+	fn build_circuit(
+		builder: &mut ConstraintSystemBuilder,
+		tampered_read: Option<usize>,
+	) -> Result<(), anyhow::Error> {
+		let (address, timestamp, value, is_write) = trace(tampered_read);
+		let log_rows = LOG_COUNT + 5;
+		let address = fixed_u32::<BinaryField1b>(builder, "address", log_rows, address)?;
+		let timestamp = fixed_u32::<BinaryField1b>(builder, "timestamp", log_rows, timestamp)?;
+		let value = fixed_u32::<BinaryField1b>(builder, "value", log_rows, value)?;
+		let is_write =
+			fill_column_from_fn::<BinaryField1b, _>(builder, "is_write", LOG_COUNT, |i| {
+				is_write[i]
+			})?;
+		check_memory(builder, "memory", address, timestamp, value, is_write)
+	}
+
+	#[test]
+	fn test_check_memory() {
+		test_circuit(|builder| {
+			build_circuit(builder, None)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_check_memory_wrong_read() {
+		assert!(test_circuit(|builder| {
+			build_circuit(builder, Some(3))?;
+			Ok(vec![])
+		})
+		.is_err());
+	}
+}
@@ -0,0 +1,205 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Merkle tree membership proofs over 256-bit digests.
+//!
+//! A digest is [`DIGEST_SIZE`] B32 elements. The two-to-one compression of the tree is the
+//! [Vision Mark-32] permutation in truncated mode: the state is the left and right children
+//! followed by a zero capacity, and the parent is the first [`DIGEST_SIZE`] elements of the
+//! permuted state. It is computed in-circuit with the [`vision`](crate::vision) gadget.
+//!
+//! [Vision Mark-32]: https://eprint.iacr.org/2024/633
+
+use std::array;
+
+use anyhow::{ensure, Result};
+use binius_core::oracle::OracleId;
+use binius_field::{
+	AESTowerField32b, BinaryField32b, Field, PackedAESBinaryField8x32b, PackedField,
+};
+use binius_hash::{permutation::Permutation, Vision32bPermutation};
+
+use crate::{builder::ConstraintSystemBuilder, mux::mux, vision::vision_permutation};
+
+type B32 = BinaryField32b;
+
+/// The number of B32 elements in a digest.
+pub const DIGEST_SIZE: usize = 8;
+
+/// The B32 columns of a digest.
+pub type DigestColumns = [OracleId; DIGEST_SIZE];
+
+/// Computes the root of the Merkle path from `leaf` through `siblings`, for every row.
+///
+/// `leaf` and `siblings` are digests, listed from the leaf level up. `path_bits` are the B1
+/// columns of the position of the node at every level, which is 1 if the node is the right child
+/// of its parent and 0 if it is the left child. Returns the root digest, which the caller should
+/// constrain to the expected root.
+pub fn merkle_verify(
+	builder: &mut ConstraintSystemBuilder,
+	leaf: DigestColumns,
+	siblings: &[DigestColumns],
+	path_bits: &[OracleId],
+) -> Result<DigestColumns> {
+	ensure!(
+		siblings.len() == path_bits.len(),
+		"Got {} siblings for {} path bits",
+		siblings.len(),
+		path_bits.len()
+	);
+
+	builder.push_namespace("merkle");
+	let log_rows = builder.log_rows(leaf.into_iter().chain(siblings.iter().flatten().copied()))?;
+	let capacity = builder.add_transparent_constant("capacity", log_rows, B32::ZERO)?;
+
+	let mut node = leaf;
+	for (level, (sibling, &bit)) in siblings.iter().zip(path_bits).enumerate() {
+		builder.push_namespace(format!("level_{level}"));
+		let mut state = [capacity; 3 * DIGEST_SIZE];
+		for i in 0..DIGEST_SIZE {
+			state[i] = mux::<B32>(builder, format!("left_{i}"), bit, sibling[i], node[i])?;
+			state[DIGEST_SIZE + i] =
+				mux::<B32>(builder, format!("right_{i}"), bit, node[i], sibling[i])?;
+		}
+		let state = vision_permutation(builder, log_rows, state)?;
+		node = array::from_fn(|i| state[i]);
+		builder.pop_namespace();
+	}
+
+	builder.pop_namespace();
+	Ok(node)
+}
+
+/// Computes the two-to-one compression of the tree, outside of any circuit.
+pub fn compress(left: [B32; DIGEST_SIZE], right: [B32; DIGEST_SIZE]) -> [B32; DIGEST_SIZE] {
+	let input = [left, right, [B32::ZERO; DIGEST_SIZE]];
+	let mut state =
+		input.map(|words| PackedAESBinaryField8x32b::from_fn(|i| AESTowerField32b::from(words[i])));
+	Vision32bPermutation::default().permute_mut(&mut state);
+	array::from_fn(|i| B32::from(state[0].get(i)))
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField1b, BinaryField32b};
+
+	use super::{compress, merkle_verify, DIGEST_SIZE};
+	use crate::{
+		builder::{
+			test_utils::{pseudo_random_words, test_circuit},
+			ConstraintSystemBuilder,
+		},
+		unconstrained::fill_column_from_fn,
+	};
+
+	type Digest = [BinaryField32b; DIGEST_SIZE];
+
+	const DEPTH: usize = 4;
+	const LOG_SIZE: usize = 3;
+
+	/// The levels of the tree over `2^DEPTH` leaves, from the leaves up to the root.
+	fn tree() -> Vec<Vec<Digest>> {
+		let leaves = pseudo_random_words::<[u32; DIGEST_SIZE]>(1 << DEPTH, 0)
+			.into_iter()
+			.map(|words| words.map(BinaryField32b::new))
+			.collect::<Vec<_>>();
+		let mut levels = vec![leaves];
+		while levels.last().unwrap().len() > 1 {
+			let level = levels
+				.last()
+				.unwrap()
+				.chunks_exact(2)
+				.map(|pair| compress(pair[0], pair[1]))
+				.collect();
+			levels.push(level);
+		}
+		levels
+	}
+
+	/// The leaf index proven on the row `z`.
+	const fn leaf_index(z: usize) -> usize {
+		(5 * z + 3) % (1 << DEPTH)
+	}
+
+	/// Adds the B32 columns of a digest whose value on the row `z` is `f(z)`.
+	fn digest_columns(
+		builder: &mut ConstraintSystemBuilder,
+		name: &str,
+		f: impl Fn(usize) -> Digest + Sync,
+	) -> Result<super::DigestColumns, anyhow::Error> {
+		array_util::try_from_fn(|i| {
+			fill_column_from_fn::<BinaryField32b, _>(
+				builder,
+				format!("{name}_{i}"),
+				LOG_SIZE,
+				|z| f(z)[i],
+			)
+		})
+	}
+
+	/// Proves the membership of the leaves, with the leaf on the row `tampered_row` replaced.
+	fn build_circuit(
+		builder: &mut ConstraintSystemBuilder,
+		tampered_row: Option<usize>,
+	) -> Result<(), anyhow::Error> {
+		let tree = tree();
+		let leaf = digest_columns(builder, "leaf", |z| {
+			let mut leaf = tree[0][leaf_index(z)];
+			if Some(z) == tampered_row {
+				leaf[0] += BinaryField32b::new(1);
+			}
+			leaf
+		})?;
+		let mut siblings = Vec::new();
+		let mut path_bits = Vec::new();
+		for (level, nodes) in tree.iter().enumerate().take(DEPTH) {
+			siblings.push(digest_columns(builder, &format!("sibling_{level}"), |z| {
+				nodes[(leaf_index(z) >> level) ^ 1]
+			})?);
+			path_bits.push(fill_column_from_fn::<BinaryField1b, _>(
+				builder,
+				format!("path_bit_{level}"),
+				LOG_SIZE,
+				|z| (leaf_index(z) >> level) & 1 == 1,
+			)?);
+		}
+
+		let root = merkle_verify(builder, leaf, &siblings, &path_bits)?;
+		for (i, &root) in root.iter().enumerate() {
+			let expected_root = builder.add_transparent_constant(
+				format!("expected_root_{i}"),
+				LOG_SIZE,
+				tree[DEPTH][0][i],
+			)?;
+			builder.assert_eq(format!("root_{i}"), root, expected_root)?;
+		}
+
+		if let (Some(witness), None) = (builder.witness(), tampered_row) {
+			for (i, &root) in root.iter().enumerate() {
+				let root = witness.get::<BinaryField32b>(root)?;
+				assert!(root
+					.as_slice::<BinaryField32b>()
+					.iter()
+					.all(|&root| root == tree[DEPTH][0][i]));
+			}
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_merkle_verify() {
+		test_circuit(|builder| {
+			build_circuit(builder, None)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_merkle_verify_wrong_leaf() {
+		assert!(test_circuit(|builder| {
+			build_circuit(builder, Some(2))?;
+			Ok(vec![])
+		})
+		.is_err());
+	}
+}
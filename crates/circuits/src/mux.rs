@@ -0,0 +1,145 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Conditional selection between two columns.
+
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::PackScalar,
+	packed::{get_packed_slice, set_packed_slice},
+	BinaryField1b, ExtensionField, Field, TowerField,
+};
+use binius_macros::arith_expr;
+
+use crate::builder::{
+	types::{F, U},
+	ConstraintSystemBuilder,
+};
+
+/// Selects `a` in the rows where `selector` is 1, and `b` in the rows where it is 0.
+///
+/// `selector` is a B1 column, `a` and `b` are columns of the same size over `FS`. The output is
+/// committed and constrained by `out = b + selector * (a - b)`, and the selector is additionally
+/// constrained to be boolean.
+pub fn mux<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	selector: OracleId,
+	a: OracleId,
+	b: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	FS: TowerField,
+	U: PackScalar<FS>,
+	F: ExtensionField<FS>,
+{
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([selector, a, b])?;
+	let out = builder.add_committed("out", log_rows, FS::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let selector = witness.get::<BinaryField1b>(selector)?;
+		let a = witness.get::<FS>(a)?;
+		let b = witness.get::<FS>(b)?;
+		let mut out = witness.new_column::<FS>(out);
+		let out = out.packed();
+		for i in 0..1 << log_rows {
+			let value = if get_packed_slice(selector.packed(), i) == BinaryField1b::ONE {
+				get_packed_slice(a.packed(), i)
+			} else {
+				get_packed_slice(b.packed(), i)
+			};
+			set_packed_slice(out, i, value);
+		}
+	}
+
+	mux_constraints(builder, selector, a, b, out);
+	builder.pop_namespace();
+	Ok(out)
+}
+
+/// Constrains `out` to be the selection made by `selector`, which must be boolean.
+///
+/// This is the constraint of [`mux`], for gadgets that populate `out` themselves.
+pub(crate) fn mux_constraints(
+	builder: &mut ConstraintSystemBuilder,
+	selector: OracleId,
+	a: OracleId,
+	b: OracleId,
+	out: OracleId,
+) {
+	builder.assert_zero(
+		"mux",
+		[selector, a, b, out],
+		arith_expr!([s, a, b, out] = out - b - s * (a - b)).convert_field(),
+	);
+	builder.assert_boolean("selector_boolean", selector);
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_field::{BinaryField1b, BinaryField32b};
+
+	use super::{mux, mux_constraints};
+	use crate::{
+		builder::{test_utils::test_circuit, ConstraintSystemBuilder},
+		unconstrained::fill_column_from_fn,
+	};
+
+	const LOG_SIZE: usize = 8;
+
+	#[test]
+	fn test_mux() {
+		test_circuit(|builder| {
+			let selector =
+				fill_column_from_fn::<BinaryField1b, _>(builder, "selector", LOG_SIZE, |i| {
+					i % 3 == 0
+				})?;
+			let a = fill_column_from_fn::<BinaryField32b, _>(builder, "a", LOG_SIZE, |_| {
+				BinaryField32b::new(5)
+			})?;
+			let b = fill_column_from_fn::<BinaryField32b, _>(builder, "b", LOG_SIZE, |_| {
+				BinaryField32b::new(3)
+			})?;
+			let out = mux::<BinaryField32b>(builder, "mux", selector, a, b)?;
+
+			if let Some(witness) = builder.witness() {
+				let out = witness.get::<BinaryField32b>(out)?.as_slice::<u32>();
+				for (i, &value) in out.iter().enumerate() {
+					assert_eq!(value, if i % 3 == 0 { 5 } else { 3 });
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_mux_non_boolean_selector() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let selector = BinaryField32b::new(2);
+		let a = BinaryField32b::new(5);
+		let b = BinaryField32b::new(3);
+		// The output satisfies the mux relation, so only the boolean check can reject it.
+		let out = b + selector * (a - b);
+
+		let selector_id =
+			fill_column_from_fn::<BinaryField32b, _>(&mut builder, "selector", LOG_SIZE, |_| {
+				selector
+			})
+			.unwrap();
+		let a_id =
+			fill_column_from_fn::<BinaryField32b, _>(&mut builder, "a", LOG_SIZE, |_| a).unwrap();
+		let b_id =
+			fill_column_from_fn::<BinaryField32b, _>(&mut builder, "b", LOG_SIZE, |_| b).unwrap();
+		let out_id =
+			fill_column_from_fn::<BinaryField32b, _>(&mut builder, "out", LOG_SIZE, |_| out)
+				.unwrap();
+		mux_constraints(&mut builder, selector_id, a_id, b_id, out_id);
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		assert!(validate_witness(&constraint_system, &[], &witness).is_err());
+	}
+}
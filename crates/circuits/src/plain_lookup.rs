@@ -1,23 +1,27 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{cmp::Reverse, fmt::Debug, hash::Hash};
+use std::{cmp::Reverse, collections::HashMap, fmt::Debug, hash::Hash};
 
-use anyhow::{ensure, Result};
+use anyhow::{anyhow, ensure, Result};
 use binius_core::{
 	constraint_system::channel::{FlushDirection, OracleOrConst},
 	oracle::OracleId,
 };
 use binius_field::{
-	as_packed_field::PackScalar,
+	as_packed_field::{PackScalar, PackedType},
 	packed::{get_packed_slice, set_packed_slice},
-	BinaryField1b, ExtensionField, Field, PackedField, TowerField,
+	BinaryField1b, BinaryField32b, ExtensionField, Field, PackedField, TowerField,
 };
+use binius_utils::checked_arithmetics::log2_ceil_usize;
 use bytemuck::Pod;
-use itertools::izip;
-
-use crate::builder::{
-	types::{F, U},
-	ConstraintSystemBuilder,
+use itertools::{izip, Itertools};
+
+use crate::{
+	builder::{
+		types::{F, U},
+		ConstraintSystemBuilder,
+	},
+	transparent,
 };
 
 /// A gadget validating the lookup relation between:
@@ -180,6 +184,147 @@ where
 	Ok(())
 }
 
+/// A gadget looking up `key` in one of several u32 tables, selected on every row by `tag`.
+///
+/// Each table is given as `(tag_value, keys, values)`, mapping `keys[i]` to `values[i]`, where the
+/// keys of a table are distinct. Returns
+/// the committed column of looked up values, so that every row satisfies
+/// `value = table[tag](key)`. `key` and `tag` must be [`BinaryField32b`] columns of the same
+/// height, and every row is looked up.
+///
+/// All tables are merged into a single lookup table of `(tag, key, value)` tuples, which is
+/// checked with [`plain_lookup`]. Including the tag in the tuple routes every lookup to the table
+/// with the matching tag value. The merged table is padded to a power of two with copies of its
+/// first entry, so that padding does not introduce additional valid tuples.
+pub fn multi_table_lookup<const LOG_MAX_MULTIPLICITY: usize>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	key: OracleId,
+	tag: OracleId,
+	tables: &[(u32, Vec<u32>, Vec<u32>)],
+) -> Result<OracleId> {
+	ensure!(!tables.is_empty(), "at least one table is required");
+	ensure!(
+		tables.iter().map(|(tag_value, ..)| tag_value).all_unique(),
+		"table tag values must be distinct"
+	);
+	for (tag_value, keys, values) in tables {
+		ensure!(
+			!keys.is_empty() && keys.len() == values.len(),
+			"table {tag_value} must have the same non-zero number of keys and values"
+		);
+		ensure!(keys.iter().all_unique(), "table {tag_value} must have distinct keys");
+	}
+
+	let mut entries = tables
+		.iter()
+		.flat_map(|(tag_value, keys, values)| {
+			izip!(keys, values).map(|(&key, &value)| [*tag_value, key, value])
+		})
+		.collect::<Vec<_>>();
+	let log_table_len =
+		log2_ceil_usize(entries.len()).max(PackedType::<U, BinaryField32b>::LOG_WIDTH);
+	entries.resize(1 << log_table_len, entries[0]);
+
+	builder.push_namespace(name);
+
+	let log_rows = builder.log_rows([key, tag])?;
+	let table = ["table_tag", "table_key", "table_value"]
+		.into_iter()
+		.enumerate()
+		.map(|(i, name)| {
+			let column = entries
+				.iter()
+				.map(|entry| BinaryField32b::new(entry[i]))
+				.collect::<Vec<_>>();
+			transparent::make_transparent(builder, name, &column)
+		})
+		.collect::<Result<Vec<_>>>()?;
+	let value = builder.add_committed("value", log_rows, BinaryField32b::TOWER_LEVEL);
+
+	let multiplicities = if let Some(witness) = builder.witness() {
+		// The padding entries repeat the first entry, and resolve to it.
+		let entry_index = entries
+			.iter()
+			.enumerate()
+			.rev()
+			.map(|(i, &[tag, key, _])| ((tag, key), i))
+			.collect::<HashMap<_, _>>();
+
+		let mut multiplicities = vec![0; entries.len()];
+		let mut value_column = witness.new_column::<BinaryField32b>(value);
+		for (value, &tag, &key) in izip!(
+			value_column.as_mut_slice::<u32>(),
+			witness.get::<BinaryField32b>(tag)?.as_slice::<u32>(),
+			witness.get::<BinaryField32b>(key)?.as_slice::<u32>()
+		) {
+			let &i = entry_index
+				.get(&(tag, key))
+				.ok_or_else(|| anyhow!("key {key} not in table with tag {tag}"))?;
+			*value = entries[i][2];
+			multiplicities[i] += 1;
+		}
+		Some(multiplicities)
+	} else {
+		None
+	};
+
+	plain_lookup::<BinaryField32b, LOG_MAX_MULTIPLICITY>(
+		builder,
+		"lookup",
+		&[1 << log_rows],
+		&[[tag, key, value]],
+		&table,
+		multiplicities,
+	)?;
+
+	builder.pop_namespace();
+	Ok(value)
+}
+
+/// A gadget checking that every row of the [`BinaryField32b`] column `value` is less than 256.
+///
+/// The values are looked up with [`plain_lookup`] in a transparent table holding the 256 values
+/// `0..=255`. Every row of `value` is looked up, and the prover fails if a row is out of range.
+pub fn range_check_u8_table<const LOG_MAX_MULTIPLICITY: usize>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	value: OracleId,
+) -> Result<()> {
+	builder.push_namespace(name);
+
+	let log_rows = builder.log_rows([value])?;
+	let table_values = (0..=u8::MAX as u32)
+		.map(BinaryField32b::new)
+		.collect::<Vec<_>>();
+	let table = transparent::make_transparent(builder, "u8_table", &table_values)?;
+
+	let multiplicities = if let Some(witness) = builder.witness() {
+		let mut multiplicities = vec![0; table_values.len()];
+		for &value in witness.get::<BinaryField32b>(value)?.as_slice::<u32>() {
+			let count = multiplicities
+				.get_mut(value as usize)
+				.ok_or_else(|| anyhow!("value {value} is not in the u8 range"))?;
+			*count += 1;
+		}
+		Some(multiplicities)
+	} else {
+		None
+	};
+
+	plain_lookup::<BinaryField32b, LOG_MAX_MULTIPLICITY>(
+		builder,
+		"lookup",
+		&[1 << log_rows],
+		&[[value]],
+		&[table],
+		multiplicities,
+	)?;
+
+	builder.pop_namespace();
+	Ok(())
+}
+
 #[cfg(test)]
 pub mod test_plain_lookup {
 	use binius_field::BinaryField32b;
@@ -341,12 +486,37 @@ mod count_multiplicity_tests {
 
 #[cfg(test)]
 mod tests {
-	use binius_core::{fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
+	use binius_core::{
+		fiat_shamir::HasherChallenger, oracle::OracleId, tower::CanonicalTowerFamily,
+	};
+	use binius_field::{BinaryField32b, TowerField};
 	use binius_hal::make_portable_backend;
 	use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
 
-	use super::test_plain_lookup;
-	use crate::builder::ConstraintSystemBuilder;
+	use super::{multi_table_lookup, range_check_u8_table, test_plain_lookup};
+	use crate::builder::{test_utils::test_circuit, ConstraintSystemBuilder};
+
+	/// Overwrites the first row of the witness of the [`BinaryField32b`] column `id` after a
+	/// gadget has filled it, so that the witness no longer satisfies the constraints.
+	fn tamper_first_row(
+		builder: &mut ConstraintSystemBuilder,
+		id: OracleId,
+		tamper: impl FnOnce(u32) -> u32,
+	) -> Result<(), anyhow::Error> {
+		if let Some(witness) = builder.witness() {
+			let mut values = witness
+				.get::<BinaryField32b>(id)?
+				.as_slice::<u32>()
+				.to_vec();
+			values[0] = tamper(values[0]);
+			witness
+				.new_column::<BinaryField32b>(id)
+				.as_mut_slice::<u32>()
+				.copy_from_slice(&values);
+		}
+		Ok(())
+	}
 
 	#[test]
 	fn test_plain_u8_mul_lookup() {
@@ -405,4 +575,156 @@ mod tests {
 			.unwrap();
 		}
 	}
+
+	const MULTI_TABLE_LOG_ROWS: usize = 8;
+
+	fn tables() -> Vec<(u32, Vec<u32>, Vec<u32>)> {
+		let keys = (0..16).collect::<Vec<u32>>();
+		vec![
+			(1, keys.clone(), keys.iter().map(|k| k * k).collect()),
+			(2, keys.clone(), keys.iter().map(|k| k + 100).collect()),
+			(7, keys.clone(), keys.iter().map(|k| !k).collect()),
+		]
+	}
+
+	fn expected_value(tag: u32, key: u32) -> Option<u32> {
+		match tag {
+			1 => Some(key * key),
+			2 => Some(key + 100),
+			7 => Some(!key),
+			_ => None,
+		}
+	}
+
+	/// Looks up random keys with tags drawn from `tag_values`, and returns the looked up values.
+	fn multi_table_lookup_circuit(
+		builder: &mut ConstraintSystemBuilder,
+		tag_values: &[u32],
+	) -> Result<OracleId, anyhow::Error> {
+		let key = builder.add_committed("key", MULTI_TABLE_LOG_ROWS, BinaryField32b::TOWER_LEVEL);
+		let tag = builder.add_committed("tag", MULTI_TABLE_LOG_ROWS, BinaryField32b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let mut rng = StdRng::seed_from_u64(0);
+			let mut key_column = witness.new_column::<BinaryField32b>(key);
+			let mut tag_column = witness.new_column::<BinaryField32b>(tag);
+			for (key, tag) in key_column
+				.as_mut_slice::<u32>()
+				.iter_mut()
+				.zip(tag_column.as_mut_slice::<u32>())
+			{
+				*key = rng.gen_range(0..16);
+				*tag = tag_values[rng.gen_range(0..tag_values.len())];
+			}
+		}
+
+		let value = multi_table_lookup::<{ MULTI_TABLE_LOG_ROWS + 1 }>(
+			builder,
+			"lookup",
+			key,
+			tag,
+			&tables(),
+		)?;
+
+		if let Some(witness) = builder.witness() {
+			let keys = witness.get::<BinaryField32b>(key)?.as_slice::<u32>();
+			let tags = witness.get::<BinaryField32b>(tag)?.as_slice::<u32>();
+			let values = witness.get::<BinaryField32b>(value)?.as_slice::<u32>();
+			for ((&key, &tag), &value) in keys.iter().zip(tags).zip(values) {
+				assert_eq!(Some(value), expected_value(tag, key));
+			}
+		}
+		Ok(value)
+	}
+
+	#[test]
+	fn test_multi_table_lookup_routes_by_tag() {
+		test_circuit(|builder| {
+			multi_table_lookup_circuit(builder, &[1, 2, 7])?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_multi_table_lookup_unknown_tag() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		assert!(multi_table_lookup_circuit(&mut builder, &[1, 3]).is_err());
+	}
+
+	#[test]
+	fn test_multi_table_lookup_rejects_wrong_value() {
+		let result = test_circuit(|builder| {
+			let value = multi_table_lookup_circuit(builder, &[1, 2, 7])?;
+			tamper_first_row(builder, value, |value| value ^ 1)?;
+			Ok(vec![])
+		});
+		assert!(result.is_err());
+	}
+
+	#[test]
+	fn test_multi_table_lookup_duplicate_keys() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let key = builder.add_committed("key", MULTI_TABLE_LOG_ROWS, BinaryField32b::TOWER_LEVEL);
+		let tag = builder.add_committed("tag", MULTI_TABLE_LOG_ROWS, BinaryField32b::TOWER_LEVEL);
+		let tables = [(1, vec![3, 5, 3], vec![9, 25, 10])];
+		assert!(multi_table_lookup::<{ MULTI_TABLE_LOG_ROWS + 1 }>(
+			&mut builder,
+			"lookup",
+			key,
+			tag,
+			&tables
+		)
+		.is_err());
+	}
+
+	const RANGE_CHECK_LOG_ROWS: usize = 10;
+
+	/// Range checks the values `(7 * i) % (max_value + 1)`, and returns the checked column.
+	fn range_check_circuit(
+		builder: &mut ConstraintSystemBuilder,
+		max_value: u32,
+	) -> Result<OracleId, anyhow::Error> {
+		let value =
+			builder.add_committed("value", RANGE_CHECK_LOG_ROWS, BinaryField32b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			for (i, value) in witness
+				.new_column::<BinaryField32b>(value)
+				.as_mut_slice::<u32>()
+				.iter_mut()
+				.enumerate()
+			{
+				*value = (i as u32 * 7) % (max_value + 1);
+			}
+		}
+		range_check_u8_table::<{ RANGE_CHECK_LOG_ROWS + 1 }>(builder, "range_check", value)?;
+		Ok(value)
+	}
+
+	#[test]
+	fn test_range_check_u8_table() {
+		test_circuit(|builder| {
+			range_check_circuit(builder, 255)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_range_check_u8_table_out_of_range() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		assert!(range_check_circuit(&mut builder, 256).is_err());
+	}
+
+	#[test]
+	fn test_range_check_u8_table_rejects_out_of_range_value() {
+		let result = test_circuit(|builder| {
+			let value = range_check_circuit(builder, 255)?;
+			tamper_first_row(builder, value, |_| 256)?;
+			Ok(vec![])
+		});
+		assert!(result.is_err());
+	}
 }
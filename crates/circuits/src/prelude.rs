@@ -0,0 +1,18 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Re-exports of the types and gadgets most commonly needed to build a circuit.
+//!
+//! ```
+//! use binius_circuits::prelude::*;
+//! ```
+
+pub use binius_core::oracle::OracleId;
+pub use binius_macros::arith_expr;
+pub use binius_math::ArithExpr;
+
+pub use crate::{
+	bitwise::{and as bitwise_and, or as bitwise_or, xor},
+	builder::ConstraintSystemBuilder,
+	lasso::{u32add, u8add},
+	unconstrained::unconstrained,
+};
@@ -0,0 +1,137 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Range checks of B32 columns against arbitrary bit widths.
+
+use binius_core::oracle::{OracleId, ShiftVariant};
+use binius_field::{
+	as_packed_field::PackScalar, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField,
+	TowerField,
+};
+use binius_macros::arith_expr;
+use binius_maybe_rayon::prelude::*;
+
+use crate::builder::{
+	types::{F, U},
+	ConstraintSystemBuilder,
+};
+
+/// Constrains every value of the B32 column `x` to fit in `n_bits` bits.
+///
+/// Widths that are a multiple of 8 decompose `x` into byte limbs, other widths into bit limbs.
+/// The limbs are committed in a column over the limb field, packed back and constrained to equal
+/// `x`, and the limbs above `n_bits` are constrained to be zero by shifting them down within each
+/// value.
+pub fn range_check(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	x: OracleId,
+	n_bits: usize,
+) -> Result<(), anyhow::Error> {
+	anyhow::ensure!((1..=32).contains(&n_bits), "Bit width must be between 1 and 32. Got {n_bits}");
+
+	builder.push_namespace(name);
+	if n_bits % 8 == 0 {
+		check_limbs::<BinaryField8b>(builder, x, n_bits / 8)?;
+	} else {
+		check_limbs::<BinaryField1b>(builder, x, n_bits)?;
+	}
+	builder.pop_namespace();
+	Ok(())
+}
+
+/// Decomposes `x` into limbs over `FL` and constrains all but the lowest `n_limbs` to be zero.
+fn check_limbs<FL>(
+	builder: &mut ConstraintSystemBuilder,
+	x: OracleId,
+	n_limbs: usize,
+) -> Result<(), anyhow::Error>
+where
+	FL: TowerField,
+	U: PackScalar<FL>,
+	F: ExtensionField<FL>,
+{
+	let log_rows = builder.log_rows([x])?;
+	let log_limbs = BinaryField32b::TOWER_LEVEL - FL::TOWER_LEVEL;
+	let limbs = builder.add_committed("limbs", log_rows + log_limbs, FL::TOWER_LEVEL);
+	let packed = builder.add_packed("packed", limbs, log_limbs)?;
+	let high = (n_limbs < 1 << log_limbs)
+		.then(|| builder.add_shifted("high", limbs, n_limbs, log_limbs, ShiftVariant::LogicalRight))
+		.transpose()?;
+
+	if let Some(witness) = builder.witness() {
+		// The limbs of a value are its little-endian bit groups, so all columns share the layout
+		// of `x` as u32 words.
+		let x = witness.get::<BinaryField32b>(x)?.as_slice::<u32>();
+		witness
+			.new_column::<FL>(limbs)
+			.as_mut_slice::<u32>()
+			.copy_from_slice(x);
+		witness
+			.new_column::<BinaryField32b>(packed)
+			.as_mut_slice::<u32>()
+			.copy_from_slice(x);
+		if let Some(high) = high {
+			let shift = n_limbs << FL::TOWER_LEVEL;
+			(x, witness.new_column::<FL>(high).as_mut_slice::<u32>())
+				.into_par_iter()
+				.for_each(|(x, high)| {
+					*high = *x >> shift;
+				});
+		}
+	}
+
+	builder.assert_eq("recombination", x, packed)?;
+	if let Some(high) = high {
+		builder.assert_zero("high_zero", [high], arith_expr!([high] = high).convert_field());
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_field::BinaryField32b;
+
+	use super::range_check;
+	use crate::{
+		builder::{test_utils::pseudo_random_words, ConstraintSystemBuilder},
+		unconstrained::fill_column_from_fn,
+	};
+
+	const LOG_SIZE: usize = 8;
+
+	/// Range checks pseudo-random `n_bits`-bit values followed by a final `last` value.
+	fn check(n_bits: usize, last: u32) -> Result<(), anyhow::Error> {
+		let mask = u32::MAX >> (32 - n_bits);
+		let mut values = pseudo_random_words::<u32>(1 << LOG_SIZE, 0);
+		for value in &mut values {
+			*value &= mask;
+		}
+		values[(1 << LOG_SIZE) - 1] = last;
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let x = fill_column_from_fn::<BinaryField32b, _>(&mut builder, "x", LOG_SIZE, |i| {
+			BinaryField32b::new(values[i])
+		})?;
+		range_check(&mut builder, "range", x, n_bits)?;
+		let witness = builder.take_witness()?;
+		let constraint_system = builder.build()?;
+		validate_witness(&constraint_system, &[], &witness)?;
+		Ok(())
+	}
+
+	#[test]
+	fn test_range_check() {
+		for n_bits in [4, 10, 24, 32] {
+			check(n_bits, u32::MAX >> (32 - n_bits)).unwrap();
+		}
+	}
+
+	#[test]
+	fn test_range_check_out_of_range() {
+		for n_bits in [4, 10, 24] {
+			assert!(check(n_bits, 1 << n_bits).is_err());
+		}
+	}
+}
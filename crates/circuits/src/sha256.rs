@@ -29,6 +29,25 @@ pub const INIT: [u32; 8] = [
 	0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
 ];
 
+/// Returns u32 constant columns holding the SHA-256 initial hash values, [`INIT`], on every row.
+pub fn sha256_iv_oracles(
+	builder: &mut ConstraintSystemBuilder,
+	log_size: usize,
+) -> Result<[OracleId; 8], anyhow::Error> {
+	array_util::try_from_fn(|i| u32const_repeating(log_size, builder, INIT[i], "INIT"))
+}
+
+/// Returns u32 constant columns holding the 64 SHA-256 round constants, [`ROUND_CONSTS_K`], on
+/// every row.
+pub fn sha256_round_constants_oracle(
+	builder: &mut ConstraintSystemBuilder,
+	log_size: usize,
+) -> Result<[OracleId; 64], anyhow::Error> {
+	array_util::try_from_fn(|i| {
+		u32const_repeating(log_size, builder, ROUND_CONSTS_K[i], "ROUND_CONSTS_K")
+	})
+}
+
 pub enum RotateRightType {
 	Circular,
 	Logical,
@@ -93,6 +112,7 @@ pub fn rotate_and_xor(
 	Ok(result_oracle_id)
 }
 
+/// Computes the SHA-256 compression function on a single message block, starting from [`INIT`].
 pub fn sha256(
 	builder: &mut ConstraintSystemBuilder,
 	input: [OracleId; 16],
@@ -102,6 +122,161 @@ pub fn sha256(
 		Err(anyhow::Error::msg("log_size too small"))?
 	}
 
+	let w = schedule(builder, input, log_size)?;
+	let init_oracles = sha256_iv_oracles(builder, log_size)?;
+	let k = sha256_round_constants_oracle(builder, log_size)?;
+	compress(builder, init_oracles, w, &k, log_size)
+}
+
+/// Computes the SHA-256 hash of a message of `byte_len` bytes on every row.
+///
+/// `message` holds the message as `byte_len.div_ceil(4)` u32 columns of big-endian words. The
+/// trailing bytes of the last word, if `byte_len` is not a multiple of 4, are constrained to be
+/// zero. The message is padded with constants, since its length is fixed, and then hashed with
+/// `(byte_len + 9).div_ceil(64)` chained compressions. Returns the 8 words of the digest.
+///
+/// Every row of the `log_size` columns hashes an independent message, so `log_size` must be at
+/// least the log width of a packed binary field, independently of the number of message blocks.
+pub fn sha256_full(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	message: &[OracleId],
+	byte_len: usize,
+	log_size: usize,
+) -> Result<[OracleId; 8], anyhow::Error> {
+	if log_size < <PackedType<U, BinaryField1b>>::LOG_WIDTH {
+		Err(anyhow::Error::msg("log_size too small"))?
+	}
+	anyhow::ensure!(
+		message.len() == byte_len.div_ceil(4),
+		"Expected {} message words for {byte_len} bytes, got {}",
+		byte_len.div_ceil(4),
+		message.len()
+	);
+
+	builder.push_namespace(name);
+	let padded = pad(builder, message, byte_len, log_size)?;
+	let mut state = sha256_iv_oracles(builder, log_size)?;
+	let k = sha256_round_constants_oracle(builder, log_size)?;
+	for (i, block) in padded.chunks_exact(16).enumerate() {
+		builder.push_namespace(format!("block_{i}"));
+		let input = block.try_into().expect("chunks have exactly 16 words");
+		let w = schedule(builder, input, log_size)?;
+		state = compress(builder, state, w, &k, log_size)?;
+		builder.pop_namespace();
+	}
+	builder.pop_namespace();
+	Ok(state)
+}
+
+/// The 64-word SHA-256 message schedule of a message block.
+///
+/// Building the schedule is a large part of a compression circuit. Compressing the same block
+/// from several chaining values, as in HMAC, can share one schedule through
+/// [`sha256_compress_from_schedule`]. The schedule is deliberately not `Clone`, so that it is
+/// computed once and passed around by reference.
+#[derive(Debug)]
+pub struct MsgSchedule([OracleId; 64]);
+
+impl MsgSchedule {
+	/// Adds the message schedule circuit of the 16-word block `msg`.
+	pub fn compute(
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		msg: &[OracleId; 16],
+	) -> Result<Self, anyhow::Error> {
+		let log_size = builder.log_rows(msg.iter().copied())?;
+		builder.push_namespace(name);
+		let w = schedule(builder, *msg, log_size);
+		builder.pop_namespace();
+		Ok(Self(w?))
+	}
+
+	/// The 64 words of the schedule, the first 16 of which are the message block.
+	pub const fn words(&self) -> &[OracleId; 64] {
+		&self.0
+	}
+}
+
+/// Computes the SHA-256 compression function from the chaining value `h`, using a precomputed
+/// message schedule.
+///
+/// `k` holds the round constant columns from [`sha256_round_constants_oracle`], which can be
+/// shared by all the compressions of the same size.
+pub fn sha256_compress_from_schedule(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	h: [OracleId; 8],
+	schedule: &MsgSchedule,
+	k: &[OracleId; 64],
+) -> Result<[OracleId; 8], anyhow::Error> {
+	let log_size = builder.log_rows(h.iter().chain(schedule.words()).chain(k).copied())?;
+	builder.push_namespace(name);
+	let output = compress(builder, h, schedule.0, k, log_size);
+	builder.pop_namespace();
+	output
+}
+
+/// Appends the SHA-256 padding for a message of `byte_len` bytes.
+///
+/// The padding only depends on the message length, so it consists of constant columns, except for
+/// the last message word if it is partially filled.
+fn pad(
+	builder: &mut ConstraintSystemBuilder,
+	message: &[OracleId],
+	byte_len: usize,
+	log_size: usize,
+) -> Result<Vec<OracleId>, anyhow::Error> {
+	let n_blocks = (byte_len + 9).div_ceil(64);
+	let mut padded = message.to_vec();
+
+	let n_trailing_bytes = byte_len % 4;
+	if n_trailing_bytes == 0 {
+		padded.push(u32const_repeating(log_size, builder, 0x80000000, "padding")?);
+	} else {
+		let last = padded.pop().expect("message has a partially filled word");
+		let unused_mask = u32::MAX >> (8 * n_trailing_bytes);
+		let padding_bit = 0x80000000 >> (8 * n_trailing_bytes);
+
+		let mask = u32const_repeating(log_size, builder, unused_mask, "unused_mask")?;
+		builder.assert_zero(
+			"unused_bytes",
+			[last, mask],
+			arith_expr!([last, mask] = last * mask).convert_field(),
+		);
+
+		let padding = u32const_repeating(log_size, builder, padding_bit, "padding")?;
+		let padded_last = builder.add_linear_combination(
+			"padded_last",
+			log_size,
+			[(last, Field::ONE), (padding, Field::ONE)],
+		)?;
+		if let Some(witness) = builder.witness() {
+			let mut padded_last_witness = witness.new_column::<B1>(padded_last);
+			let last_u32 = witness.get::<B1>(last)?.as_slice::<u32>();
+			padded_last_witness
+				.as_mut_slice::<u32>()
+				.iter_mut()
+				.zip(last_u32)
+				.for_each(|(padded, last)| *padded = last | padding_bit);
+		}
+		padded.push(padded_last);
+	}
+
+	let bit_len = (byte_len as u64) * 8;
+	let zero = u32const_repeating(log_size, builder, 0, "padding")?;
+	padded.resize(n_blocks * 16 - 2, zero);
+	padded.push(u32const_repeating(log_size, builder, (bit_len >> 32) as u32, "length_hi")?);
+	padded.push(u32const_repeating(log_size, builder, bit_len as u32, "length_lo")?);
+	Ok(padded)
+}
+
+/// Expands a message block into the 64 words of the message schedule.
+fn schedule(
+	builder: &mut ConstraintSystemBuilder,
+	input: [OracleId; 16],
+	log_size: usize,
+) -> Result<[OracleId; 64], anyhow::Error> {
 	let mut w = [OracleId::MAX; 64];
 
 	w[0..16].copy_from_slice(&input);
@@ -144,12 +319,19 @@ pub fn sha256(
 		)?;
 	}
 
-	let init_oracles = INIT.map(|val| u32const_repeating(log_size, builder, val, "INIT").unwrap());
-
-	let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = init_oracles;
+	Ok(w)
+}
 
-	let k = ROUND_CONSTS_K
-		.map(|val| u32const_repeating(log_size, builder, val, "ROUND_CONSTS_K").unwrap());
+/// Runs the 64 rounds of the SHA-256 compression function on the message schedule `w` with the
+/// round constant columns `k`, and adds the result to the initial state `state`.
+fn compress(
+	builder: &mut ConstraintSystemBuilder,
+	state: [OracleId; 8],
+	w: [OracleId; 64],
+	k: &[OracleId; 64],
+	log_size: usize,
+) -> Result<[OracleId; 8], anyhow::Error> {
+	let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
 
 	let ch: [OracleId; 64] = builder.add_committed_multiple("ch", log_size, B1::TOWER_LEVEL);
 
@@ -244,14 +426,8 @@ pub fn sha256(
 	let abcdefgh = [a, b, c, d, e, f, g, h];
 
 	let output = std::array::from_fn(|i| {
-		arithmetic::u32::add(
-			builder,
-			"output",
-			init_oracles[i],
-			abcdefgh[i],
-			arithmetic::Flags::Unchecked,
-		)
-		.unwrap()
+		arithmetic::u32::add(builder, "output", state[i], abcdefgh[i], arithmetic::Flags::Unchecked)
+			.unwrap()
 	});
 
 	Ok(output)
@@ -259,15 +435,53 @@ pub fn sha256(
 
 #[cfg(test)]
 mod tests {
-	use binius_core::oracle::OracleId;
-	use binius_field::{as_packed_field::PackedType, BinaryField1b};
-	use sha2::{compress256, digest::generic_array::GenericArray};
+	use binius_core::{
+		constraint_system::{channel::Boundary, ConstraintSystem},
+		oracle::{MultilinearPolyOracle, MultilinearPolyVariant, OracleId},
+	};
+	use binius_field::{as_packed_field::PackedType, BinaryField1b, TowerField};
+	use sha2::{compress256, digest::generic_array::GenericArray, Digest, Sha256};
 
 	use crate::{
-		builder::{test_utils::test_circuit, types::U},
+		arithmetic::u32::u32const_repeating,
+		builder::{
+			test_utils::test_circuit,
+			types::{F, U},
+			ConstraintSystemBuilder,
+		},
 		unconstrained::unconstrained,
 	};
 
+	#[test]
+	fn test_sha256_constant_oracles() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let log_size = PackedType::<U, BinaryField1b>::LOG_WIDTH + 1;
+		let iv = super::sha256_iv_oracles(&mut builder, log_size).unwrap();
+		let k = super::sha256_round_constants_oracle(&mut builder, log_size).unwrap();
+
+		let witness = builder.witness().unwrap();
+		let column = |id| {
+			let values = witness.get::<BinaryField1b>(id).unwrap().as_slice::<u32>();
+			assert_eq!(values.len(), 1 << (log_size - 5));
+			assert!(values.iter().all(|value| *value == values[0]));
+			values[0]
+		};
+
+		// FIPS 180-4, sections 4.2.2 and 5.3.3.
+		assert_eq!(
+			iv.map(column),
+			[
+				0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+				0x5be0cd19,
+			]
+		);
+		let k = k.map(column);
+		assert_eq!(k, super::ROUND_CONSTS_K);
+		assert_eq!(k[0], 0x428a2f98);
+		assert_eq!(k[63], 0xc67178f2);
+	}
+
 	#[test]
 	fn test_sha256() {
 		test_circuit(|builder| {
@@ -316,4 +530,225 @@ mod tests {
 		})
 		.unwrap();
 	}
+
+	#[test]
+	fn test_sha256_compress_from_shared_schedule() {
+		test_circuit(|builder| {
+			let log_size = PackedType::<U, BinaryField1b>::LOG_WIDTH;
+			let input: [OracleId; 16] = array_util::try_from_fn(|i| {
+				unconstrained::<BinaryField1b>(builder, format!("input_{i}"), log_size)
+			})?;
+			let schedule = super::MsgSchedule::compute(builder, "schedule", &input)?;
+			assert_eq!(schedule.words()[..16], input);
+
+			let k = super::sha256_round_constants_oracle(builder, log_size)?;
+
+			let chaining_values = [super::INIT, super::INIT.map(u32::reverse_bits)];
+			let mut outputs = Vec::new();
+			for (i, chaining_value) in chaining_values.iter().enumerate() {
+				let h = array_util::try_from_fn(|j| {
+					u32const_repeating(log_size, builder, chaining_value[j], &format!("h_{i}_{j}"))
+				})?;
+				outputs.push(super::sha256_compress_from_schedule(
+					builder,
+					format!("compress_{i}"),
+					h,
+					&schedule,
+					&k,
+				)?);
+			}
+
+			if let Some(witness) = builder.witness() {
+				let input = input
+					.iter()
+					.map(|&id| Ok(witness.get::<BinaryField1b>(id)?.as_slice::<u32>()))
+					.collect::<Result<Vec<_>, anyhow::Error>>()?;
+				for (chaining_value, output) in chaining_values.iter().zip(&outputs) {
+					let output = output
+						.iter()
+						.map(|&id| Ok(witness.get::<BinaryField1b>(id)?.as_slice::<u32>()))
+						.collect::<Result<Vec<_>, anyhow::Error>>()?;
+					for row in 0..input[0].len() {
+						let mut block = GenericArray::<u8, _>::default();
+						for (bytes, word) in block.chunks_exact_mut(4).zip(&input) {
+							bytes.copy_from_slice(&word[row].to_be_bytes());
+						}
+						let mut expected = *chaining_value;
+						compress256(&mut expected, &[block]);
+						for (word, expected) in output.iter().zip(expected) {
+							assert_eq!(word[row], expected);
+						}
+					}
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	fn sha256_compression_circuit(
+		builder: &mut ConstraintSystemBuilder,
+		log_size: usize,
+	) -> Result<[OracleId; 8], anyhow::Error> {
+		let input: [OracleId; 16] = array_util::try_from_fn(|i| {
+			unconstrained::<BinaryField1b>(builder, format!("input_{i}"), log_size)
+		})?;
+		super::sha256(builder, input, log_size)
+	}
+
+	#[test]
+	fn test_sha256_snapshot_replication() {
+		let log_size = PackedType::<U, BinaryField1b>::LOG_WIDTH;
+		let allocator = bumpalo::Bump::new();
+
+		let mut template = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let output = sha256_compression_circuit(&mut template, log_size).unwrap();
+		let snapshot = template.snapshot();
+
+		let mut replicated = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let instances = (0..4)
+			.map(|_| replicated.restore_with_offset(snapshot.clone()))
+			.collect::<Vec<_>>();
+		for (i, instance) in instances.iter().enumerate() {
+			assert_eq!(instance.len(), snapshot.n_oracles());
+			assert_eq!(instance[output[0]], output[0] + i * snapshot.n_oracles());
+		}
+
+		// The witness columns of the template are restored for every instance.
+		let template_witness = template.witness().unwrap();
+		let replicated_witness = replicated.witness().unwrap();
+		for instance in &instances {
+			for &id in &output {
+				let expected = template_witness.get::<BinaryField1b>(id).unwrap();
+				let restored = replicated_witness
+					.get::<BinaryField1b>(instance[id])
+					.unwrap();
+				assert_eq!(restored.as_slice::<u32>(), expected.as_slice::<u32>());
+			}
+		}
+
+		let mut direct = ConstraintSystemBuilder::new();
+		for _ in 0..4 {
+			sha256_compression_circuit(&mut direct, log_size).unwrap();
+		}
+
+		let witness = replicated.take_witness().unwrap();
+		let replicated = replicated.build().unwrap();
+		for oracle in replicated.oracles.iter() {
+			if matches!(oracle.variant, MultilinearPolyVariant::Committed) {
+				assert!(witness.get_multilin_poly(oracle.id()).is_ok());
+			}
+		}
+		let direct = direct.build().unwrap();
+
+		// Some gadgets embed oracle ids in their names, so the names are ignored. Transparent
+		// oracles compare by pointer, so they are compared by their value at a fixed point.
+		let oracles = |system: &ConstraintSystem<F>| system.oracles.iter().collect::<Vec<_>>();
+		let (replicated_oracles, direct_oracles) = (oracles(&replicated), oracles(&direct));
+		assert_eq!(replicated_oracles.len(), direct_oracles.len());
+		for (replicated, direct) in replicated_oracles.into_iter().zip(direct_oracles) {
+			match (&replicated.variant, &direct.variant) {
+				(
+					MultilinearPolyVariant::Transparent(replicated_poly),
+					MultilinearPolyVariant::Transparent(direct_poly),
+				) => {
+					let point = (0..replicated.n_vars)
+						.map(|i| F::new(i as u128 + 3))
+						.collect::<Vec<_>>();
+					assert_eq!(replicated.n_vars, direct.n_vars);
+					assert_eq!(replicated.tower_level, direct.tower_level);
+					assert_eq!(
+						replicated_poly.poly().evaluate(&point).unwrap(),
+						direct_poly.poly().evaluate(&point).unwrap()
+					);
+				}
+				_ => assert_eq!(
+					MultilinearPolyOracle {
+						name: None,
+						..replicated
+					},
+					MultilinearPolyOracle {
+						name: None,
+						..direct
+					}
+				),
+			}
+		}
+
+		assert_eq!(replicated.table_constraints.len(), direct.table_constraints.len());
+		for (replicated, direct) in replicated
+			.table_constraints
+			.iter()
+			.zip(&direct.table_constraints)
+		{
+			assert_eq!(replicated.oracle_ids, direct.oracle_ids);
+			assert_eq!(replicated.constraints.len(), direct.constraints.len());
+			for (replicated, direct) in replicated.constraints.iter().zip(&direct.constraints) {
+				assert_eq!(replicated.composition, direct.composition);
+			}
+		}
+	}
+
+	fn sha256_full_circuit(
+		builder: &mut ConstraintSystemBuilder,
+		message: &[u8],
+	) -> Result<Vec<Boundary<F>>, anyhow::Error> {
+		let log_size = PackedType::<U, BinaryField1b>::LOG_WIDTH;
+		let words = message
+			.chunks(4)
+			.map(|chunk| {
+				let mut bytes = [0u8; 4];
+				bytes[..chunk.len()].copy_from_slice(chunk);
+				u32::from_be_bytes(bytes)
+			})
+			.collect::<Vec<_>>();
+		let message_oracles = words
+			.iter()
+			.enumerate()
+			.map(|(i, &word)| {
+				let id = builder.add_committed(
+					format!("message_{i}"),
+					log_size,
+					BinaryField1b::TOWER_LEVEL,
+				);
+				if let Some(witness) = builder.witness() {
+					witness
+						.new_column::<BinaryField1b>(id)
+						.as_mut_slice::<u32>()
+						.fill(word);
+				}
+				id
+			})
+			.collect::<Vec<_>>();
+
+		let digest =
+			super::sha256_full(builder, "sha256", &message_oracles, message.len(), log_size)?;
+
+		if let Some(witness) = builder.witness() {
+			let expected = Sha256::digest(message);
+			for (i, expected_word) in expected.chunks(4).enumerate() {
+				let expected_word = u32::from_be_bytes(expected_word.try_into().unwrap());
+				for &word in witness.get::<BinaryField1b>(digest[i])?.as_slice::<u32>() {
+					assert_eq!(word, expected_word);
+				}
+			}
+		}
+
+		Ok(vec![])
+	}
+
+	#[test]
+	fn test_sha256_full_empty() {
+		test_circuit(|builder| sha256_full_circuit(builder, b"")).unwrap();
+	}
+
+	#[test]
+	fn test_sha256_full_abc() {
+		test_circuit(|builder| sha256_full_circuit(builder, b"abc")).unwrap();
+	}
+
+	#[test]
+	fn test_sha256_full_two_blocks() {
+		test_circuit(|builder| sha256_full_circuit(builder, &[0x5a; 57])).unwrap();
+	}
 }
@@ -0,0 +1,170 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Gadgets constraining columns to be sorted.
+
+use binius_core::oracle::{OracleId, ShiftVariant};
+use binius_field::{as_packed_field::PackedType, BinaryField1b};
+use binius_macros::arith_expr;
+use binius_maybe_rayon::prelude::*;
+
+use crate::{
+	arithmetic,
+	builder::{
+		types::{F, U},
+		ConstraintSystemBuilder,
+	},
+	transparent::step_down,
+};
+
+type B1 = BinaryField1b;
+
+/// Constrains the rows of a u32 column to be sorted in non-decreasing order.
+///
+/// Every row except the last is compared with the next one using [`arithmetic::u32::le`].
+pub fn is_sorted_u32(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	column: OracleId,
+) -> Result<(), anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([column])?;
+	anyhow::ensure!(log_rows >= 5, "Polynomial must have n_vars >= 5. Got {log_rows}");
+
+	let next = next_row_u32(builder, "next", column)?;
+	let le = arithmetic::u32::le(builder, "le", column, next)?;
+	// The last row is compared with the zero padding of the shifted column.
+	let packed_log_rows = log_rows - 5;
+	let enabled = step_down(builder, "enabled", packed_log_rows, (1 << packed_log_rows) - 1)?;
+	builder.assert_zero("sorted", [le, enabled], arith_expr!(F[le, enabled] = (le - 1) * enabled));
+
+	builder.pop_namespace();
+	Ok(())
+}
+
+/// Shifts a u32 column up by one row, so that every row holds the value of the next one.
+///
+/// The last row is zero.
+pub(crate) fn next_row_u32(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	column: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	let log_rows = builder.log_rows([column])?;
+	let next = builder.add_shifted(name, column, 32, log_rows, ShiftVariant::LogicalRight)?;
+	if let Some(witness) = builder.witness() {
+		let column = witness.get::<B1>(column)?.as_slice::<u32>();
+		let mut next = witness.new_column::<B1>(next);
+		let next = next.as_mut_slice::<u32>();
+		(&mut next[..column.len() - 1], &column[1..])
+			.into_par_iter()
+			.for_each(|(next, column)| *next = *column);
+	}
+	Ok(next)
+}
+
+/// Constrains the rows of the u32 column `column` to be sorted by the bits selected by `key_mask`.
+///
+/// `key` is a u32 column of the same size as `column` and is constrained to be `column & key_mask`
+/// on every row, then to be sorted in non-decreasing order. Rows with equal keys may appear in any
+/// order.
+///
+/// Only keys that are a fixed bit mask of the row value are supported; the masked bits are
+/// compared as an unsigned integer, so a key such as a bit field in the middle of the word sorts
+/// by that field alone. Keys that need any other derivation should be computed into their own
+/// column and checked with [`is_sorted_u32`].
+pub fn is_sorted_by_masked_key(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	column: OracleId,
+	key: OracleId,
+	key_mask: u32,
+) -> Result<(), anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([column, key])?;
+	anyhow::ensure!(
+		log_rows >= PackedType::<U, B1>::LOG_WIDTH,
+		"Polynomial must have n_vars >= {}. Got {log_rows}",
+		PackedType::<U, B1>::LOG_WIDTH
+	);
+
+	let mask = arithmetic::u32::u32const_repeating(log_rows, builder, key_mask, "key_mask")?;
+	builder.assert_zero(
+		"key",
+		[column, mask, key],
+		arith_expr!([column, mask, key] = column * mask - key).convert_field(),
+	);
+	is_sorted_u32(builder, "key_sorted", key)?;
+	builder.pop_namespace();
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField1b;
+
+	use crate::{builder::test_utils::test_circuit, unconstrained::fixed_u32};
+
+	#[test]
+	fn test_is_sorted_u32() {
+		test_circuit(|builder| {
+			let log_size = 10;
+			let values = (0..1 << (log_size - 5)).map(|i| i / 3 * 7).collect();
+			let column = fixed_u32::<BinaryField1b>(builder, "column", log_size, values)?;
+			super::is_sorted_u32(builder, "is_sorted", column)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_is_sorted_u32_unsorted() {
+		let result = test_circuit(|builder| {
+			let log_size = 10;
+			let mut values = (0..1 << (log_size - 5)).collect::<Vec<_>>();
+			values.swap(10, 11);
+			let column = fixed_u32::<BinaryField1b>(builder, "column", log_size, values)?;
+			super::is_sorted_u32(builder, "is_sorted", column)?;
+			Ok(vec![])
+		});
+		assert!(result.is_err());
+	}
+
+	const KEY_MASK: u32 = 0xffff0000;
+
+	/// Rows with sorted high halves and unsorted low halves.
+	fn rows(log_size: usize) -> Vec<u32> {
+		(0..1 << (log_size - 5))
+			.map(|i| (i / 4) << 16 | (31 - i))
+			.collect()
+	}
+
+	#[test]
+	fn test_is_sorted_by_masked_key() {
+		test_circuit(|builder| {
+			let log_size = 10;
+			let rows = rows(log_size);
+			let keys = rows.iter().map(|row| row & KEY_MASK).collect();
+			let column = fixed_u32::<BinaryField1b>(builder, "column", log_size, rows)?;
+			let key = fixed_u32::<BinaryField1b>(builder, "key", log_size, keys)?;
+			super::is_sorted_by_masked_key(builder, "is_sorted", column, key, KEY_MASK)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_is_sorted_by_masked_key_tampered_key() {
+		// The keys are sorted, but they are not extracted from the unsorted column.
+		let result = test_circuit(|builder| {
+			let log_size = 10;
+			let mut rows = rows(log_size);
+			rows.swap(3, 4);
+			let keys = (0..1 << (log_size - 5)).map(|i| (i / 4) << 16).collect();
+			let column = fixed_u32::<BinaryField1b>(builder, "column", log_size, rows)?;
+			let key = fixed_u32::<BinaryField1b>(builder, "key", log_size, keys)?;
+			super::is_sorted_by_masked_key(builder, "is_sorted", column, key, KEY_MASK)?;
+			Ok(vec![])
+		});
+		assert!(result.is_err());
+	}
+}
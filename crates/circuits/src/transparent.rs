@@ -50,15 +50,19 @@ where
 	F: TowerField + ExtensionField<FS>,
 	FS: TowerField,
 {
-	let poly = transparent::constant::Constant::new(log_size, value);
-	let id = builder.add_transparent(name, poly)?;
-	if let Some(witness) = builder.witness() {
-		witness
-			.new_column::<FS>(id)
-			.packed()
-			.fill(<PackedType<U, FS>>::broadcast(value));
-	}
-	Ok(id)
+	Ok(builder.add_transparent_constant(name, log_size, value)?)
+}
+
+/// Adds a transparent column whose `i`-th row is the integer `i` as a B32 element.
+///
+/// This is a shorthand for [`ConstraintSystemBuilder::add_identity`], which precomputes the
+/// values, so nothing is committed.
+pub fn counter(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_count: usize,
+) -> Result<OracleId, anyhow::Error> {
+	builder.add_identity(name, log_count)
 }
 
 pub fn make_transparent<FS>(
@@ -91,3 +95,22 @@ where
 
 	Ok(oracle)
 }
+
+#[cfg(test)]
+mod tests {
+	use binius_math::MultilinearPoly;
+
+	use super::counter;
+	use crate::builder::{types::F, ConstraintSystemBuilder};
+
+	#[test]
+	fn test_counter() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let counter = counter(&mut builder, "counter", 10).unwrap();
+		let witness = builder.take_witness().unwrap();
+		let counter = witness.get_multilin_poly(counter).unwrap();
+		assert_eq!(counter.evaluate_on_hypercube(500).unwrap(), F::from(500u128));
+		assert_eq!(counter.evaluate_on_hypercube(1023).unwrap(), F::from(1023u128));
+	}
+}
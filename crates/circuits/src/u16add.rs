@@ -0,0 +1,64 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Addition of u16 values modulo 2^16.
+//!
+//! This is [`uadd`](crate::uadd::uadd) over blocks of 16 consecutive bits.
+
+use binius_core::oracle::OracleId;
+
+use crate::{
+	builder::ConstraintSystemBuilder,
+	uadd::{uadd, uadd_committed, UnsignedWord},
+};
+
+pub const LOG_U16_BITS: usize = <u16 as UnsignedWord>::LOG_BITS;
+
+/// Adds two u16 columns, wrapping on overflow.
+///
+/// The sum is a linear combination of the operands and the carries, so only the carries are
+/// committed.
+pub fn u16add(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	uadd::<u16>(builder, name, xin, yin)
+}
+
+/// Adds two u16 columns, wrapping on overflow, and commits the sum.
+///
+/// This is [`u16add`] with the sum committed, which costs a commitment but allows the output to
+/// be used where a committed oracle is required.
+pub fn u16add_committed(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	uadd_committed::<u16>(builder, name, xin, yin)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{u16add, u16add_committed};
+	use crate::uadd::tests::test_add;
+
+	/// Pairs of operands with the expected sums.
+	const EDGE_CASES: [(u16, u16, u16); 4] = [
+		(200, 100, 300),
+		(u16::MAX, 1, 0),
+		(u16::MAX, u16::MAX, u16::MAX - 1),
+		(0, 0, 0),
+	];
+
+	#[test]
+	fn test_u16add() {
+		test_add(&EDGE_CASES, |builder, x, y| u16add(builder, "x + y", x, y));
+	}
+
+	#[test]
+	fn test_u16add_committed() {
+		test_add(&EDGE_CASES, |builder, x, y| u16add_committed(builder, "x + y", x, y));
+	}
+}
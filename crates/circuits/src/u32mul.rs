@@ -0,0 +1,157 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Full multiplication of u32 values into u64 products.
+//!
+//! The product is computed with the schoolbook algorithm. For every bit `i` of `b`, `a` is masked
+//! by the bit, shifted left by `i` bits into a 64-bit partial product, and added to a 64-bit
+//! accumulator held as its low and high u32 halves.
+
+use binius_core::oracle::{OracleId, ShiftVariant};
+use binius_field::{BinaryField1b, Field, TowerField};
+use binius_macros::arith_expr;
+use binius_maybe_rayon::prelude::*;
+
+use crate::{
+	arithmetic::{
+		self,
+		u32::{select_bit, shl, shr, LOG_U32_BITS},
+		Flags,
+	},
+	bitwise,
+	builder::ConstraintSystemBuilder,
+	transparent,
+};
+
+type B1 = BinaryField1b;
+
+/// Multiplies two u32 columns, and returns the low and high u32 halves of the u64 products.
+pub fn u32mul(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	a: OracleId,
+	b: OracleId,
+) -> Result<(OracleId, OracleId), anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([a, b])?;
+
+	let zero = transparent::constant(builder, "zero", log_rows, B1::ZERO)?;
+	let (mut lo, mut hi) = (zero, zero);
+	for i in 0..32 {
+		builder.push_namespace(format!("bit_{i}"));
+		let mask = broadcast_bit(builder, b, i)?;
+		let masked = bitwise::and(builder, "masked", a, mask)?;
+		let partial_lo = shl(builder, "partial_lo", masked, i)?;
+		if i == 0 {
+			lo = partial_lo;
+		} else {
+			let partial_hi = shr(builder, "partial_hi", masked, 32 - i)?;
+			// The accumulator is less than 2^(32 + i), so the sum never overflows.
+			(lo, hi) = arithmetic::u64::add_from_u32_halves(
+				builder,
+				"sum",
+				lo,
+				hi,
+				partial_lo,
+				partial_hi,
+				Flags::Unchecked,
+			)?;
+		}
+		builder.pop_namespace();
+	}
+
+	builder.pop_namespace();
+	Ok((lo, hi))
+}
+
+/// Commits a u32 column whose words are all ones where bit `index` of `input` is set, and zero
+/// elsewhere.
+///
+/// The mask is constrained to be constant within each word, and to agree with `input` on its
+/// least significant bit.
+fn broadcast_bit(
+	builder: &mut ConstraintSystemBuilder,
+	input: OracleId,
+	index: usize,
+) -> Result<OracleId, anyhow::Error> {
+	let log_rows = builder.log_rows([input])?;
+	let mask = builder.add_committed("mask", log_rows, B1::TOWER_LEVEL);
+	let mask_rotated =
+		builder.add_shifted("mask_rotated", mask, 1, LOG_U32_BITS, ShiftVariant::CircularLeft)?;
+
+	if let Some(witness) = builder.witness() {
+		(
+			witness.get::<B1>(input)?.as_slice::<u32>(),
+			witness.new_column::<B1>(mask).as_mut_slice::<u32>(),
+			witness.new_column::<B1>(mask_rotated).as_mut_slice::<u32>(),
+		)
+			.into_par_iter()
+			.for_each(|(input, mask, mask_rotated)| {
+				*mask = if (input >> index) & 1 == 1 {
+					u32::MAX
+				} else {
+					0
+				};
+				*mask_rotated = *mask;
+			});
+	}
+
+	builder.assert_zero(
+		"mask_broadcast",
+		[mask, mask_rotated],
+		arith_expr!([x, y] = x - y).convert_field(),
+	);
+	let mask_bit = select_bit(builder, "mask_bit", mask, 0)?;
+	let input_bit = select_bit(builder, "input_bit", input, index)?;
+	builder.assert_zero(
+		"mask_bit",
+		[mask_bit, input_bit],
+		arith_expr!([x, y] = x - y).convert_field(),
+	);
+	Ok(mask)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField1b;
+
+	use super::u32mul;
+	use crate::{
+		builder::test_utils::{prove_verify_circuit, test_operands},
+		unconstrained::fixed_u32,
+	};
+
+	const LOG_SIZE: usize = 10;
+
+	/// Pairs of operands with the expected low and high halves of the product.
+	const EDGE_CASES: [(u32, u32, u32, u32); 4] = [
+		(100, 200, 20000, 0),
+		(u32::MAX, 2, u32::MAX - 1, 1),
+		(u32::MAX, u32::MAX, 1, u32::MAX - 1),
+		(0, u32::MAX, 0, 0),
+	];
+
+	#[test]
+	fn test_u32mul() {
+		prove_verify_circuit(|builder| {
+			let (a_values, b_values) =
+				test_operands(1 << (LOG_SIZE - 5), EDGE_CASES.map(|(a, b, _, _)| (a, b)));
+			let a = fixed_u32::<BinaryField1b>(builder, "a", LOG_SIZE, a_values.clone())?;
+			let b = fixed_u32::<BinaryField1b>(builder, "b", LOG_SIZE, b_values.clone())?;
+			let (lo, hi) = u32mul(builder, "a * b", a, b)?;
+
+			if let Some(witness) = builder.witness() {
+				let lo = witness.get::<BinaryField1b>(lo)?.as_slice::<u32>();
+				let hi = witness.get::<BinaryField1b>(hi)?.as_slice::<u32>();
+				for (row, &(_, _, expected_lo, expected_hi)) in EDGE_CASES.iter().enumerate() {
+					assert_eq!((lo[row], hi[row]), (expected_lo, expected_hi));
+				}
+				for (row, (&a, &b)) in a_values.iter().zip(&b_values).enumerate() {
+					let product = a as u64 * b as u64;
+					assert_eq!((lo[row], hi[row]), (product as u32, (product >> 32) as u32));
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
@@ -0,0 +1,104 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Subtraction of u32 values modulo 2^32.
+//!
+//! This is the subtraction of [`arithmetic::u32`](crate::arithmetic::u32) without underflow
+//! checking. The operands are columns of bits, where each block of 32 consecutive bits holds one
+//! value, least significant bit first. The borrows are committed, and the borrow into each bit is
+//! the borrow column shifted left by one bit within the block.
+
+use binius_core::oracle::OracleId;
+
+use crate::{
+	arithmetic::{self, u32::sub_with_borrow},
+	builder::ConstraintSystemBuilder,
+};
+
+/// Subtracts one u32 column from another, wrapping on underflow.
+///
+/// The difference is a linear combination of the operands and the borrows, so only the borrows
+/// are committed.
+pub fn u32sub(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	a: OracleId,
+	b: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	let (zout, _) = sub_with_borrow(builder, name, a, b, arithmetic::Flags::Unchecked, false)?;
+	Ok(zout)
+}
+
+/// Subtracts one u32 column from another, wrapping on underflow, and commits the difference.
+///
+/// This is [`arithmetic::u32::sub`] without underflow checking. It costs a commitment more than
+/// [`u32sub`], but allows the output to be used where a committed oracle is required.
+pub fn u32sub_committed(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	a: OracleId,
+	b: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	arithmetic::u32::sub(builder, name, a, b, arithmetic::Flags::Unchecked)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::channel::Boundary;
+	use binius_field::BinaryField1b;
+
+	use super::{u32sub, u32sub_committed};
+	use crate::{
+		builder::{
+			test_utils::{prove_verify_circuit, test_operands},
+			types::F,
+			ConstraintSystemBuilder,
+		},
+		unconstrained::fixed_u32,
+	};
+
+	const LOG_SIZE: usize = 10;
+
+	/// Pairs of operands with the expected differences.
+	const EDGE_CASES: [(u32, u32, u32); 4] = [
+		(200, 100, 100),
+		(0, 1, u32::MAX),
+		(u32::MAX, u32::MAX, 0),
+		(100, 200, u32::MAX - 99),
+	];
+
+	fn build_circuit(
+		builder: &mut ConstraintSystemBuilder,
+		committed: bool,
+	) -> Result<Vec<Boundary<F>>, anyhow::Error> {
+		let (a_values, b_values) =
+			test_operands(1 << (LOG_SIZE - 5), EDGE_CASES.map(|(a, b, _)| (a, b)));
+		let a = fixed_u32::<BinaryField1b>(builder, "a", LOG_SIZE, a_values.clone())?;
+		let b = fixed_u32::<BinaryField1b>(builder, "b", LOG_SIZE, b_values.clone())?;
+		let z = if committed {
+			u32sub_committed(builder, "a - b", a, b)?
+		} else {
+			u32sub(builder, "a - b", a, b)?
+		};
+
+		if let Some(witness) = builder.witness() {
+			let z = witness.get::<BinaryField1b>(z)?.as_slice::<u32>();
+			for (row, &(_, _, difference)) in EDGE_CASES.iter().enumerate() {
+				assert_eq!(z[row], difference);
+			}
+			for (row, (a, b)) in a_values.iter().zip(&b_values).enumerate() {
+				assert_eq!(z[row], a.wrapping_sub(*b));
+			}
+		}
+		Ok(vec![])
+	}
+
+	#[test]
+	fn test_u32sub() {
+		prove_verify_circuit(|builder| build_circuit(builder, false)).unwrap();
+	}
+
+	#[test]
+	fn test_u32sub_committed() {
+		prove_verify_circuit(|builder| build_circuit(builder, true)).unwrap();
+	}
+}
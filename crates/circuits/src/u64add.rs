@@ -0,0 +1,64 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Addition of u64 values modulo 2^64.
+//!
+//! This is [`uadd`](crate::uadd::uadd) over blocks of 64 consecutive bits.
+
+use binius_core::oracle::OracleId;
+
+use crate::{
+	builder::ConstraintSystemBuilder,
+	uadd::{uadd, uadd_committed, UnsignedWord},
+};
+
+pub const LOG_U64_BITS: usize = <u64 as UnsignedWord>::LOG_BITS;
+
+/// Adds two u64 columns, wrapping on overflow.
+///
+/// The sum is a linear combination of the operands and the carries, so only the carries are
+/// committed.
+pub fn u64add(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	uadd::<u64>(builder, name, xin, yin)
+}
+
+/// Adds two u64 columns, wrapping on overflow, and commits the sum.
+///
+/// This is [`u64add`] with the sum committed, which costs a commitment but allows the output to
+/// be used where a committed oracle is required.
+pub fn u64add_committed(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	uadd_committed::<u64>(builder, name, xin, yin)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{u64add, u64add_committed};
+	use crate::uadd::tests::test_add;
+
+	/// Pairs of operands with the expected sums.
+	const EDGE_CASES: [(u64, u64, u64); 4] = [
+		(u32::MAX as u64, 1, 1 << 32),
+		(u64::MAX, 1, 0),
+		(u64::MAX, u64::MAX, u64::MAX - 1),
+		(0, 0, 0),
+	];
+
+	#[test]
+	fn test_u64add() {
+		test_add(&EDGE_CASES, |builder, x, y| u64add(builder, "x + y", x, y));
+	}
+
+	#[test]
+	fn test_u64add_committed() {
+		test_add(&EDGE_CASES, |builder, x, y| u64add_committed(builder, "x + y", x, y));
+	}
+}
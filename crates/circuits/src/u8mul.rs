@@ -0,0 +1,116 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Multiplication of u8 values modulo 256, checked with a lookup into the full multiplication
+//! table.
+
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField8b, TowerField};
+use itertools::izip;
+
+use crate::{builder::ConstraintSystemBuilder, plain_lookup::plain_lookup, transparent};
+
+type B8 = BinaryField8b;
+
+/// Multiplies two [`BinaryField8b`] columns of u8 values, and returns the committed column of the
+/// products modulo 256.
+///
+/// The table of all 256 × 256 products is materialized as three transparent columns of
+/// `(a, b, a * b mod 256)` triples, and the `(a, b, product)` rows are checked against the table
+/// with [`plain_lookup`].
+pub fn u8mul<const LOG_MAX_MULTIPLICITY: usize>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	a: OracleId,
+	b: OracleId,
+) -> Result<OracleId> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([a, b])?;
+
+	// The table entry at index `a << 8 | b` is the triple `(a, b, a * b mod 256)`.
+	let table = |f: fn(u8, u8) -> u8| {
+		(0..1usize << 16)
+			.map(|index| B8::new(f((index >> 8) as u8, index as u8)))
+			.collect::<Vec<_>>()
+	};
+	let table_a = transparent::make_transparent(builder, "table_a", &table(|a, _| a))?;
+	let table_b = transparent::make_transparent(builder, "table_b", &table(|_, b| b))?;
+	let table_product =
+		transparent::make_transparent(builder, "table_product", &table(u8::wrapping_mul))?;
+
+	let product = builder.add_committed("product", log_rows, B8::TOWER_LEVEL);
+
+	let multiplicities = if let Some(witness) = builder.witness() {
+		let mut multiplicities = vec![0; 1 << 16];
+		let mut product_column = witness.new_column::<B8>(product);
+		for (product, &a, &b) in izip!(
+			product_column.as_mut_slice::<u8>(),
+			witness.get::<B8>(a)?.as_slice::<u8>(),
+			witness.get::<B8>(b)?.as_slice::<u8>(),
+		) {
+			multiplicities[(a as usize) << 8 | b as usize] += 1;
+			*product = a.wrapping_mul(b);
+		}
+		Some(multiplicities)
+	} else {
+		None
+	};
+
+	plain_lookup::<B8, LOG_MAX_MULTIPLICITY>(
+		builder,
+		"lookup",
+		&[1 << log_rows],
+		&[[a, b, product]],
+		&[table_a, table_b, table_product],
+		multiplicities,
+	)?;
+
+	builder.pop_namespace();
+	Ok(product)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField8b;
+
+	use super::u8mul;
+	use crate::{
+		builder::test_utils::{prove_verify_circuit, test_operands},
+		unconstrained::fill_column_from_fn,
+	};
+
+	const LOG_SIZE: usize = 10;
+	const LOG_MAX_MULTIPLICITY: usize = 11;
+
+	/// Pairs of operands with the expected product.
+	const EDGE_CASES: [(u8, u8, u8); 4] = [(7, 11, 77), (255, 255, 1), (0, 255, 0), (16, 16, 0)];
+
+	// Witness validation evaluates the transparent product table at every point of the
+	// hypercube, which is quadratic in its 2^16 entries, so the circuit is proven instead.
+	#[test]
+	fn test_u8mul() {
+		prove_verify_circuit(|builder| {
+			let (a_values, b_values) =
+				test_operands(1 << LOG_SIZE, EDGE_CASES.map(|(a, b, _)| (a, b)));
+			let a = fill_column_from_fn::<BinaryField8b, _>(builder, "a", LOG_SIZE, |i| {
+				BinaryField8b::new(a_values[i])
+			})?;
+			let b = fill_column_from_fn::<BinaryField8b, _>(builder, "b", LOG_SIZE, |i| {
+				BinaryField8b::new(b_values[i])
+			})?;
+			let product = u8mul::<LOG_MAX_MULTIPLICITY>(builder, "a * b", a, b)?;
+
+			if let Some(witness) = builder.witness() {
+				let product = witness.get::<BinaryField8b>(product)?.as_slice::<u8>();
+				for (row, &(_, _, expected)) in EDGE_CASES.iter().enumerate() {
+					assert_eq!(product[row], expected);
+				}
+				for (row, (a, b)) in a_values.iter().zip(&b_values).enumerate() {
+					assert_eq!(product[row], a.wrapping_mul(*b));
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
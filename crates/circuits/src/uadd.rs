@@ -0,0 +1,205 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Addition of unsigned words modulo 2^BITS, for any word width.
+//!
+//! The operands are columns of bits, where each block of `BITS` consecutive bits holds one value,
+//! least significant bit first. The carries are committed, and the carry into each bit is the
+//! carry column shifted left by one bit within the block.
+
+use binius_core::oracle::{OracleId, ShiftVariant};
+use binius_field::{BinaryField1b, Field, TowerField};
+use binius_macros::arith_expr;
+use binius_maybe_rayon::prelude::*;
+use binius_utils::checked_arithmetics::checked_log_2;
+use bytemuck::Pod;
+
+use crate::builder::{types::F, ConstraintSystemBuilder};
+
+type B1 = BinaryField1b;
+
+/// An unsigned word that [`uadd`] can add.
+pub trait UnsignedWord: Pod + Send + Sync {
+	/// The binary logarithm of the word width in bits.
+	const LOG_BITS: usize;
+
+	/// Returns the wrapping sum of `x` and `y`, together with the carries into and out of every
+	/// bit of the sum.
+	fn add_with_carries(x: Self, y: Self) -> (Self, Self, Self);
+}
+
+macro_rules! impl_unsigned_word {
+	($($word:ty),*) => {
+		$(
+			impl UnsignedWord for $word {
+				const LOG_BITS: usize = checked_log_2(<$word>::BITS as usize);
+
+				fn add_with_carries(x: Self, y: Self) -> (Self, Self, Self) {
+					let (z, carry) = x.overflowing_add(y);
+					let cin = x ^ y ^ z;
+					let cout = ((carry as Self) << (Self::BITS - 1)) | (cin >> 1);
+					(z, cin, cout)
+				}
+			}
+		)*
+	};
+}
+
+impl_unsigned_word!(u8, u16, u32, u64);
+
+/// Adds two columns of `W` words, wrapping on overflow.
+///
+/// The sum is a linear combination of the operands and the carries, so only the carries are
+/// committed.
+pub fn uadd<W: UnsignedWord>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([xin, yin])?;
+	let (cin, cout) = add_carries::<W>(builder, log_rows)?;
+	let zout =
+		builder.add_linear_combination("zout", log_rows, [xin, yin, cin].map(|id| (id, F::ONE)))?;
+	uadd_common::<W>(builder, xin, yin, zout, cin, cout)?;
+	builder.pop_namespace();
+	Ok(zout)
+}
+
+/// Adds two columns of `W` words, wrapping on overflow, and commits the sum.
+///
+/// This is [`uadd`] with the sum committed, which costs a commitment but allows the output to be
+/// used where a committed oracle is required.
+pub fn uadd_committed<W: UnsignedWord>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([xin, yin])?;
+	let (cin, cout) = add_carries::<W>(builder, log_rows)?;
+	let zout = builder.add_committed("zout", log_rows, B1::TOWER_LEVEL);
+	uadd_common::<W>(builder, xin, yin, zout, cin, cout)?;
+	builder.assert_zero(
+		"sum",
+		[xin, yin, cin, zout],
+		arith_expr!([xin, yin, cin, zout] = xin + yin + cin - zout).convert_field(),
+	);
+	builder.pop_namespace();
+	Ok(zout)
+}
+
+/// Adds the committed carries out of every bit, and the carries into every bit.
+fn add_carries<W: UnsignedWord>(
+	builder: &mut ConstraintSystemBuilder,
+	log_rows: usize,
+) -> Result<(OracleId, OracleId), anyhow::Error> {
+	let cout = builder.add_committed("cout", log_rows, B1::TOWER_LEVEL);
+	let cin = builder.add_shifted("cin", cout, 1, W::LOG_BITS, ShiftVariant::LogicalLeft)?;
+	Ok((cin, cout))
+}
+
+/// Fills the witness and constrains the carries, which is the same for both output kinds.
+fn uadd_common<W: UnsignedWord>(
+	builder: &mut ConstraintSystemBuilder,
+	xin: OracleId,
+	yin: OracleId,
+	zout: OracleId,
+	cin: OracleId,
+	cout: OracleId,
+) -> Result<(), anyhow::Error> {
+	if let Some(witness) = builder.witness() {
+		(
+			witness.get::<B1>(xin)?.as_slice::<W>(),
+			witness.get::<B1>(yin)?.as_slice::<W>(),
+			witness.new_column::<B1>(zout).as_mut_slice::<W>(),
+			witness.new_column::<B1>(cout).as_mut_slice::<W>(),
+			witness.new_column::<B1>(cin).as_mut_slice::<W>(),
+		)
+			.into_par_iter()
+			.for_each(|(xin, yin, zout, cout, cin)| {
+				(*zout, *cin, *cout) = W::add_with_carries(*xin, *yin);
+			});
+	}
+
+	builder.assert_zero(
+		"carry",
+		[xin, yin, cin, cout],
+		arith_expr!([xin, yin, cin, cout] = (xin + cin) * (yin + cin) + cin - cout).convert_field(),
+	);
+	Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+	use std::fmt::Debug;
+
+	use binius_core::oracle::OracleId;
+	use binius_field::{BinaryField1b, TowerField};
+
+	use super::UnsignedWord;
+	use crate::builder::{
+		test_utils::{prove_verify_circuit, test_operands},
+		ConstraintSystemBuilder,
+	};
+
+	const LOG_SIZE: usize = 10;
+
+	/// Returns the little-endian value of `word`, which has at most 64 bits.
+	fn to_u64<W: UnsignedWord>(word: &W) -> u64 {
+		let mut bytes = [0; 8];
+		let word = bytemuck::bytes_of(word);
+		bytes[..word.len()].copy_from_slice(word);
+		u64::from_le_bytes(bytes)
+	}
+
+	fn fixed_words<W: UnsignedWord>(
+		builder: &mut ConstraintSystemBuilder,
+		name: &str,
+		values: &[W],
+	) -> OracleId {
+		let id = builder.add_committed(name, LOG_SIZE, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField1b>(id)
+				.as_mut_slice::<W>()
+				.copy_from_slice(values);
+		}
+		id
+	}
+
+	/// Proves `add` on two columns of `W` words, which hold the operands of `edge_cases` in the
+	/// first rows followed by pseudo-random words, and checks the sums in the witness.
+	pub(crate) fn test_add<W: UnsignedWord + PartialEq + Debug>(
+		edge_cases: &[(W, W, W)],
+		add: impl Fn(
+			&mut ConstraintSystemBuilder,
+			OracleId,
+			OracleId,
+		) -> Result<OracleId, anyhow::Error>,
+	) {
+		prove_verify_circuit(|builder| {
+			let (x_values, y_values) = test_operands(
+				1 << (LOG_SIZE - W::LOG_BITS),
+				edge_cases.iter().map(|&(x, y, _)| (x, y)),
+			);
+			let x = fixed_words(builder, "x", &x_values);
+			let y = fixed_words(builder, "y", &y_values);
+			let z = add(builder, x, y)?;
+
+			if let Some(witness) = builder.witness() {
+				let z = witness.get::<BinaryField1b>(z)?.as_slice::<W>();
+				for (row, &(_, _, sum)) in edge_cases.iter().enumerate() {
+					assert_eq!(z[row], sum);
+				}
+				let mask = u64::MAX >> (64 - (1 << W::LOG_BITS));
+				for (row, (x, y)) in x_values.iter().zip(&y_values).enumerate() {
+					assert_eq!(to_u64(&z[row]), to_u64(x).wrapping_add(to_u64(y)) & mask);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
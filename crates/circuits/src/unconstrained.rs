@@ -1,6 +1,9 @@
 // Copyright 2024-2025 Irreducible Inc.
 use binius_core::oracle::OracleId;
-use binius_field::{as_packed_field::PackScalar, ExtensionField, TowerField};
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	ExtensionField, PackedField, TowerField,
+};
 use binius_maybe_rayon::prelude::*;
 use bytemuck::Pod;
 use rand::{thread_rng, Rng};
@@ -35,6 +38,36 @@ where
 	Ok(rng)
 }
 
+/// Adds a committed column over `FS` in which every row is a uniformly random `FS` element.
+///
+/// Unlike [`unconstrained`], which fills the raw bytes of the column, the rows are sampled with
+/// [`PackedField::random`]. Taking `FS` to be `F` gives random full-field elements, as needed when
+/// sampling random linear combinations.
+pub fn random_field_element<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<FS>,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField,
+{
+	let id = builder.add_committed(name, log_size, FS::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		witness
+			.new_column::<FS>(id)
+			.packed()
+			.par_iter_mut()
+			.for_each_init(thread_rng, |rng, packed| {
+				*packed = PackedType::<U, FS>::random(rng);
+			});
+	}
+
+	Ok(id)
+}
+
 // Same as 'unconstrained' but uses some pre-defined values instead of a random ones
 pub fn fixed_u32<FS>(
 	builder: &mut ConstraintSystemBuilder,
@@ -62,3 +95,101 @@ where
 
 	Ok(fixed)
 }
+
+/// Adds a committed column whose `i`-th row is `f(i)`.
+///
+/// The witness rows are computed in parallel, so `f` should be cheap and free of side effects.
+pub fn fill_column_from_fn<FS, V>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	f: impl Fn(usize) -> V + Sync,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<FS>,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField,
+	V: Into<FS>,
+{
+	let id = builder.add_committed(name, log_size, FS::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let width = PackedType::<U, FS>::WIDTH;
+		witness
+			.new_column::<FS>(id)
+			.packed()
+			.par_iter_mut()
+			.enumerate()
+			.for_each(|(i, packed)| {
+				*packed = PackedType::<U, FS>::from_fn(|j| {
+					let index = i * width + j;
+					if index < 1 << log_size {
+						f(index).into()
+					} else {
+						FS::ZERO
+					}
+				});
+			});
+	}
+
+	Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField32b, BinaryField8b, Field, TowerField};
+
+	use crate::builder::{types::F, ConstraintSystemBuilder};
+
+	#[test]
+	fn test_fill_column_from_fn() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let log_size = 10;
+		let id = super::fill_column_from_fn::<BinaryField32b, _>(
+			&mut builder,
+			"sequential",
+			log_size,
+			|i| i as u32,
+		)
+		.unwrap();
+
+		let witness = builder.witness().unwrap();
+		let values = witness.get::<BinaryField32b>(id).unwrap();
+		assert!(values
+			.as_slice::<u32>()
+			.iter()
+			.copied()
+			.eq(0..1 << log_size));
+	}
+
+	#[test]
+	fn test_random_field_element() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let log_size = 8;
+		let id = super::random_field_element::<F>(&mut builder, "random", log_size).unwrap();
+
+		let witness = builder.witness().unwrap();
+		let values = witness.get::<F>(id).unwrap().as_slice::<F>().to_vec();
+		assert_eq!(values.len(), 1 << log_size);
+		// 256 random 128-bit elements collide or vanish with negligible probability.
+		assert!(values.iter().all(|value| *value != F::ZERO));
+		let mut sorted = values
+			.iter()
+			.map(|value| u128::from(*value))
+			.collect::<Vec<_>>();
+		sorted.sort_unstable();
+		sorted.dedup();
+		assert_eq!(sorted.len(), values.len());
+
+		let byte_id =
+			super::random_field_element::<BinaryField8b>(&mut builder, "random_byte", log_size)
+				.unwrap();
+
+		builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		assert_eq!(constraint_system.oracles.tower_level(id), F::TOWER_LEVEL);
+		assert_eq!(constraint_system.oracles.tower_level(byte_id), BinaryField8b::TOWER_LEVEL);
+	}
+}
@@ -0,0 +1,97 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Circuits and proving helpers shared by the integration tests.
+//!
+//! Every test includes this module with `mod common;` and uses a different subset of it.
+#![allow(dead_code)]
+
+use binius_circuits::{
+	arithmetic::{self, Flags},
+	builder::{
+		test_utils::{LOG_INV_RATE, SECURITY_BITS},
+		types::{F, U},
+		ConstraintSystemBuilder,
+	},
+	unconstrained::fill_column_from_fn,
+};
+use binius_core::{
+	constraint_system::{self, channel::Boundary, error::Error, ConstraintSystem, Proof},
+	fiat_shamir::HasherChallenger,
+	tower::CanonicalTowerFamily,
+	witness::MultilinearExtensionIndex,
+};
+use binius_field::{as_packed_field::PackedType, BinaryField1b};
+use binius_hal::make_portable_backend;
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+
+/// The witness of a circuit built with [`ConstraintSystemBuilder`].
+pub type Witness<'arena> = MultilinearExtensionIndex<'arena, PackedType<U, F>>;
+
+/// Adds pairs of u32 values over `2^n_vars` bits, with a witness that depends on `seed`.
+pub fn u32_add(builder: &mut ConstraintSystemBuilder, n_vars: usize, seed: usize) {
+	let x = fill_column_from_fn::<BinaryField1b, _>(builder, "x", n_vars, |i| (i + seed) & 1 == 1)
+		.unwrap();
+	let y = fill_column_from_fn::<BinaryField1b, _>(builder, "y", n_vars, |i| (i + seed) % 3 == 0)
+		.unwrap();
+	arithmetic::u32::add(builder, "x + y", x, y, Flags::Unchecked).unwrap();
+}
+
+/// Builds the [`u32_add`] circuit, and returns the constraint system together with the witness.
+pub fn u32_add_circuit(
+	allocator: &bumpalo::Bump,
+	n_vars: usize,
+	seed: usize,
+) -> (ConstraintSystem<F>, Witness<'_>) {
+	let mut builder = ConstraintSystemBuilder::new_with_witness(allocator);
+	u32_add(&mut builder, n_vars, seed);
+	let witness = builder.take_witness().unwrap();
+	(builder.build().unwrap(), witness)
+}
+
+/// Proves `witness` at [`LOG_INV_RATE`] and [`SECURITY_BITS`].
+pub fn prove(
+	constraint_system: &ConstraintSystem<F>,
+	boundaries: &[Boundary<F>],
+	witness: Witness,
+) -> Result<Proof, Error> {
+	constraint_system::prove::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		constraint_system,
+		LOG_INV_RATE,
+		SECURITY_BITS,
+		boundaries,
+		witness,
+		&make_portable_backend(),
+	)
+}
+
+/// Verifies a proof produced by [`prove`].
+pub fn verify(
+	constraint_system: &ConstraintSystem<F>,
+	boundaries: &[Boundary<F>],
+	proof: Proof,
+) -> Result<(), Error> {
+	constraint_system::verify::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(constraint_system, LOG_INV_RATE, SECURITY_BITS, boundaries, proof)
+}
+
+/// Proves `witness` and verifies the proof.
+pub fn prove_and_verify(
+	constraint_system: &ConstraintSystem<F>,
+	boundaries: &[Boundary<F>],
+	witness: Witness,
+) {
+	let proof = prove(constraint_system, boundaries, witness).unwrap();
+	verify(constraint_system, boundaries, proof).unwrap();
+}
@@ -0,0 +1,197 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Checks the debugging helpers of a [`ConstraintSystem`] on the fibonacci circuit: witness and
+//! channel checks without a proof, proof bytes, and the per-step outcome and challenges of a
+//! verification.
+
+mod common;
+
+use binius_circuits::{
+	builder::{
+		test_utils::{LOG_INV_RATE, SECURITY_BITS},
+		types::{F, U},
+	},
+	fibonacci::{fibonacci_constraint_system, fibonacci_witness},
+};
+use binius_core::{
+	constraint_system::{
+		self,
+		channel::{Boundary, OracleOrConst},
+		error::{Error, VerificationError},
+		validate::verify_channel_balance,
+		ConstraintSystem, Proof, VerifyDiagnostics, VerifyStep,
+	},
+	fiat_shamir::HasherChallenger,
+	tower::CanonicalTowerFamily,
+};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use common::{prove, verify};
+
+fn verify_with_diagnostics(
+	constraint_system: &ConstraintSystem<F>,
+	boundaries: &[Boundary<F>],
+	proof: Proof,
+) -> Result<VerifyDiagnostics, Error> {
+	constraint_system::verify_with_diagnostics::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(constraint_system, LOG_INV_RATE, SECURITY_BITS, boundaries, proof)
+}
+
+#[test]
+fn test_is_satisfiable_witness() {
+	let allocator = bumpalo::Bump::new();
+	let witness = fibonacci_witness(100, &allocator).unwrap();
+	let (constraint_system, mut boundaries) = fibonacci_constraint_system(100).unwrap();
+	assert!(constraint_system.is_satisfiable_witness(&witness.witness, &boundaries));
+
+	boundaries[1].values.swap(0, 1);
+	assert!(!constraint_system.is_satisfiable_witness(&witness.witness, &boundaries));
+}
+
+#[test]
+fn test_verify_channel_balance() {
+	let allocator = bumpalo::Bump::new();
+	let witness = fibonacci_witness(100, &allocator).unwrap();
+	let (constraint_system, mut boundaries) = fibonacci_constraint_system(100).unwrap();
+	verify_channel_balance(&constraint_system, &boundaries, &witness.witness).unwrap();
+
+	boundaries.pop();
+	assert!(matches!(
+		verify_channel_balance(&constraint_system, &boundaries, &witness.witness),
+		Err(Error::Verification(VerificationError::ChannelUnbalanced { .. }))
+	));
+}
+
+#[test]
+fn test_proof_bytes_roundtrip() {
+	let allocator = bumpalo::Bump::new();
+	let witness = fibonacci_witness(50, &allocator).unwrap();
+	let (constraint_system, boundaries) = fibonacci_constraint_system(50).unwrap();
+	let proof = prove(&constraint_system, &boundaries, witness.witness).unwrap();
+
+	let bytes = proof.to_bytes();
+	let roundtrip = Proof::from_bytes(&bytes).unwrap();
+	assert_eq!(roundtrip, proof);
+	assert_eq!(Vec::from(roundtrip.clone()), bytes);
+
+	verify(&constraint_system, &boundaries, roundtrip).unwrap();
+}
+
+#[test]
+fn test_verify_with_diagnostics() {
+	let allocator = bumpalo::Bump::new();
+	let witness = fibonacci_witness(50, &allocator).unwrap();
+	let (constraint_system, boundaries) = fibonacci_constraint_system(50).unwrap();
+	let proof = prove(&constraint_system, &boundaries, witness.witness).unwrap();
+
+	let diagnostics =
+		verify_with_diagnostics(&constraint_system, &boundaries, proof.clone()).unwrap();
+	assert!(diagnostics.is_ok());
+	assert!(diagnostics.gkr_ok && diagnostics.flush_ok && diagnostics.zerocheck_ok);
+	assert!(diagnostics.evalcheck_ok && diagnostics.pcs_ok);
+	assert!(diagnostics.gkr_claims > 0 && diagnostics.pcs_claims > 0);
+
+	// The prover does not check the channels balance, so it proves the wrong boundaries.
+	let mut wrong_boundaries = boundaries.clone();
+	wrong_boundaries[1].values.swap(0, 1);
+	let witness = fibonacci_witness(50, &allocator).unwrap();
+	let wrong_proof = prove(&constraint_system, &wrong_boundaries, witness.witness).unwrap();
+	let diagnostics =
+		verify_with_diagnostics(&constraint_system, &wrong_boundaries, wrong_proof).unwrap();
+	assert!(diagnostics.gkr_ok && !diagnostics.flush_ok && !diagnostics.zerocheck_ok);
+	assert!(matches!(
+		diagnostics.failure,
+		Some(Error::Verification(VerificationError::ChannelUnbalanced { .. }))
+	));
+
+	let mut truncated = proof.clone();
+	truncated
+		.transcript
+		.truncate(truncated.transcript.len() / 2);
+	let diagnostics = verify_with_diagnostics(&constraint_system, &boundaries, truncated).unwrap();
+	assert!(diagnostics.evalcheck_ok && !diagnostics.pcs_ok);
+
+	let mut truncated = proof;
+	truncated.transcript.clear();
+	assert!(verify_with_diagnostics(&constraint_system, &boundaries, truncated).is_err());
+}
+
+#[test]
+fn test_replay_transcript() {
+	let allocator = bumpalo::Bump::new();
+	let witness = fibonacci_witness(50, &allocator).unwrap();
+	let (constraint_system, boundaries) = fibonacci_constraint_system(50).unwrap();
+	let proof = prove(&constraint_system, &boundaries, witness.witness).unwrap();
+
+	let replay = |boundaries: &[Boundary<F>]| {
+		let mut steps = Vec::new();
+		let result = constraint_system::replay_transcript::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(
+			&constraint_system,
+			LOG_INV_RATE,
+			SECURITY_BITS,
+			boundaries,
+			proof.clone(),
+			|step, challenges| steps.push((step, challenges.len())),
+		);
+		(result, steps)
+	};
+
+	let (result, steps) = replay(&boundaries);
+	result.unwrap();
+	assert_eq!(
+		steps.iter().map(|&(step, _)| step).collect::<Vec<_>>(),
+		[
+			VerifyStep::Gkr,
+			VerifyStep::Flush,
+			VerifyStep::Zerocheck,
+			VerifyStep::Evalcheck,
+			VerifyStep::Pcs
+		]
+	);
+
+	// The fibonacci circuit has no exponents, so the GKR step only samples the mixing challenge
+	// and one permutation challenge per channel.
+	assert_eq!(steps[0].1, constraint_system.max_channel_id + 2);
+
+	// The flush sumcheck samples one challenge per variable of the largest flushed oracle.
+	let flush_n_vars = constraint_system
+		.flushes
+		.iter()
+		.flat_map(|flush| &flush.oracles)
+		.filter_map(|oracle| match oracle {
+			OracleOrConst::Oracle(id) => Some(constraint_system.oracles.n_vars(*id)),
+			OracleOrConst::Const { .. } => None,
+		})
+		.max()
+		.unwrap();
+	assert_eq!(steps[1].1, flush_n_vars);
+
+	// The zerocheck samples one challenge per variable of the largest table, except for the
+	// variables folded by the univariate round.
+	let table_n_vars = constraint_system
+		.table_constraints
+		.iter()
+		.map(|constraint_set| constraint_set.n_vars)
+		.max()
+		.unwrap();
+	assert!(0 < steps[2].1 && steps[2].1 < table_n_vars);
+
+	assert_eq!(steps[3].1, 0);
+	assert_eq!(steps[4].1, 0);
+
+	let mut wrong_boundaries = boundaries;
+	wrong_boundaries[1].values.swap(0, 1);
+	let (result, steps) = replay(&wrong_boundaries);
+	assert!(result.is_err());
+	assert!(steps.is_empty());
+}
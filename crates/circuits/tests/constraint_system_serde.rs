@@ -0,0 +1,107 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Checks that a [`ConstraintSystem`] deserialized from JSON proves and verifies.
+
+mod common;
+
+use binius_circuits::{
+	builder::{types::F, ConstraintSystemBuilder},
+	transparent,
+	unconstrained::fill_column_from_fn,
+};
+use binius_core::{
+	constraint_system::{channel::OracleOrConst, ConstraintSystem},
+	oracle::OracleId,
+	transparent::eq_ind::EqIndPartialEval,
+};
+use binius_field::{BinaryField32b, Field};
+use binius_macros::arith_expr;
+use common::{prove_and_verify, u32_add, Witness};
+
+const N_VARS: usize = 12;
+const STEP_DOWN_INDEX: usize = 1000;
+
+/// Builds a circuit using every transparent polynomial kind supported by serde, alongside the
+/// derived oracles, constraints and flushes of a u32 addition.
+fn build_circuit(allocator: &bumpalo::Bump) -> (ConstraintSystem<F>, Witness<'_>) {
+	let mut builder = ConstraintSystemBuilder::new_with_witness(allocator);
+	u32_add(&mut builder, N_VARS, 0);
+
+	let c = F::new(0x1234);
+	let table_value = |i: usize| BinaryField32b::new(7 * i as u32 + 1);
+	let eq_ind = EqIndPartialEval::new(
+		(0..N_VARS)
+			.map(|i| F::new(0x9e3779b97f4a7c15 * (i as u128 + 1)))
+			.collect::<Vec<_>>(),
+	);
+
+	let step_down =
+		transparent::step_down(&mut builder, "step_down", N_VARS, STEP_DOWN_INDEX).unwrap();
+	let constant = transparent::constant(&mut builder, "constant", N_VARS, c).unwrap();
+	let table = transparent::make_transparent(
+		&mut builder,
+		"table",
+		&(0..1 << N_VARS).map(table_value).collect::<Vec<_>>(),
+	)
+	.unwrap();
+	let eq = add_eq_ind(&mut builder, &eq_ind);
+
+	let z = fill_column_from_fn::<F, _>(&mut builder, "z", N_VARS, |i| {
+		let step = if i < STEP_DOWN_INDEX { F::ONE } else { F::ZERO };
+		eq_ind.evaluate_on_hypercube(i) * step + c * F::from(table_value(i))
+	})
+	.unwrap();
+	builder.assert_zero(
+		"z",
+		[eq, step_down, constant, table, z],
+		arith_expr!(F[eq, step, c, table, z] = eq * step + c * table - z),
+	);
+
+	let channel = builder.add_channel();
+	let flushed = || {
+		[
+			OracleOrConst::Oracle(z),
+			OracleOrConst::Const {
+				base: F::ONE,
+				tower_level: 0,
+			},
+		]
+	};
+	builder.send(channel, 1 << N_VARS, flushed()).unwrap();
+	builder.receive(channel, 1 << N_VARS, flushed()).unwrap();
+
+	let witness = builder.take_witness().unwrap();
+	let constraint_system = builder.build().unwrap();
+	(constraint_system, witness)
+}
+
+fn add_eq_ind(builder: &mut ConstraintSystemBuilder, eq_ind: &EqIndPartialEval<F>) -> OracleId {
+	let id = builder.add_transparent("eq_ind", eq_ind.clone()).unwrap();
+	if let Some(witness) = builder.witness() {
+		let mut column = witness.new_column::<F>(id);
+		for (i, value) in column.as_mut_slice::<F>().iter_mut().enumerate() {
+			*value = eq_ind.evaluate_on_hypercube(i);
+		}
+	}
+	id
+}
+
+#[test]
+fn test_json_roundtrip_proves_and_verifies() {
+	let allocator = bumpalo::Bump::new();
+	let (constraint_system, witness) = build_circuit(&allocator);
+
+	let json = serde_json::to_string(&constraint_system).unwrap();
+	for kind in [
+		"Constant",
+		"StepDown",
+		"EqIndPartialEval",
+		"MultilinearExtensionTransparent",
+	] {
+		assert!(json.contains(&format!(r#""kind":"{kind}""#)), "missing {kind}");
+	}
+	let constraint_system = serde_json::from_str::<ConstraintSystem<F>>(&json).unwrap();
+	assert_eq!(serde_json::to_string(&constraint_system).unwrap(), json);
+
+	prove_and_verify(&constraint_system, &[], witness);
+}
@@ -0,0 +1,61 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Checks that a [`ConstraintSystem::merge`] of two circuits proves and verifies like the
+//! circuits do on their own.
+
+mod common;
+
+use binius_circuits::{
+	builder::{types::F, ConstraintSystemBuilder},
+	unconstrained::fill_column_from_fn,
+};
+use binius_core::constraint_system::{channel::OracleOrConst, ConstraintSystem};
+use binius_field::BinaryField1b;
+use common::{prove_and_verify, u32_add_circuit, Witness};
+
+const N_VARS: usize = 12;
+
+/// Sends a column over a channel and receives it back.
+fn channel_circuit(allocator: &bumpalo::Bump) -> (ConstraintSystem<F>, Witness<'_>) {
+	let mut builder = ConstraintSystemBuilder::new_with_witness(allocator);
+	let x =
+		fill_column_from_fn::<BinaryField1b, _>(&mut builder, "x", N_VARS, |i| i % 5 == 0).unwrap();
+	let channel = builder.add_channel();
+	builder
+		.send(channel, 1 << N_VARS, [OracleOrConst::Oracle(x)])
+		.unwrap();
+	builder
+		.receive(channel, 1 << N_VARS, [OracleOrConst::Oracle(x)])
+		.unwrap();
+	let witness = builder.take_witness().unwrap();
+	(builder.build().unwrap(), witness)
+}
+
+#[test]
+fn test_merged_circuit_proves() {
+	let allocator = bumpalo::Bump::new();
+	let (first, first_witness) = u32_add_circuit(&allocator, N_VARS, 0);
+	let (second, second_witness) = channel_circuit(&allocator);
+	prove_and_verify(&first, &[], first_witness);
+	prove_and_verify(&second, &[], second_witness);
+
+	let (first, mut witness) = u32_add_circuit(&allocator, N_VARS, 0);
+	let (second, second_witness) = channel_circuit(&allocator);
+	let offset = first.oracles.size();
+	let second_size = second.oracles.size();
+	let merged = first.merge(second).unwrap();
+	assert_eq!(merged.oracles.size(), offset + second_size);
+
+	// The witness of the second circuit moves along with its oracles.
+	witness
+		.update_multilin_poly_with_nonzero_scalars_prefixes(
+			(0..second_size)
+				.filter(|&id| second_witness.has(id))
+				.map(|id| {
+					let entry = second_witness.get_index_entry(id).unwrap();
+					(id + offset, entry.multilin_poly, entry.nonzero_scalars_prefix)
+				}),
+		)
+		.unwrap();
+	prove_and_verify(&merged, &[], witness);
+}
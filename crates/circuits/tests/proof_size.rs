@@ -0,0 +1,29 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Checks that [`estimate_proof_size`] is close to the size of an actual proof.
+
+mod common;
+
+use binius_circuits::builder::test_utils::{LOG_INV_RATE, SECURITY_BITS};
+use binius_core::constraint_system::estimate_proof_size;
+use common::{prove, u32_add_circuit};
+
+const N_VARS: usize = 16;
+
+#[test]
+fn test_estimate_proof_size() {
+	let allocator = bumpalo::Bump::new();
+	let (constraint_system, witness) = u32_add_circuit(&allocator, N_VARS, 0);
+
+	let estimate = estimate_proof_size(&constraint_system, LOG_INV_RATE, SECURITY_BITS).unwrap();
+
+	let actual = prove(&constraint_system, &[], witness)
+		.unwrap()
+		.transcript
+		.len();
+
+	assert!(
+		estimate <= 2 * actual && actual <= 2 * estimate,
+		"estimated {estimate} bytes, actual proof has {actual} bytes"
+	);
+}
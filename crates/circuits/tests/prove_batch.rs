@@ -0,0 +1,68 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Checks that [`constraint_system::prove_batch`] generates the same proofs as
+//! [`constraint_system::prove`].
+
+mod common;
+
+use binius_circuits::builder::{
+	test_utils::{LOG_INV_RATE, SECURITY_BITS},
+	types::U,
+};
+use binius_core::{constraint_system, fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
+use binius_hal::make_portable_backend;
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use common::{prove, u32_add_circuit};
+
+const N_VARS: usize = 12;
+
+#[test]
+fn test_prove_batch_matches_prove() {
+	let seeds = [0, 1, 2];
+
+	let allocator = bumpalo::Bump::new();
+	let proofs = seeds
+		.iter()
+		.map(|&seed| {
+			let (constraint_system, witness) = u32_add_circuit(&allocator, N_VARS, seed);
+			prove(&constraint_system, &[], witness).unwrap()
+		})
+		.collect::<Vec<_>>();
+
+	let allocator = bumpalo::Bump::new();
+	let (constraint_system, _) = u32_add_circuit(&allocator, N_VARS, 0);
+	let batch_proofs = constraint_system::prove_batch::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		&constraint_system,
+		LOG_INV_RATE,
+		SECURITY_BITS,
+		seeds.map(|seed| (&[][..], u32_add_circuit(&allocator, N_VARS, seed).1)),
+		&make_portable_backend(),
+	)
+	.unwrap();
+
+	assert_eq!(batch_proofs.len(), seeds.len());
+	for (batch_proof, proof) in batch_proofs.iter().zip(&proofs) {
+		assert_eq!(batch_proof.transcript, proof.transcript);
+	}
+
+	constraint_system::verify_batch::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(
+		&constraint_system,
+		LOG_INV_RATE,
+		SECURITY_BITS,
+		batch_proofs.into_iter().map(|proof| (&[][..], proof)),
+	)
+	.unwrap();
+}
@@ -0,0 +1,62 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Checks that [`constraint_system::streaming_prove`] writes the same proof as
+//! [`constraint_system::prove`].
+
+mod common;
+
+use binius_circuits::builder::{
+	test_utils::{LOG_INV_RATE, SECURITY_BITS},
+	types::U,
+};
+use binius_core::{
+	constraint_system::{self, Proof},
+	fiat_shamir::HasherChallenger,
+	tower::CanonicalTowerFamily,
+};
+use binius_hal::make_portable_backend;
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use common::{prove, u32_add_circuit, verify};
+
+const N_VARS: usize = 20;
+
+#[test]
+fn test_streaming_prove_matches_prove() {
+	let allocator = bumpalo::Bump::new();
+	let (constraint_system, witness) = u32_add_circuit(&allocator, N_VARS, 0);
+	let proof = prove(&constraint_system, &[], witness).unwrap();
+
+	let allocator = bumpalo::Bump::new();
+	let (constraint_system, witness) = u32_add_circuit(&allocator, N_VARS, 0);
+	let mut streamed = Vec::new();
+	let bytes_written = constraint_system::streaming_prove::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+		_,
+	>(
+		&constraint_system,
+		LOG_INV_RATE,
+		SECURITY_BITS,
+		&[],
+		witness,
+		&mut streamed,
+		&make_portable_backend(),
+	)
+	.unwrap();
+
+	assert_eq!(bytes_written, streamed.len());
+	assert_eq!(streamed, proof.transcript);
+
+	verify(
+		&constraint_system,
+		&[],
+		Proof {
+			transcript: streamed,
+		},
+	)
+	.unwrap();
+}
@@ -49,7 +49,7 @@
 //!                                       +-+-+
 //! ```
 
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt, ops::Not};
 
 use binius_field::{Field, PackedField, TowerField};
 use binius_macros::{DeserializeBytes, SerializeBytes};
@@ -61,11 +61,25 @@ use crate::{oracle::OracleId, witness::MultilinearExtensionIndex};
 pub type ChannelId = usize;
 
 #[derive(Debug, Clone, Copy, SerializeBytes, DeserializeBytes, PartialEq, Eq)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(bound = "")
+)]
 pub enum OracleOrConst<F: Field> {
 	Oracle(usize),
-	Const { base: F, tower_level: usize },
+	Const {
+		#[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::bytes"))]
+		base: F,
+		tower_level: usize,
+	},
 }
 #[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(bound = "")
+)]
 pub struct Flush<F: TowerField> {
 	pub oracles: Vec<OracleOrConst<F>>,
 	pub channel_id: ChannelId,
@@ -83,17 +97,114 @@ pub struct Boundary<F: TowerField> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FlushDirection {
 	Push,
 	Pull,
 }
 
+impl FlushDirection {
+	/// Returns the direction that cancels out a flush in this direction.
+	pub const fn opposite(&self) -> Self {
+		match self {
+			Self::Push => Self::Pull,
+			Self::Pull => Self::Push,
+		}
+	}
+}
+
+impl Not for FlushDirection {
+	type Output = Self;
+
+	fn not(self) -> Self {
+		self.opposite()
+	}
+}
+
+impl fmt::Display for FlushDirection {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Push => write!(f, "push"),
+			Self::Pull => write!(f, "pull"),
+		}
+	}
+}
+
+/// Checks that every channel balances with the flushes of the witness and the boundaries.
+///
+/// Returns the first error found. Use [`validate_witness_all_errors`] when debugging a circuit, to
+/// see all the unbalanced channels at once.
 pub fn validate_witness<F, P>(
 	witness: &MultilinearExtensionIndex<P>,
 	flushes: &[Flush<F>],
 	boundaries: &[Boundary<F>],
 	max_channel_id: ChannelId,
 ) -> Result<(), Error>
+where
+	P: PackedField<Scalar = F>,
+	F: TowerField,
+{
+	let channels = flush_channels(witness, flushes, boundaries, max_channel_id)?;
+
+	for (id, channel) in channels.iter().enumerate() {
+		if !channel.is_balanced() {
+			return Err(channel.unbalanced_error(id));
+		}
+	}
+
+	Ok(())
+}
+
+/// Same as [`validate_witness`], but checks all the channels and returns all the errors found.
+///
+/// The errors are ordered by channel id. If any flush or boundary has an out-of-range channel id,
+/// only those errors are returned.
+pub fn validate_witness_all_errors<F, P>(
+	witness: &MultilinearExtensionIndex<P>,
+	flushes: &[Flush<F>],
+	boundaries: &[Boundary<F>],
+	max_channel_id: ChannelId,
+) -> Result<(), Vec<Error>>
+where
+	P: PackedField<Scalar = F>,
+	F: TowerField,
+{
+	let channel_ids = boundaries
+		.iter()
+		.map(|boundary| boundary.channel_id)
+		.chain(flushes.iter().map(|flush| flush.channel_id));
+	let mut errors = channel_ids
+		.filter(|&channel_id| channel_id > max_channel_id)
+		.map(|channel_id| Error::ChannelIdOutOfRange {
+			max: max_channel_id,
+			got: channel_id,
+		})
+		.collect::<Vec<_>>();
+
+	if errors.is_empty() {
+		let channels = flush_channels(witness, flushes, boundaries, max_channel_id)
+			.map_err(|err| vec![err])?;
+		errors = channels
+			.iter()
+			.enumerate()
+			.filter(|(_, channel)| !channel.is_balanced())
+			.map(|(id, channel)| channel.unbalanced_error(id))
+			.collect();
+	}
+
+	if errors.is_empty() {
+		Ok(())
+	} else {
+		Err(errors)
+	}
+}
+
+fn flush_channels<F, P>(
+	witness: &MultilinearExtensionIndex<P>,
+	flushes: &[Flush<F>],
+	boundaries: &[Boundary<F>],
+	max_channel_id: ChannelId,
+) -> Result<Vec<Channel<F>>, Error>
 where
 	P: PackedField<Scalar = F>,
 	F: TowerField,
@@ -210,21 +321,7 @@ where
 		}
 	}
 
-	for (id, channel) in channels.iter().enumerate() {
-		if !channel.is_balanced() {
-			let unbalanced_flushes: Vec<_> = channel
-				.multiplicities
-				.iter()
-				.filter(|(_, &c)| c != 0i64)
-				.collect();
-
-			tracing::debug!("Channel {:?} unbalanced: {:?}", id, unbalanced_flushes);
-
-			return Err((VerificationError::ChannelUnbalanced { id }).into());
-		}
-	}
-
-	Ok(())
+	Ok(channels)
 }
 
 #[derive(Default, Debug, Clone)]
@@ -271,6 +368,18 @@ impl<F: TowerField> Channel<F> {
 	fn is_balanced(&self) -> bool {
 		self.multiplicities.iter().all(|(_, m)| *m == 0)
 	}
+
+	fn unbalanced_error(&self, id: ChannelId) -> Error {
+		let unbalanced_flushes: Vec<_> = self
+			.multiplicities
+			.iter()
+			.filter(|(_, &c)| c != 0i64)
+			.collect();
+
+		tracing::debug!("Channel {:?} unbalanced: {:?}", id, unbalanced_flushes);
+
+		(VerificationError::ChannelUnbalanced { id }).into()
+	}
 }
 
 #[cfg(test)]
@@ -279,6 +388,51 @@ mod tests {
 
 	use super::*;
 
+	#[test]
+	fn test_validate_witness_all_errors() {
+		type F = BinaryField64b;
+		let witness = MultilinearExtensionIndex::<F>::new();
+		let boundary = |channel_id, direction, value| Boundary {
+			values: vec![F::new(value)],
+			channel_id,
+			direction,
+			multiplicity: 1,
+		};
+		let boundaries = [
+			boundary(0, FlushDirection::Push, 1),
+			boundary(0, FlushDirection::Pull, 2),
+			boundary(1, FlushDirection::Push, 3),
+			boundary(1, FlushDirection::Pull, 3),
+			boundary(2, FlushDirection::Push, 4),
+		];
+
+		assert!(matches!(
+			validate_witness(&witness, &[], &boundaries, 2),
+			Err(Error::Verification(VerificationError::ChannelUnbalanced { id: 0 }))
+		));
+		let errors = validate_witness_all_errors(&witness, &[], &boundaries, 2).unwrap_err();
+		let ids = errors
+			.iter()
+			.map(|err| match err {
+				Error::Verification(VerificationError::ChannelUnbalanced { id }) => *id,
+				err => panic!("unexpected error {err}"),
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(ids, vec![0, 2]);
+
+		let errors = validate_witness_all_errors(&witness, &[], &boundaries, 1).unwrap_err();
+		assert!(matches!(errors[..], [Error::ChannelIdOutOfRange { max: 1, got: 2 }]));
+		assert!(validate_witness_all_errors(&witness, &[], &boundaries[2..4], 1).is_ok());
+	}
+
+	#[test]
+	fn test_flush_direction_opposite() {
+		assert_eq!(FlushDirection::Push.opposite(), FlushDirection::Pull);
+		assert_eq!(FlushDirection::Pull.opposite(), FlushDirection::Push);
+		assert_eq!(!FlushDirection::Push, FlushDirection::Pull);
+		assert_eq!(FlushDirection::Pull.to_string(), "pull");
+	}
+
 	#[test]
 	fn test_flush_push_single_row() {
 		let mut channel = Channel::<BinaryField64b>::new();
@@ -0,0 +1,235 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{collections::HashMap, hash::Hash};
+
+use binius_field::TowerField;
+use binius_math::ArithExpr;
+use binius_utils::{SerializationMode, SerializeBytes};
+
+use super::{
+	channel::{ChannelId, Flush},
+	ConstraintSystem,
+};
+use crate::oracle::{
+	Constraint, ConstraintPredicate, MultilinearOracleSet, MultilinearPolyOracle,
+	MultilinearPolyVariant, OracleId, TransparentPolyOracle,
+};
+
+/// The structural differences between two constraint systems.
+///
+/// Names of oracles and constraints are not compared, so renaming never shows up in a diff.
+/// Constraints are compared by the oracles they reference rather than by the constraint set they
+/// are grouped in.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CircuitDiff {
+	/// Ids of the oracles of the new system that are not in the old system.
+	pub added_oracles: Vec<OracleId>,
+	/// Ids of the oracles of the old system that are not in the new system.
+	pub removed_oracles: Vec<OracleId>,
+	/// Constraints of the new system that are not in the old system, as
+	/// `(constraint set index, constraint index)` pairs.
+	pub added_constraints: Vec<(usize, usize)>,
+	/// Constraints of the old system that are not in the new system, as
+	/// `(constraint set index, constraint index)` pairs.
+	pub removed_constraints: Vec<(usize, usize)>,
+	/// Indices of the flushes of the new system that are not in the old system.
+	pub added_flushes: Vec<usize>,
+	/// Indices of the flushes of the old system that are not in the new system.
+	pub removed_flushes: Vec<usize>,
+	/// The old and new maximum channel ids, if they differ.
+	pub max_channel_id: Option<(ChannelId, ChannelId)>,
+}
+
+impl CircuitDiff {
+	/// Whether the two constraint systems are structurally identical.
+	pub fn is_empty(&self) -> bool {
+		self == &Self::default()
+	}
+}
+
+pub(super) fn diff<F: TowerField>(
+	old: &ConstraintSystem<F>,
+	new: &ConstraintSystem<F>,
+) -> CircuitDiff {
+	// Oracles reference each other by id, so the oracles with the same id are compared. An id
+	// that only exists in one system, for instance because its oracle was removed, is only
+	// reported on that side.
+	let changed = |id: &OracleId,
+	               this: &MultilinearOracleSet<F>,
+	               that: &MultilinearOracleSet<F>| {
+		match (this.try_oracle(*id), that.try_oracle(*id)) {
+			(Ok(this), Ok(that)) => !same_oracle(this, that),
+			_ => true,
+		}
+	};
+	let added_oracles = new
+		.oracles
+		.ids()
+		.filter(|id| changed(id, &new.oracles, &old.oracles))
+		.collect();
+	let removed_oracles = old
+		.oracles
+		.ids()
+		.filter(|id| changed(id, &old.oracles, &new.oracles))
+		.collect();
+
+	let (removed_constraints, added_constraints) =
+		multiset_diff(constraint_keys(old), constraint_keys(new), same_constraint);
+	let (removed_flushes, added_flushes) = multiset_diff(
+		old.flushes
+			.iter()
+			.enumerate()
+			.map(|(index, flush)| (index, flush.channel_id, flush)),
+		new.flushes
+			.iter()
+			.enumerate()
+			.map(|(index, flush)| (index, flush.channel_id, flush)),
+		same_flush,
+	);
+
+	CircuitDiff {
+		added_oracles,
+		removed_oracles,
+		added_constraints,
+		removed_constraints,
+		added_flushes,
+		removed_flushes,
+		max_channel_id: (old.max_channel_id != new.max_channel_id)
+			.then_some((old.max_channel_id, new.max_channel_id)),
+	}
+}
+
+/// Compares two oracles, ignoring their names.
+fn same_oracle<F: TowerField>(a: MultilinearPolyOracle<F>, b: MultilinearPolyOracle<F>) -> bool {
+	match (&a.variant, &b.variant) {
+		// Transparent oracles compare by pointer, so they are compared by their encoding, which
+		// identifies the type and the parameters of the polynomial.
+		(
+			MultilinearPolyVariant::Transparent(a_poly),
+			MultilinearPolyVariant::Transparent(b_poly),
+		) => {
+			let encode = |poly: &TransparentPolyOracle<F>| {
+				let mut bytes = Vec::new();
+				poly.serialize(&mut bytes, SerializationMode::CanonicalTower)
+					.ok()
+					.map(|()| bytes)
+			};
+			(a.id, a.n_vars, a.tower_level) == (b.id, b.n_vars, b.tower_level)
+				&& (a_poly == b_poly || encode(a_poly).is_some_and(|a| Some(a) == encode(b_poly)))
+		}
+		_ => MultilinearPolyOracle { name: None, ..a } == MultilinearPolyOracle { name: None, ..b },
+	}
+}
+
+/// A constraint restricted to the oracles it references, so that it does not depend on the other
+/// constraints of its set.
+struct ConstraintKey<F: TowerField> {
+	composition: ArithExpr<F>,
+	predicate: ConstraintPredicate<F>,
+}
+
+fn constraint_keys<F: TowerField>(
+	system: &ConstraintSystem<F>,
+) -> impl Iterator<Item = ((usize, usize), Vec<OracleId>, ConstraintKey<F>)> + '_ {
+	system
+		.table_constraints
+		.iter()
+		.enumerate()
+		.flat_map(|(set_index, constraint_set)| {
+			constraint_set
+				.constraints
+				.iter()
+				.enumerate()
+				.map(move |(index, constraint)| {
+					let (referenced_ids, key) =
+						constraint_key(&constraint_set.oracle_ids, constraint);
+					((set_index, index), referenced_ids, key)
+				})
+		})
+}
+
+/// Returns the ids of the oracles referenced by `constraint`, and the constraint with its
+/// composition remapped to these oracles.
+fn constraint_key<F: TowerField>(
+	oracle_ids: &[OracleId],
+	constraint: &Constraint<F>,
+) -> (Vec<OracleId>, ConstraintKey<F>) {
+	let usage = constraint.composition.vars_usage();
+	let mut referenced_ids = Vec::new();
+	let indices = usage
+		.iter()
+		.zip(oracle_ids)
+		.map(|(&used, &id)| {
+			if used {
+				referenced_ids.push(id);
+			}
+			referenced_ids.len().saturating_sub(1)
+		})
+		.collect::<Vec<_>>();
+	let composition = constraint
+		.composition
+		.clone()
+		.remap_vars(&indices)
+		.expect("indices has an entry for every variable of the composition");
+	let key = ConstraintKey {
+		composition,
+		predicate: constraint.predicate.clone(),
+	};
+	(referenced_ids, key)
+}
+
+fn same_constraint<F: TowerField>(a: &ConstraintKey<F>, b: &ConstraintKey<F>) -> bool {
+	let same_predicate = match (&a.predicate, &b.predicate) {
+		(ConstraintPredicate::Sum(a), ConstraintPredicate::Sum(b)) => a == b,
+		(ConstraintPredicate::Zero, ConstraintPredicate::Zero) => true,
+		_ => false,
+	};
+	same_predicate && a.composition == b.composition
+}
+
+fn same_flush<F: TowerField>(a: &&Flush<F>, b: &&Flush<F>) -> bool {
+	a.oracles == b.oracles
+		&& a.channel_id == b.channel_id
+		&& a.direction == b.direction
+		&& a.selector == b.selector
+		&& a.multiplicity == b.multiplicity
+}
+
+/// Matches the equal items of `old` and `new` with multiplicity, and returns the positions of the
+/// unmatched items of `old` and `new` respectively.
+///
+/// Items are `(position, key, value)` triples, and only the values of items with the same key
+/// are compared with `eq`.
+fn multiset_diff<P: Ord, K: Hash + Eq, V>(
+	old: impl IntoIterator<Item = (P, K, V)>,
+	new: impl IntoIterator<Item = (P, K, V)>,
+	eq: impl Fn(&V, &V) -> bool,
+) -> (Vec<P>, Vec<P>) {
+	let mut unmatched_old = HashMap::<K, Vec<(P, V)>>::new();
+	for (position, key, value) in old {
+		unmatched_old
+			.entry(key)
+			.or_default()
+			.push((position, value));
+	}
+
+	let mut added = Vec::new();
+	for (position, key, value) in new {
+		let unmatched = unmatched_old.get_mut(&key);
+		let matched = unmatched.and_then(|unmatched| {
+			let index = unmatched.iter().position(|(_, old)| eq(old, &value))?;
+			Some(unmatched.swap_remove(index))
+		});
+		if matched.is_none() {
+			added.push(position);
+		}
+	}
+
+	let mut removed = unmatched_old
+		.into_values()
+		.flatten()
+		.map(|(position, _)| position)
+		.collect::<Vec<_>>();
+	removed.sort();
+	(removed, added)
+}
@@ -0,0 +1,180 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Export of the oracle graph of a constraint system in the Graphviz DOT format.
+//!
+//! Oracles are ellipses, with an edge from every inner oracle to the oracles derived from it.
+//! Constraints are diamonds linked to the oracles they reference, and flushes are boxes with an
+//! arrow from every pushed oracle and to every pulled oracle.
+
+use std::{fmt::Write, fs, io, path::Path};
+
+use binius_field::TowerField;
+
+use super::{
+	channel::{FlushDirection, OracleOrConst},
+	ConstraintSystem,
+};
+
+/// Renders the oracles, constraints and flushes of `cs` as a DOT digraph.
+pub fn oracle_graph_dot<F: TowerField>(cs: &ConstraintSystem<F>) -> String {
+	let mut dot = String::from("digraph constraint_system {\n");
+
+	for oracle in cs.oracles.iter() {
+		let id = oracle.id();
+		let label = format!("{}\nn_vars={}", oracle.label(), oracle.n_vars());
+		writeln!(dot, "\toracle_{id} [shape=ellipse, label={}];", quote(&label)).unwrap();
+		for inner_id in oracle.variant.inner_ids() {
+			writeln!(dot, "\toracle_{inner_id} -> oracle_{id};").unwrap();
+		}
+	}
+
+	for (set_index, constraint_set) in cs.table_constraints.iter().enumerate() {
+		for (index, constraint) in constraint_set.constraints.iter().enumerate() {
+			let node = format!("constraint_{set_index}_{index}");
+			writeln!(dot, "\t{node} [shape=diamond, label={}];", quote(&constraint.name)).unwrap();
+			for var in constraint.composition.vars() {
+				let oracle_id = constraint_set.oracle_ids[var];
+				writeln!(dot, "\toracle_{oracle_id} -> {node} [dir=none];").unwrap();
+			}
+		}
+	}
+
+	for (index, flush) in cs.flushes.iter().enumerate() {
+		let node = format!("flush_{index}");
+		let label = format!("{} channel {}", flush.direction, flush.channel_id);
+		writeln!(dot, "\t{node} [shape=box, label={}];", quote(&label)).unwrap();
+		for oracle in &flush.oracles {
+			let OracleOrConst::Oracle(oracle_id) = oracle else {
+				continue;
+			};
+			match flush.direction {
+				FlushDirection::Push => writeln!(dot, "\toracle_{oracle_id} -> {node};"),
+				FlushDirection::Pull => writeln!(dot, "\t{node} -> oracle_{oracle_id};"),
+			}
+			.unwrap();
+		}
+		if let Some(selector) = flush.selector {
+			writeln!(dot, "\toracle_{selector} -> {node} [style=dashed, label=\"selector\"];")
+				.unwrap();
+		}
+	}
+
+	dot.push_str("}\n");
+	dot
+}
+
+/// Writes the DOT rendering of `cs` to the file at `path`.
+pub fn to_file<F: TowerField>(cs: &ConstraintSystem<F>, path: &Path) -> io::Result<()> {
+	fs::write(path, oracle_graph_dot(cs))
+}
+
+/// Quotes a label as a DOT string, escaping the characters with a special meaning.
+fn quote(label: &str) -> String {
+	let escaped = label
+		.replace('\\', "\\\\")
+		.replace('"', "\\\"")
+		.replace('\n', "\\n");
+	format!("\"{escaped}\"")
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, BinaryField1b, Field, TowerField};
+	use binius_math::ArithExpr;
+
+	use super::{oracle_graph_dot, quote, to_file};
+	use crate::{
+		constraint_system::{
+			channel::{Flush, FlushDirection, OracleOrConst},
+			ConstraintSystem,
+		},
+		oracle::{ConstraintSetBuilder, MultilinearOracleSet, ShiftVariant},
+	};
+
+	fn constraint_system() -> ConstraintSystem<BinaryField128b> {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let a = oracles
+			.add_named("a")
+			.committed(4, BinaryField1b::TOWER_LEVEL);
+		let b = oracles
+			.add_named("b \"quoted\"")
+			.committed(4, BinaryField1b::TOWER_LEVEL);
+		let shifted = oracles
+			.add_shifted(a, 1, 4, ShiftVariant::LogicalLeft)
+			.unwrap();
+		let sum = oracles
+			.add_linear_combination(4, [(a, F::ONE), (b, F::ONE)])
+			.unwrap();
+
+		let mut builder = ConstraintSetBuilder::new();
+		builder.add_zerocheck("a_b", [a, b, shifted], ArithExpr::Var(0) * ArithExpr::Var(2));
+		let flush = |direction, oracle_id| Flush {
+			oracles: vec![
+				OracleOrConst::Oracle(oracle_id),
+				OracleOrConst::Const {
+					base: F::ONE,
+					tower_level: 0,
+				},
+			],
+			channel_id: 0,
+			direction,
+			selector: Some(b),
+			multiplicity: 1,
+		};
+		ConstraintSystem {
+			oracles: oracles.clone(),
+			table_constraints: vec![builder.build_one(&oracles).unwrap()],
+			non_zero_oracle_ids: vec![],
+			flushes: vec![
+				flush(FlushDirection::Push, sum),
+				flush(FlushDirection::Pull, shifted),
+			],
+			exponents: vec![],
+			max_channel_id: 0,
+		}
+	}
+
+	#[test]
+	fn test_oracle_graph_dot() {
+		let dot = oracle_graph_dot(&constraint_system());
+
+		assert!(dot.starts_with("digraph constraint_system {\n"));
+		assert!(dot.ends_with("}\n"));
+		assert_eq!(dot.matches('{').count(), dot.matches('}').count());
+
+		// The shifted oracle 2 and the linear combination 3 are derived from the committed ones.
+		assert!(dot.contains("\toracle_0 -> oracle_2;\n"));
+		assert!(dot.contains("\toracle_0 -> oracle_3;\n"));
+		assert!(dot.contains("\toracle_1 -> oracle_3;\n"));
+		assert!(dot.contains(r#"Committed: b \"quoted\"\nn_vars=4"#));
+
+		// The constraint only references the oracles of the variables it uses.
+		assert!(dot.contains("\tconstraint_0_0 [shape=diamond, label=\"a_b\"];\n"));
+		assert!(dot.contains("\toracle_0 -> constraint_0_0 [dir=none];\n"));
+		assert!(dot.contains("\toracle_2 -> constraint_0_0 [dir=none];\n"));
+		assert!(!dot.contains("\toracle_1 -> constraint_0_0"));
+
+		assert!(dot.contains("\tflush_0 [shape=box, label=\"push channel 0\"];\n"));
+		assert!(dot.contains("\toracle_3 -> flush_0;\n"));
+		assert!(dot.contains("\tflush_1 -> oracle_2;\n"));
+		assert!(dot.contains("\toracle_1 -> flush_1 [style=dashed, label=\"selector\"];\n"));
+	}
+
+	#[test]
+	fn test_to_file() {
+		let constraint_system = constraint_system();
+		let path =
+			std::env::temp_dir().join(format!("binius_dot_graph_{}.dot", std::process::id()));
+		to_file(&constraint_system, &path).unwrap();
+		let written = std::fs::read_to_string(&path).unwrap();
+		std::fs::remove_file(&path).unwrap();
+		assert_eq!(written, oracle_graph_dot(&constraint_system));
+	}
+
+	#[test]
+	fn test_quote() {
+		assert_eq!(quote(r#"a "b" \c"#), r#""a \"b\" \\c""#);
+		assert_eq!(quote("a\nb"), r#""a\nb""#);
+	}
+}
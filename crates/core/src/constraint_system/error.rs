@@ -6,7 +6,7 @@ use crate::{
 	oracle::OracleId,
 	piop, polynomial, protocols,
 	protocols::{gkr_gpa, greedy_evalcheck},
-	ring_switch, witness,
+	ring_switch, transcript, witness,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -92,10 +92,51 @@ pub enum Error {
 	Verification(#[from] VerificationError),
 
 	#[error("transcript error: {0}")]
-	TranscriptError(#[from] crate::transcript::Error),
+	TranscriptError(transcript::Error),
+
+	#[error("malformed transcript at byte {position}: expected {expected}, got {got}")]
+	TranscriptParseError {
+		position: usize,
+		expected: String,
+		got: String,
+	},
+
+	#[error("malformed advice at byte {position}: {detail}")]
+	AdviceParseError { position: usize, detail: String },
 
 	#[error("gkr exp error: {0}")]
 	GkrExp(#[from] crate::protocols::gkr_exp::Error),
+
+	#[error("I/O error: {0}")]
+	Io(#[from] std::io::Error),
+
+	#[cfg(feature = "proof_text")]
+	#[error("malformed hex proof: {0}")]
+	ProofHexDecode(#[from] hex::FromHexError),
+
+	#[cfg(feature = "proof_text")]
+	#[error("malformed base64 proof: {0}")]
+	ProofBase64Decode(#[from] base64::DecodeError),
+}
+
+impl From<transcript::Error> for Error {
+	fn from(err: transcript::Error) -> Self {
+		match err {
+			transcript::Error::TranscriptParseError {
+				position,
+				expected,
+				got,
+			} => Self::TranscriptParseError {
+				position,
+				expected,
+				got,
+			},
+			transcript::Error::AdviceParseError { position, detail } => {
+				Self::AdviceParseError { position, detail }
+			}
+			err => Self::TranscriptError(err),
+		}
+	}
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -30,6 +30,11 @@ use crate::{
 };
 
 #[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(bound = "")
+)]
 pub struct Exp<F: Field> {
 	/// A vector of `OracleId`s representing the exponent in little-endian bit order
 	pub bits_ids: Vec<OracleId>,
@@ -0,0 +1,102 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Header of the versioned binary format of constraint systems and proofs.
+//!
+//! The header is the 4-byte magic number `BINS` followed by the major and minor format versions,
+//! one byte each. A reader accepts data with its own major version and a minor version no greater
+//! than its own.
+//!
+//! Constraint systems and proofs are versioned separately, so that a change in the encoding of
+//! one does not reject data of the other.
+//!
+//! Version 2.0 of the constraint system format encodes the oracles of a [`MultilinearOracleSet`]
+//! as one optional entry per oracle id, so that removed oracles leave a gap and the other ids are
+//! preserved. Version 1.0 stored the oracles as a plain list.
+//!
+//! [`MultilinearOracleSet`]: crate::oracle::MultilinearOracleSet
+
+use std::io::{self, Read, Write};
+
+/// The magic number `0x42494E53`, which is `BINS` in ASCII.
+pub const MAGIC: [u8; 4] = *b"BINS";
+
+/// The `(major, minor)` version of the constraint system format written by this library.
+pub const CONSTRAINT_SYSTEM_FORMAT_VERSION: (u8, u8) = (2, 0);
+
+/// The `(major, minor)` version of the proof format written by this library.
+pub const PROOF_FORMAT_VERSION: (u8, u8) = (1, 0);
+
+pub(super) fn write_header(mut writer: impl Write, version: (u8, u8)) -> io::Result<()> {
+	writer.write_all(&MAGIC)?;
+	writer.write_all(&[version.0, version.1])
+}
+
+/// Reads a header, and checks that it can be read by a reader of the given version.
+pub(super) fn read_header(mut reader: impl Read, version: (u8, u8)) -> io::Result<()> {
+	let mut header = [0; 6];
+	reader.read_exact(&mut header)?;
+	if header[..4] != MAGIC {
+		return Err(io::Error::new(
+			io::ErrorKind::InvalidData,
+			format!("invalid magic number {:02x?}, expected {MAGIC:02x?}", &header[..4]),
+		));
+	}
+
+	let (major, minor) = (header[4], header[5]);
+	if major != version.0 || minor > version.1 {
+		return Err(io::Error::new(
+			io::ErrorKind::InvalidData,
+			format!(
+				"unsupported format version {major}.{minor}, this reader supports version {}.{}",
+				version.0, version.1
+			),
+		));
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io;
+
+	use super::{read_header, write_header, CONSTRAINT_SYSTEM_FORMAT_VERSION};
+
+	#[test]
+	fn test_read_header_same_version() {
+		let mut bytes = Vec::new();
+		write_header(&mut bytes, (1, 0)).unwrap();
+		assert_eq!(bytes, [0x42, 0x49, 0x4e, 0x53, 1, 0]);
+		read_header(bytes.as_slice(), (1, 0)).unwrap();
+		read_header(bytes.as_slice(), (1, 3)).unwrap();
+
+		let mut bytes = Vec::new();
+		write_header(&mut bytes, CONSTRAINT_SYSTEM_FORMAT_VERSION).unwrap();
+		assert_eq!(bytes, [0x42, 0x49, 0x4e, 0x53, 2, 0]);
+		read_header(bytes.as_slice(), CONSTRAINT_SYSTEM_FORMAT_VERSION).unwrap();
+	}
+
+	#[test]
+	fn test_read_header_unsupported_version() {
+		let mut bytes = Vec::new();
+		write_header(&mut bytes, (1, 0)).unwrap();
+
+		let err = read_header(bytes.as_slice(), (2, 0)).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+		assert_eq!(
+			err.to_string(),
+			"unsupported format version 1.0, this reader supports version 2.0"
+		);
+
+		let mut bytes = Vec::new();
+		write_header(&mut bytes, (1, 1)).unwrap();
+		let err = read_header(bytes.as_slice(), (1, 0)).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn test_read_header_invalid_magic() {
+		let err = read_header(&b"BINX\x01\x00"[..], (1, 0)).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+		assert!(err.to_string().contains("magic"));
+	}
+}
@@ -2,20 +2,51 @@
 
 pub mod channel;
 mod common;
+mod diff;
+pub mod dot_graph;
 pub mod error;
 pub mod exp;
+pub mod format;
 mod prove;
 pub mod validate;
 mod verify;
 
-use binius_field::{BinaryField128b, TowerField};
+use std::{
+	collections::HashMap,
+	io::{self, Read, Write},
+};
+
+#[cfg(feature = "proof_text")]
+use base64::{prelude::BASE64_STANDARD, Engine};
+use binius_field::{
+	BinaryField128b, BinaryField1b, Field, PackedExtension, PackedField, TowerField,
+};
+pub use binius_hal::ComputationBackend;
 use binius_macros::{DeserializeBytes, SerializeBytes};
-use channel::{ChannelId, Flush};
+use binius_ntt::NTTOptions;
+use binius_utils::{
+	bail, checked_arithmetics::log2_ceil_usize, DeserializeBytes, SerializationMode, SerializeBytes,
+};
+use channel::{Boundary, ChannelId, Flush, OracleOrConst};
+pub use diff::CircuitDiff;
 use exp::Exp;
-pub use prove::prove;
-pub use verify::verify;
+use itertools::{chain, Itertools};
+pub use prove::{
+	prove, prove_batch, prove_with_stats, streaming_prove, ProveStats, ProverUnderlier,
+};
+pub use verify::{
+	replay_transcript, verify, verify_batch, verify_with_diagnostics, VerifyDiagnostics, VerifyStep,
+};
 
-use crate::oracle::{ConstraintSet, MultilinearOracleSet, OracleId};
+use crate::{
+	fiat_shamir::Challenger,
+	oracle::{self, ConstraintSet, MultilinearOracleSet, MultilinearPolyVariant, OracleId},
+	piop,
+	protocols::fri,
+	reed_solomon::reed_solomon::ReedSolomonCode,
+	transcript::VerifierTranscript,
+	witness::MultilinearExtensionIndex,
+};
 
 /// Contains the 3 things that place constraints on witness data in Binius
 /// - virtual oracles
@@ -26,6 +57,14 @@ use crate::oracle::{ConstraintSet, MultilinearOracleSet, OracleId};
 /// constraints against a witness, as well as enabling generic prove/verify
 #[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
 #[deserialize_bytes(eval_generics(F = BinaryField128b))]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(bound(
+		serialize = "",
+		deserialize = "crate::oracle::TransparentPolyOracle<F>: serde::Deserialize<'de>"
+	))
+)]
 pub struct ConstraintSystem<F: TowerField> {
 	pub oracles: MultilinearOracleSet<F>,
 	pub table_constraints: Vec<ConstraintSet<F>>,
@@ -39,16 +78,888 @@ impl<F: TowerField> ConstraintSystem<F> {
 	pub const fn no_base_constraints(self) -> Self {
 		self
 	}
+
+	/// Returns the maximum degree of the table constraints, or zero if there are none.
+	pub fn global_max_degree(&self) -> usize {
+		self.table_constraints
+			.iter()
+			.map(ConstraintSet::max_degree)
+			.max()
+			.unwrap_or(0)
+	}
+
+	/// Returns the structural differences between `self` and `other`.
+	///
+	/// `self` is treated as the old system and `other` as the new one. An empty diff shows that
+	/// two circuits have the same oracles, constraints and flushes up to naming.
+	pub fn diff(&self, other: &Self) -> CircuitDiff {
+		diff::diff(self, other)
+	}
+
+	/// Returns whether `witness` satisfies the constraint system with the given boundaries.
+	///
+	/// This is [`validate::validate_witness`] without the details of the failure, which is
+	/// convenient for assertions in tests.
+	pub fn is_satisfiable_witness<P>(
+		&self,
+		witness: &MultilinearExtensionIndex<P>,
+		boundaries: &[Boundary<F>],
+	) -> bool
+	where
+		P: PackedField<Scalar = F> + PackedExtension<BinaryField1b>,
+	{
+		validate::validate_witness(self, boundaries, witness).is_ok()
+	}
+
+	/// Extracts the sub-circuit constraining the oracles `oracle_ids`.
+	///
+	/// The oracles of the sub-circuit are `oracle_ids` together with all the oracles they are
+	/// defined over. Only the table constraints, non-zero assertions, flushes and exponentiations
+	/// that exclusively reference these oracles are retained. Returns the sub-circuit along with
+	/// the mapping from oracle ids in `self` to oracle ids in the sub-circuit.
+	pub fn extract_subcircuit(
+		&self,
+		oracle_ids: &[OracleId],
+	) -> Result<(Self, HashMap<OracleId, OracleId>), error::Error> {
+		let (oracles, id_map) = self.oracles.subset(oracle_ids)?;
+		let included_ids = id_map.keys().copied().collect::<Vec<_>>();
+
+		let table_constraints = self
+			.table_constraints
+			.iter()
+			.filter_map(|constraint_set| constraint_set.oracle_subset(&included_ids))
+			.map(|mut constraint_set| {
+				for id in &mut constraint_set.oracle_ids {
+					*id = id_map[id];
+				}
+				constraint_set
+			})
+			.collect();
+
+		let non_zero_oracle_ids = self
+			.non_zero_oracle_ids
+			.iter()
+			.filter_map(|id| id_map.get(id).copied())
+			.collect();
+
+		let remap_oracle_or_const = |oracle_or_const: &OracleOrConst<F>| match *oracle_or_const {
+			OracleOrConst::Oracle(id) => id_map.get(&id).copied().map(OracleOrConst::Oracle),
+			OracleOrConst::Const { base, tower_level } => {
+				Some(OracleOrConst::Const { base, tower_level })
+			}
+		};
+
+		let flushes = self
+			.flushes
+			.iter()
+			.filter_map(|flush| {
+				let oracles = flush
+					.oracles
+					.iter()
+					.map(remap_oracle_or_const)
+					.collect::<Option<Vec<_>>>()?;
+				let selector = match flush.selector {
+					Some(selector) => Some(*id_map.get(&selector)?),
+					None => None,
+				};
+				Some(Flush {
+					oracles,
+					channel_id: flush.channel_id,
+					direction: flush.direction,
+					selector,
+					multiplicity: flush.multiplicity,
+				})
+			})
+			.collect();
+
+		let exponents = self
+			.exponents
+			.iter()
+			.filter_map(|exp| {
+				Some(Exp {
+					bits_ids: exp
+						.bits_ids
+						.iter()
+						.map(|id| id_map.get(id).copied())
+						.collect::<Option<Vec<_>>>()?,
+					base: remap_oracle_or_const(&exp.base)?,
+					exp_result_id: *id_map.get(&exp.exp_result_id)?,
+				})
+			})
+			.collect();
+
+		let subcircuit = Self {
+			oracles,
+			table_constraints,
+			non_zero_oracle_ids,
+			flushes,
+			exponents,
+			max_channel_id: self.max_channel_id,
+		};
+		Ok((subcircuit, id_map))
+	}
+
+	/// Assigns the oracles contiguous ids starting from 0, keeping their order.
+	///
+	/// Removed oracles leave gaps in the id space, which this closes. Every remaining oracle is
+	/// kept, whether or not anything depends on it; see [`Self::remove_unreferenced_oracles`] to
+	/// also drop the unreferenced ones. Returns the reindexed system together with the new id of
+	/// every oracle of `self`, indexed by the old id, or `None` for the removed oracles.
+	pub fn reindex_oracles(self) -> (Self, Vec<Option<OracleId>>) {
+		debug_assert!(
+			self.referenced_oracle_ids()
+				.all(|id| self.oracles.is_valid_oracle_id(id)),
+			"a constraint references a removed oracle"
+		);
+
+		let ids = self.oracles.ids().collect::<Vec<_>>();
+		let (reindexed, id_map) = self
+			.extract_subcircuit(&ids)
+			.expect("the ids of the oracle set are valid");
+
+		let new_ids = (0..self.oracles.size())
+			.map(|id| id_map.get(&id).copied())
+			.collect();
+		(reindexed, new_ids)
+	}
+
+	/// Removes the oracles that no constraint, flush, non-zero assertion or exponentiation
+	/// depends on, and assigns the remaining oracles contiguous ids starting from 0.
+	///
+	/// Returns the reduced system together with the new id of every oracle of `self`, or `None`
+	/// for the removed oracles.
+	pub fn remove_unreferenced_oracles(
+		self,
+	) -> Result<(Self, Vec<Option<OracleId>>), error::Error> {
+		let referenced_ids = self.referenced_oracle_ids().unique().collect::<Vec<_>>();
+
+		let (reduced, id_map) = self.extract_subcircuit(&referenced_ids)?;
+		let new_ids = (0..self.oracles.size())
+			.map(|id| id_map.get(&id).copied())
+			.collect();
+		Ok((reduced, new_ids))
+	}
+
+	/// Combines two independently built constraint systems into one.
+	///
+	/// The oracles of `other` are appended after the oracles of `self`, so every oracle id in
+	/// `other` is shifted by `self.oracles.size()`. The channels of `other` are shifted by
+	/// `self.max_channel_id + 1`, which keeps the channels of the two systems apart. Boundaries
+	/// on the channels of `other` need the same shift, and so do the witness entries of `other`.
+	pub fn merge(mut self, other: Self) -> Result<Self, error::Error> {
+		let oracle_offset = self.oracles.size();
+		let channel_offset = self.max_channel_id + 1;
+
+		self.oracles.append(&other.oracles);
+		if let Some(id) = other
+			.referenced_oracle_ids()
+			.map(|id| id + oracle_offset)
+			.find(|&id| !self.oracles.is_valid_oracle_id(id))
+		{
+			bail!(oracle::Error::InvalidOracleId(id));
+		}
+
+		let shift_oracle_or_const = |oracle_or_const: OracleOrConst<F>| match oracle_or_const {
+			OracleOrConst::Oracle(id) => OracleOrConst::Oracle(id + oracle_offset),
+			OracleOrConst::Const { base, tower_level } => {
+				OracleOrConst::Const { base, tower_level }
+			}
+		};
+
+		self.table_constraints
+			.extend(
+				other
+					.table_constraints
+					.into_iter()
+					.map(|mut constraint_set| {
+						for id in &mut constraint_set.oracle_ids {
+							*id += oracle_offset;
+						}
+						constraint_set
+					}),
+			);
+		self.non_zero_oracle_ids.extend(
+			other
+				.non_zero_oracle_ids
+				.into_iter()
+				.map(|id| id + oracle_offset),
+		);
+		self.flushes.extend(other.flushes.into_iter().map(|flush| {
+			Flush {
+				oracles: flush
+					.oracles
+					.into_iter()
+					.map(shift_oracle_or_const)
+					.collect(),
+				channel_id: flush.channel_id + channel_offset,
+				selector: flush.selector.map(|selector| selector + oracle_offset),
+				..flush
+			}
+		}));
+		self.exponents
+			.extend(other.exponents.into_iter().map(|exp| {
+				Exp {
+					bits_ids: exp
+						.bits_ids
+						.into_iter()
+						.map(|id| id + oracle_offset)
+						.collect(),
+					base: shift_oracle_or_const(exp.base),
+					exp_result_id: exp.exp_result_id + oracle_offset,
+				}
+			}));
+		self.max_channel_id = other.max_channel_id + channel_offset;
+		Ok(self)
+	}
+
+	/// Checks the internal invariants of the constraint system, without a witness.
+	///
+	/// Every oracle id referenced by the constraints, non-zero assertions, flushes and
+	/// exponentiations must exist, every flush must be on a channel no greater than
+	/// `max_channel_id`, and every committed oracle must have a committable tower level. All the
+	/// violations are returned, rather than only the first one.
+	pub fn check_consistency(&self) -> Result<(), Vec<error::Error>> {
+		let invalid_ids = self
+			.referenced_oracle_ids()
+			.unique()
+			.filter(|&id| !self.oracles.is_valid_oracle_id(id))
+			.map(|id| error::Error::from(oracle::Error::InvalidOracleId(id)));
+		let invalid_channels = self
+			.flushes
+			.iter()
+			.filter(|flush| flush.channel_id > self.max_channel_id)
+			.map(|flush| error::Error::ChannelIdOutOfRange {
+				max: self.max_channel_id,
+				got: flush.channel_id,
+			});
+		let invalid_tower_levels = self
+			.oracles
+			.iter()
+			.filter(|oracle| {
+				matches!(oracle.variant, MultilinearPolyVariant::Committed)
+					&& !matches!(oracle.tower_level, 0 | 3..=7)
+			})
+			.map(|oracle| error::Error::CannotCommitTowerLevel {
+				tower_level: oracle.tower_level,
+			});
+
+		let errors =
+			chain!(invalid_ids, invalid_channels, invalid_tower_levels).collect::<Vec<_>>();
+		if errors.is_empty() {
+			Ok(())
+		} else {
+			Err(errors)
+		}
+	}
+
+	/// Iterates over the oracle ids referenced by the constraints, non-zero assertions, flushes
+	/// and exponentiations, with repetitions.
+	fn referenced_oracle_ids(&self) -> impl Iterator<Item = OracleId> + '_ {
+		chain!(
+			self.table_constraints
+				.iter()
+				.flat_map(|constraint_set| constraint_set.oracle_ids.iter().copied()),
+			self.non_zero_oracle_ids.iter().copied(),
+			self.flushes.iter().flat_map(|flush| {
+				chain!(flush.oracles.iter().filter_map(oracle_id), flush.selector)
+			}),
+			self.exponents.iter().flat_map(|exp| {
+				chain!(exp.bits_ids.iter().copied(), oracle_id(&exp.base), [exp.exp_result_id])
+			}),
+		)
+	}
+
+	/// Writes the constraint system in the versioned binary format.
+	///
+	/// The [`format`] header is followed by the [`SerializeBytes`] encoding of the system.
+	pub fn write(&self, mut writer: impl Write) -> io::Result<()> {
+		format::write_header(&mut writer, format::CONSTRAINT_SYSTEM_FORMAT_VERSION)?;
+		let mut bytes = Vec::new();
+		SerializeBytes::serialize(self, &mut bytes, SerializationMode::CanonicalTower)
+			.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+		writer.write_all(&bytes)
+	}
+}
+
+impl ConstraintSystem<BinaryField128b> {
+	/// Reads a constraint system written with [`Self::write`].
+	///
+	/// Fails with [`io::ErrorKind::InvalidData`] if the data was written in an unsupported
+	/// format version.
+	pub fn read(mut reader: impl Read) -> io::Result<Self> {
+		format::read_header(&mut reader, format::CONSTRAINT_SYSTEM_FORMAT_VERSION)?;
+		let mut bytes = Vec::new();
+		reader.read_to_end(&mut bytes)?;
+		DeserializeBytes::deserialize(bytes.as_slice(), SerializationMode::CanonicalTower)
+			.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+	}
+}
+
+const fn oracle_id<F: Field>(oracle_or_const: &OracleOrConst<F>) -> Option<OracleId> {
+	match *oracle_or_const {
+		OracleOrConst::Oracle(id) => Some(id),
+		OracleOrConst::Const { .. } => None,
+	}
+}
+
+/// Size in bytes of the digests of the Merkle tree commitments, for the 256-bit hashes in use.
+const ESTIMATE_DIGEST_SIZE: usize = 32;
+
+/// Estimates the size in bytes of a proof of `constraint_system`, without running the prover.
+///
+/// The estimate is computed from the oracle and variable counts, and follows the protocol steps:
+/// the GKR exponentiations and grand products, the flush, zerocheck and evalcheck sumchecks, the
+/// ring switching reduction, and the FRI opening of the committed batch with its Merkle paths.
+/// Sumcheck round messages are counted once per batch. It is meant for comparing circuit designs
+/// and choosing parameters, and may be off from the actual size by a small factor.
+pub fn estimate_proof_size<F: TowerField>(
+	constraint_system: &ConstraintSystem<F>,
+	log_inv_rate: usize,
+	security_bits: usize,
+) -> Result<usize, error::Error> {
+	let scalar_size = size_of::<F>();
+	let oracles = &constraint_system.oracles;
+	let (commit_meta, _) = piop::make_oracle_commit_meta(oracles)?;
+
+	// A batched sumcheck sends `degree` coefficients per round, then an evaluation per multilinear.
+	let sumcheck_size = |n_rounds: usize, degree: usize, n_evals: usize| {
+		(n_rounds * degree + n_evals) * scalar_size
+	};
+
+	// The commitment to the batch of committed oracles.
+	let mut size = ESTIMATE_DIGEST_SIZE;
+
+	// GKR exponentiation, with a degree 3 sumcheck per bit of the exponent.
+	for exp in &constraint_system.exponents {
+		let n_vars = exp.n_vars(oracles);
+		size += scalar_size + exp.bits_ids.len() * sumcheck_size(n_vars, 3, 3);
+	}
+
+	// Grand products for the non-zero oracles and the flushes. Each product is sent, then the
+	// GKR layers are proven with batched degree 2 sumchecks of increasing size.
+	let flush_n_vars = constraint_system
+		.flushes
+		.iter()
+		.map(|flush| {
+			flush
+				.oracles
+				.iter()
+				.find_map(oracle_id)
+				.map_or(0, |id| oracles.n_vars(id))
+		})
+		.collect::<Vec<_>>();
+	let product_n_vars = chain!(
+		constraint_system
+			.non_zero_oracle_ids
+			.iter()
+			.map(|&id| oracles.n_vars(id)),
+		flush_n_vars.iter().copied(),
+	)
+	.collect::<Vec<_>>();
+	size += product_n_vars.len() * scalar_size;
+	let max_product_n_vars = product_n_vars.iter().copied().max().unwrap_or(0);
+	for layer in 0..max_product_n_vars {
+		let n_claims = product_n_vars
+			.iter()
+			.filter(|&&n_vars| n_vars > layer)
+			.count();
+		size += sumcheck_size(layer, 2, 2 * n_claims);
+	}
+
+	// The flush sumcheck reduces the last GKR layers to the flushed oracles and selectors.
+	if let Some(&max_flush_n_vars) = flush_n_vars.iter().max() {
+		size += sumcheck_size(max_flush_n_vars, 3, 2 * flush_n_vars.len());
+	}
+
+	// Zerocheck on the table constraints.
+	if let Some(max_n_vars) = constraint_system
+		.table_constraints
+		.iter()
+		.map(|constraint_set| constraint_set.n_vars)
+		.max()
+	{
+		let n_evals = constraint_system
+			.table_constraints
+			.iter()
+			.map(|constraint_set| constraint_set.oracle_ids.len())
+			.sum();
+		size += sumcheck_size(max_n_vars, constraint_system.global_max_degree() + 1, n_evals);
+	}
+
+	// Evalcheck sends about one evaluation per oracle, and batches the shifted and packed claims
+	// into a degree 2 sumcheck.
+	let max_n_vars = oracles
+		.iter()
+		.map(|oracle| oracle.n_vars)
+		.max()
+		.unwrap_or(0);
+	size += sumcheck_size(max_n_vars, 2, oracles.ids().count());
+
+	// Ring switching sends a tensor algebra element per committed oracle, then the committed
+	// batch is reduced to a single evaluation with a degree 2 sumcheck.
+	size += oracles
+		.iter()
+		.filter(|oracle| matches!(oracle.variant, MultilinearPolyVariant::Committed))
+		.map(|oracle| (1 << (F::TOWER_LEVEL.saturating_sub(oracle.tower_level))) * scalar_size)
+		.sum::<usize>();
+	size += sumcheck_size(commit_meta.total_vars(), 2, 1);
+
+	// FRI, with the same arity and query count as the prover. Every fold round commits to an
+	// oracle and each query opens a coset in it, with a Merkle path up to the layer of the tree
+	// that is sent in full.
+	let log_len = commit_meta.total_vars() + log_inv_rate;
+	let arity = fri::estimate_optimal_arity(log_len, ESTIMATE_DIGEST_SIZE, scalar_size);
+	let n_fold_oracles = commit_meta.total_vars().saturating_sub(1) / arity;
+	// The committed batch is encoded with an interleaved code aligned with the first fold arity.
+	let log_batch_size = if n_fold_oracles > 0 { arity } else { 0 };
+	let rs_code = ReedSolomonCode::<F>::new(
+		commit_meta.total_vars() - log_batch_size,
+		log_inv_rate,
+		&NTTOptions::default(),
+	)
+	.map_err(piop::Error::from)?;
+	let n_queries = fri::calculate_n_test_queries::<F, F>(security_bits, &rs_code)
+		.map_err(piop::Error::from)?;
+	let mut log_n_cosets = log_len;
+	for _ in 0..n_fold_oracles {
+		log_n_cosets -= arity;
+		let layer_depth = log2_ceil_usize(n_queries).min(log_n_cosets);
+		size += ESTIMATE_DIGEST_SIZE + (1 << layer_depth) * ESTIMATE_DIGEST_SIZE;
+		size += n_queries
+			* ((1 << arity) * scalar_size + (log_n_cosets - layer_depth) * ESTIMATE_DIGEST_SIZE);
+	}
+	// The terminal codeword.
+	size += (1 << log_n_cosets) * scalar_size;
+
+	Ok(size)
 }
 
 /// Constraint system proof that has been serialized into bytes
-#[derive(Debug, Clone)]
+///
+/// The advice written by the prover, such as Merkle decommitments, is interleaved with the prover
+/// messages on the transcript tape, so there is no separate advice buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Proof {
 	pub transcript: Vec<u8>,
 }
 
 impl Proof {
+	/// Returns the size of the proof in bytes, including the advice.
 	pub fn get_proof_size(&self) -> usize {
 		self.transcript.len()
 	}
+
+	/// Returns the serialized proof, for storage or transmission.
+	///
+	/// This is the versioned binary format of [`Self::write`].
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut bytes = Vec::new();
+		self.write(&mut bytes)
+			.expect("writing to a Vec does not fail");
+		bytes
+	}
+
+	/// Reads a proof serialized with [`Self::to_bytes`].
+	///
+	/// Only the format header is checked here; malformed transcripts are rejected by the
+	/// verifier.
+	pub fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+		Self::read(bytes)
+	}
+
+	/// Returns the bytes of [`Self::to_bytes`] as a lowercase hex string, for exchanging proofs
+	/// as text.
+	#[cfg(feature = "proof_text")]
+	pub fn to_hex(&self) -> String {
+		hex::encode(self.to_bytes())
+	}
+
+	/// Reads a proof encoded with [`Self::to_hex`].
+	#[cfg(feature = "proof_text")]
+	pub fn from_hex(s: &str) -> Result<Self, error::Error> {
+		Ok(Self::from_bytes(&hex::decode(s.trim())?)?)
+	}
+
+	/// Returns the proof as a standard base64 string, which is more compact than [`Self::to_hex`].
+	#[cfg(feature = "proof_text")]
+	pub fn to_base64(&self) -> String {
+		BASE64_STANDARD.encode(self.to_bytes())
+	}
+
+	/// Reads a proof encoded with [`Self::to_base64`].
+	#[cfg(feature = "proof_text")]
+	pub fn from_base64(s: &str) -> Result<Self, error::Error> {
+		Ok(Self::from_bytes(&BASE64_STANDARD.decode(s.trim())?)?)
+	}
+
+	/// Returns a verifier transcript positioned at the start of the proof.
+	///
+	/// This allows replaying the proof transcript manually, for example to inspect the sampled
+	/// challenges when debugging a circuit. Decommitments are interleaved with the prover
+	/// messages on the same tape and are read through [`VerifierTranscript::decommitment`].
+	pub fn transcript_cursor<Challenger_: Challenger + Default>(
+		&self,
+	) -> VerifierTranscript<Challenger_> {
+		VerifierTranscript::new(self.transcript.clone())
+	}
+
+	/// Writes the proof in the versioned binary format.
+	///
+	/// The [`format`] header is followed by the transcript bytes.
+	pub fn write(&self, mut writer: impl Write) -> io::Result<()> {
+		format::write_header(&mut writer, format::PROOF_FORMAT_VERSION)?;
+		writer.write_all(&self.transcript)
+	}
+
+	/// Reads a proof written with [`Self::write`].
+	///
+	/// Fails with [`io::ErrorKind::InvalidData`] if the data was written in an unsupported
+	/// format version.
+	pub fn read(mut reader: impl Read) -> io::Result<Self> {
+		format::read_header(&mut reader, format::PROOF_FORMAT_VERSION)?;
+		let mut transcript = Vec::new();
+		reader.read_to_end(&mut transcript)?;
+		Ok(Self { transcript })
+	}
+}
+
+/// Serializes the proof with [`Proof::to_bytes`].
+impl From<Proof> for Vec<u8> {
+	fn from(proof: Proof) -> Self {
+		proof.to_bytes()
+	}
+}
+
+/// Reads a proof with [`Proof::from_bytes`].
+impl TryFrom<Vec<u8>> for Proof {
+	type Error = io::Error;
+
+	fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+		Self::from_bytes(&bytes)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, BinaryField1b, Field, TowerField};
+	use binius_math::ArithExpr;
+
+	use super::{
+		channel::{Flush, FlushDirection, OracleOrConst},
+		error::Error,
+		ConstraintSystem, Proof,
+	};
+	use crate::oracle::{self, ConstraintSetBuilder, MultilinearOracleSet};
+
+	#[test]
+	fn test_diff() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let a = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		let b = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+
+		let mut builder = ConstraintSetBuilder::new();
+		builder.add_zerocheck("a_b", [a, b], ArithExpr::Var(0) * ArithExpr::Var(1));
+		let old = ConstraintSystem {
+			oracles: oracles.clone(),
+			table_constraints: vec![builder.build_one(&oracles).unwrap()],
+			non_zero_oracle_ids: vec![],
+			flushes: vec![],
+			exponents: vec![],
+			max_channel_id: 0,
+		};
+		assert!(old.diff(&old.clone()).is_empty());
+
+		let c = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		let mut builder = ConstraintSetBuilder::new();
+		builder.add_zerocheck("renamed", [a, b], ArithExpr::Var(0) * ArithExpr::Var(1));
+		builder.add_zerocheck("c", [c], ArithExpr::Var(0));
+		let new = ConstraintSystem {
+			oracles: oracles.clone(),
+			table_constraints: vec![builder.build_one(&oracles).unwrap()],
+			non_zero_oracle_ids: vec![],
+			flushes: vec![Flush {
+				oracles: vec![OracleOrConst::Oracle(c)],
+				channel_id: 1,
+				direction: FlushDirection::Push,
+				selector: None,
+				multiplicity: 1,
+			}],
+			exponents: vec![],
+			max_channel_id: 1,
+		};
+
+		let diff = old.diff(&new);
+		assert!(!diff.is_empty());
+		assert_eq!(diff.added_oracles, vec![c]);
+		assert!(diff.removed_oracles.is_empty());
+		assert_eq!(diff.added_constraints, vec![(0, 1)]);
+		assert!(diff.removed_constraints.is_empty());
+		assert_eq!(diff.added_flushes, vec![0]);
+		assert!(diff.removed_flushes.is_empty());
+		assert_eq!(diff.max_channel_id, Some((0, 1)));
+
+		let diff = new.diff(&old);
+		assert_eq!(diff.removed_oracles, vec![c]);
+		assert_eq!(diff.removed_constraints, vec![(0, 1)]);
+		assert_eq!(diff.removed_flushes, vec![0]);
+
+		// Removing an oracle leaves the ids of the other oracles unchanged, so only the removed
+		// oracle shows up in the diff.
+		let mut removed = new.clone();
+		removed.oracles.remove_oracle(b).unwrap();
+		let diff = new.diff(&removed);
+		assert_eq!(diff.removed_oracles, vec![b]);
+		assert!(diff.added_oracles.is_empty());
+	}
+
+	#[test]
+	fn test_extract_subcircuit() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let a = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		let b = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		let c = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		let sum = oracles
+			.add_linear_combination(4, [(a, F::ONE), (b, F::ONE)])
+			.unwrap();
+
+		let mut builder = ConstraintSetBuilder::new();
+		builder.add_zerocheck("a_b", [a, b], ArithExpr::Var(0) * ArithExpr::Var(1));
+		builder.add_zerocheck("c_sum", [c, sum], ArithExpr::Var(0) + ArithExpr::Var(1));
+		builder.add_zerocheck("sum", [sum], ArithExpr::Var(0) * ArithExpr::Var(0));
+		let table_constraints = vec![builder.build_one(&oracles).unwrap()];
+
+		let flush = |id| Flush {
+			oracles: vec![OracleOrConst::Oracle(id)],
+			channel_id: 0,
+			direction: FlushDirection::Push,
+			selector: None,
+			multiplicity: 1,
+		};
+		let constraint_system = ConstraintSystem {
+			oracles,
+			table_constraints,
+			non_zero_oracle_ids: vec![b, c],
+			flushes: vec![flush(c), flush(sum)],
+			exponents: vec![],
+			max_channel_id: 0,
+		};
+
+		let (subcircuit, id_map) = constraint_system.extract_subcircuit(&[sum]).unwrap();
+		assert_eq!(subcircuit.oracles.size(), 3);
+		assert!(!id_map.contains_key(&c));
+
+		let [constraint_set] = subcircuit.table_constraints.as_slice() else {
+			panic!("expected a single constraint set");
+		};
+		let names = constraint_set
+			.constraints
+			.iter()
+			.map(|constraint| constraint.name.as_str())
+			.collect::<Vec<_>>();
+		assert_eq!(names, ["a_b", "sum"]);
+		assert!(names.len() < constraint_system.table_constraints[0].constraints.len());
+		assert_eq!(constraint_set.oracle_ids, [a, b, sum].map(|id| id_map[&id]));
+		assert_eq!(
+			constraint_set.constraints[1].composition,
+			ArithExpr::Var(2) * ArithExpr::Var(2)
+		);
+
+		assert_eq!(subcircuit.non_zero_oracle_ids, vec![id_map[&b]]);
+		assert_eq!(subcircuit.flushes.len(), 1);
+		assert!(matches!(
+			subcircuit.flushes[0].oracles[..],
+			[OracleOrConst::Oracle(id)] if id == id_map[&sum]
+		));
+	}
+
+	#[test]
+	fn test_merge() {
+		type F = BinaryField128b;
+		let flush = |id, channel_id| Flush {
+			oracles: vec![OracleOrConst::Oracle(id)],
+			channel_id,
+			direction: FlushDirection::Push,
+			selector: None,
+			multiplicity: 1,
+		};
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let a = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		let mut builder = ConstraintSetBuilder::new();
+		builder.add_zerocheck("a", [a], ArithExpr::Var(0) * ArithExpr::Var(0));
+		let first = ConstraintSystem {
+			oracles: oracles.clone(),
+			table_constraints: vec![builder.build_one(&oracles).unwrap()],
+			non_zero_oracle_ids: vec![],
+			flushes: vec![flush(a, 1)],
+			exponents: vec![],
+			max_channel_id: 1,
+		};
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let b = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		let c = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		let mut builder = ConstraintSetBuilder::new();
+		builder.add_zerocheck("b_c", [b, c], ArithExpr::Var(0) * ArithExpr::Var(1));
+		let second = ConstraintSystem {
+			oracles: oracles.clone(),
+			table_constraints: vec![builder.build_one(&oracles).unwrap()],
+			non_zero_oracle_ids: vec![c],
+			flushes: vec![flush(b, 0), flush(c, 2)],
+			exponents: vec![],
+			max_channel_id: 2,
+		};
+
+		let merged = first.clone().merge(second.clone()).unwrap();
+		assert_eq!(merged.oracles.size(), 3);
+		assert_eq!(merged.table_constraints.len(), 2);
+		assert_eq!(merged.table_constraints[1].oracle_ids, vec![b + 1, c + 1]);
+		assert_eq!(merged.non_zero_oracle_ids, vec![c + 1]);
+		assert_eq!(
+			merged
+				.flushes
+				.iter()
+				.map(|flush| flush.channel_id)
+				.collect::<Vec<_>>(),
+			vec![1, 2, 4]
+		);
+		assert!(
+			matches!(merged.flushes[2].oracles[..], [OracleOrConst::Oracle(id)] if id == c + 1)
+		);
+		assert_eq!(merged.max_channel_id, 4);
+
+		let mut dangling = second;
+		dangling.non_zero_oracle_ids.push(5);
+		assert!(first.merge(dangling).is_err());
+	}
+
+	#[test]
+	fn test_check_consistency() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let a = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		let b = oracles.add_committed(4, 2);
+		let mut builder = ConstraintSetBuilder::new();
+		builder.add_zerocheck("a", [a], ArithExpr::Var(0) * ArithExpr::Var(0));
+		let mut constraint_system = ConstraintSystem {
+			oracles: oracles.clone(),
+			table_constraints: vec![builder.build_one(&oracles).unwrap()],
+			non_zero_oracle_ids: vec![a],
+			flushes: vec![Flush {
+				oracles: vec![OracleOrConst::Oracle(a)],
+				channel_id: 0,
+				direction: FlushDirection::Push,
+				selector: None,
+				multiplicity: 1,
+			}],
+			exponents: vec![],
+			max_channel_id: 0,
+		};
+
+		// Only the B4 oracle is inconsistent.
+		let errors = constraint_system.check_consistency().unwrap_err();
+		assert!(matches!(errors[..], [Error::CannotCommitTowerLevel { tower_level: 2 }]));
+
+		constraint_system.non_zero_oracle_ids.push(b + 1);
+		constraint_system.flushes[0]
+			.oracles
+			.push(OracleOrConst::Oracle(b + 2));
+		constraint_system.flushes[0].channel_id = 3;
+		let errors = constraint_system.check_consistency().unwrap_err();
+		assert_eq!(errors.len(), 4);
+		assert!(errors.iter().any(|error| matches!(
+			error,
+			Error::Oracle(oracle::Error::InvalidOracleId(id)) if *id == b + 1
+		)));
+		assert!(errors.iter().any(|error| matches!(
+			error,
+			Error::Oracle(oracle::Error::InvalidOracleId(id)) if *id == b + 2
+		)));
+		assert!(errors
+			.iter()
+			.any(|error| matches!(error, Error::ChannelIdOutOfRange { max: 0, got: 3 })));
+	}
+
+	#[cfg(feature = "proof_text")]
+	#[test]
+	fn test_proof_text_roundtrip() {
+		let proof = Proof {
+			transcript: (0..=255u8).rev().chain([0, 1, 2]).collect(),
+		};
+
+		assert_eq!(Proof::from_hex(&proof.to_hex()).unwrap(), proof);
+		assert_eq!(Proof::from_base64(&proof.to_base64()).unwrap(), proof);
+		let short = Proof {
+			transcript: vec![0xab, 0x01],
+		};
+		assert_eq!(short.to_hex(), "42494e530100ab01");
+		assert_eq!(Proof::from_hex("42494E530100AB01\n").unwrap(), short);
+		assert_eq!(Proof::from_hex("42494e530100").unwrap(), Proof { transcript: vec![] });
+	}
+
+	#[cfg(feature = "proof_text")]
+	#[test]
+	fn test_proof_text_malformed() {
+		assert!(matches!(Proof::from_hex("abc"), Err(Error::ProofHexDecode(_))));
+		assert!(matches!(Proof::from_hex("zz"), Err(Error::ProofHexDecode(_))));
+		assert!(matches!(Proof::from_base64("a*b="), Err(Error::ProofBase64Decode(_))));
+		// Well-formed text without the format header.
+		assert!(matches!(Proof::from_hex("ab01"), Err(Error::Io(_))));
+	}
+
+	#[test]
+	fn test_versioned_write_read() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let a = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		let removed = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		let b = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		oracles.remove_oracle(removed).unwrap();
+		let mut builder = ConstraintSetBuilder::new();
+		builder.add_zerocheck("a_b", [a, b], ArithExpr::Var(0) * ArithExpr::Var(1));
+		let constraint_system = ConstraintSystem {
+			oracles: oracles.clone(),
+			table_constraints: vec![builder.build_one(&oracles).unwrap()],
+			non_zero_oracle_ids: vec![b],
+			flushes: vec![],
+			exponents: vec![],
+			max_channel_id: 0,
+		};
+
+		let mut bytes = Vec::new();
+		constraint_system.write(&mut bytes).unwrap();
+		assert_eq!(bytes[..6], [0x42, 0x49, 0x4e, 0x53, 2, 0]);
+		let read = ConstraintSystem::read(bytes.as_slice()).unwrap();
+		assert!(constraint_system.diff(&read).is_empty());
+		assert_eq!(read.non_zero_oracle_ids, vec![b]);
+		assert!(read.oracles.is_oracle_removed(removed));
+		assert_eq!(read.oracles.ids().collect::<Vec<_>>(), vec![a, b]);
+
+		// Version 1 data, which stored the oracles of a set as a plain list, is rejected.
+		let mut old_bytes = bytes.clone();
+		old_bytes[4] = 1;
+		assert!(ConstraintSystem::<F>::read(old_bytes.as_slice()).is_err());
+
+		let proof = Proof {
+			transcript: vec![1, 2, 3],
+		};
+		let mut bytes = Vec::new();
+		proof.write(&mut bytes).unwrap();
+		assert_eq!(Proof::read(bytes.as_slice()).unwrap(), proof);
+		assert_eq!(proof.to_bytes(), bytes);
+		assert_eq!(Proof::from_bytes(&bytes).unwrap(), proof);
+		assert_eq!(Vec::from(proof.clone()), bytes);
+		assert_eq!(Proof::try_from(bytes.clone()).unwrap(), proof);
+		assert!(Proof::try_from(proof.transcript.clone()).is_err());
+
+		assert_eq!(bytes[..6], [0x42, 0x49, 0x4e, 0x53, 1, 0]);
+
+		// Data written by a future major version is rejected.
+		bytes[4] = 3;
+		let err = Proof::read(bytes.as_slice()).unwrap_err();
+		assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+		assert!(err.to_string().contains("unsupported format version 3.0"));
+	}
 }
@@ -1,6 +1,13 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{cmp::Reverse, env, marker::PhantomData, slice::from_mut};
+use std::{
+	cmp::Reverse,
+	env,
+	io::Write,
+	marker::PhantomData,
+	slice::from_mut,
+	time::{Duration, Instant},
+};
 
 use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
@@ -28,18 +35,18 @@ use super::{
 	verify::{
 		get_post_flush_sumcheck_eval_claims_without_eq, make_flush_oracles,
 		max_n_vars_and_skip_rounds, reduce_unmasked_flush_eval_claims,
-		reorder_for_flushing_by_n_vars,
+		reorder_for_flushing_by_n_vars, CommitParams,
 	},
 	ConstraintSystem, Proof,
 };
 use crate::{
 	constraint_system::{
-		common::{FDomain, FEncode, FExt, FFastExt},
+		common::{FDomain, FExt, FFastExt},
 		exp,
 		verify::{make_flush_sumcheck_metas, FlushSumcheckMeta},
 	},
 	fiat_shamir::{CanSample, Challenger},
-	merkle_tree::BinaryMerkleTreeProver,
+	merkle_tree::{BinaryMerkleTreeProver, BinaryMerkleTreeScheme},
 	oracle::{Constraint, MultilinearOracleSet, MultilinearPolyVariant, OracleId},
 	piop,
 	protocols::{
@@ -61,34 +68,281 @@ use crate::{
 	witness::{MultilinearExtensionIndex, MultilinearWitness},
 };
 
+/// An underlier whose packed tower fields support all the operations of the prover.
+///
+/// This groups the bounds shared by [`prove`] and the other proving entry points, and is
+/// implemented for every underlier that satisfies them.
+// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+pub trait ProverUnderlier<Tower: ProverTowerFamily>:
+	ProverTowerUnderlier<Tower>
+	+ PackScalar<
+		Tower::B128,
+		Packed: PackedTop<Tower>
+			+ PackedFieldIndexable // REVIEW: remove this bound after piop::commit is adjusted
+			+ RepackedExtension<PackedType<Self, Tower::B8>>
+			+ RepackedExtension<PackedType<Self, Tower::B16>>
+			+ RepackedExtension<PackedType<Self, Tower::B32>>
+			+ RepackedExtension<PackedType<Self, Tower::B64>>
+			+ RepackedExtension<PackedType<Self, Tower::B128>>
+			+ PackedTransformationFactory<PackedType<Self, Tower::FastB128>>,
+	> + PackScalar<
+		Tower::FastB128,
+		Packed: PackedTransformationFactory<PackedType<Self, Tower::B128>>,
+	>
+{
+}
+
+impl<U, Tower> ProverUnderlier<Tower> for U
+where
+	Tower: ProverTowerFamily,
+	U: ProverTowerUnderlier<Tower>,
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+}
+
 /// Generates a proof that a witness satisfies a constraint system with the standard FRI PCS.
-#[instrument("constraint_system::prove", skip_all, level = "debug")]
 pub fn prove<U, Tower, Hash, Compress, Challenger_, Backend>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
 	log_inv_rate: usize,
 	security_bits: usize,
 	boundaries: &[Boundary<FExt<Tower>>],
-	mut witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
 	backend: &Backend,
 ) -> Result<Proof, Error>
 where
-	U: ProverTowerUnderlier<Tower>,
+	U: ProverUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+{
+	let (proof, _stats) = prove_with_stats::<U, Tower, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		boundaries,
+		witness,
+		backend,
+	)?;
+	Ok(proof)
+}
+
+/// Timing breakdown of a [`prove_with_stats`] call.
+///
+/// The phases are measured separately, so their sum is slightly less than `prove_ms`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ProveStats {
+	/// Total proving time.
+	pub prove_ms: u64,
+	/// Time spent generating exponentiation witnesses and collecting the committed multilinears.
+	pub witness_loading_ms: u64,
+	/// Time spent in the GKR exponentiation and grand product arguments.
+	pub gkr_ms: u64,
+	/// Time spent in the flush sumchecks, the zerocheck and the evalcheck.
+	pub sumcheck_ms: u64,
+	/// Time spent committing, ring switching and proving the PIOP evaluation claims.
+	pub pcs_ms: u64,
+	/// Size of the proof in bytes.
+	pub proof_bytes: usize,
+}
+
+/// Same as [`prove`], but also returns the time spent in each phase of the protocol.
+pub fn prove_with_stats<U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+) -> Result<(Proof, ProveStats), Error>
+where
+	U: ProverUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+{
+	let commit_params = CommitParams::<Tower, BinaryMerkleTreeScheme<_, Hash, Compress>>::new(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+	)?;
+	prove_with_commit_params::<U, Tower, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		&commit_params,
+		boundaries,
+		witness,
+		backend,
+	)
+}
+
+/// Generates proofs that several witnesses satisfy the same constraint system.
+///
+/// The polynomial commitment parameters only depend on the constraint system, so they are
+/// derived once and shared by all proofs. The proofs are identical to the ones returned by
+/// calling [`prove`] on each witness, and verify with [`super::verify_batch`]. Fails on the first
+/// witness that cannot be proven.
+#[instrument("constraint_system::prove_batch", skip_all, level = "debug")]
+pub fn prove_batch<'a, 'b, U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	witnesses: impl IntoIterator<
+		Item = (
+			&'a [Boundary<FExt<Tower>>],
+			MultilinearExtensionIndex<'b, PackedType<U, FExt<Tower>>>,
+		),
+	>,
+	backend: &Backend,
+) -> Result<Vec<Proof>, Error>
+where
+	U: ProverUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+{
+	let commit_params = CommitParams::<Tower, BinaryMerkleTreeScheme<_, Hash, Compress>>::new(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+	)?;
+
+	witnesses
+		.into_iter()
+		.map(|(boundaries, witness)| {
+			let (proof, _stats) =
+				prove_with_commit_params::<U, Tower, Hash, Compress, Challenger_, Backend>(
+					constraint_system,
+					&commit_params,
+					boundaries,
+					witness,
+					backend,
+				)?;
+			Ok(proof)
+		})
+		.collect()
+}
+
+fn prove_with_commit_params<U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	commit_params: &CommitParams<Tower, BinaryMerkleTreeScheme<FExt<Tower>, Hash, Compress>>,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+) -> Result<(Proof, ProveStats), Error>
+where
+	U: ProverUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+{
+	let mut transcript = ProverTranscript::<Challenger_>::new();
+	let mut stats = prove_to_transcript::<U, Tower, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		commit_params,
+		boundaries,
+		witness,
+		backend,
+		&mut transcript,
+		|_| Ok(()),
+	)?;
+	let proof = Proof {
+		transcript: transcript.finalize(),
+	};
+	stats.proof_bytes = proof.get_proof_size();
+	Ok((proof, stats))
+}
+
+/// Same as [`prove`], but writes the proof to `writer` as it is generated.
+///
+/// The proof bytes are written after every step of the protocol, so at most the messages of one
+/// step are buffered in memory, instead of the whole proof. The bytes written are identical to
+/// [`Proof::transcript`] of the proof returned by [`prove`]. Returns the number of bytes written.
+#[allow(clippy::too_many_arguments)]
+pub fn streaming_prove<U, Tower, Hash, Compress, Challenger_, Backend, W>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	mut writer: W,
+	backend: &Backend,
+) -> Result<usize, Error>
+where
+	U: ProverUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	W: Write,
+{
+	let commit_params = CommitParams::<Tower, BinaryMerkleTreeScheme<_, Hash, Compress>>::new(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+	)?;
+	let mut transcript = ProverTranscript::<Challenger_>::new();
+	let mut bytes_written = 0;
+	prove_to_transcript::<U, Tower, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		&commit_params,
+		boundaries,
+		witness,
+		backend,
+		&mut transcript,
+		|transcript| {
+			bytes_written += transcript.write_pending(&mut writer)?;
+			Ok(())
+		},
+	)?;
+	bytes_written += transcript.write_pending(&mut writer)?;
+	writer.flush()?;
+	Ok(bytes_written)
+}
+
+/// Runs the prover, writing the proof to `transcript`.
+///
+/// `end_step` is called with the transcript after every step of the protocol. The returned stats
+/// have `proof_bytes` unset.
+#[allow(clippy::too_many_arguments)]
+#[instrument("constraint_system::prove", skip_all, level = "debug")]
+fn prove_to_transcript<U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	commit_params: &CommitParams<Tower, BinaryMerkleTreeScheme<FExt<Tower>, Hash, Compress>>,
+	boundaries: &[Boundary<FExt<Tower>>],
+	mut witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+	transcript: &mut ProverTranscript<Challenger_>,
+	mut end_step: impl FnMut(&mut ProverTranscript<Challenger_>) -> Result<(), Error>,
+) -> Result<ProveStats, Error>
+where
+	U: ProverUnderlier<Tower>,
 	Tower: ProverTowerFamily,
 	Tower::B128: PackedTop<Tower>,
 	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
 	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
 	Challenger_: Challenger + Default,
 	Backend: ComputationBackend,
-	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
-	PackedType<U, Tower::B128>: PackedTop<Tower>
-		+ PackedFieldIndexable // REVIEW: remove this bound after piop::commit is adjusted
-		+ RepackedExtension<PackedType<U, Tower::B8>>
-		+ RepackedExtension<PackedType<U, Tower::B16>>
-		+ RepackedExtension<PackedType<U, Tower::B32>>
-		+ RepackedExtension<PackedType<U, Tower::B64>>
-		+ RepackedExtension<PackedType<U, Tower::B128>>
-		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
-	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
 {
 	tracing::debug!(
 		arch = env::consts::ARCH,
@@ -96,10 +350,13 @@ where
 		"using computation backend: {backend:?}"
 	);
 
+	let start = Instant::now();
+	let mut witness_loading = Duration::ZERO;
+	let mut pcs = Duration::ZERO;
+
 	let domain_factory = DefaultEvaluationDomainFactory::<FDomain<Tower>>::default();
 	let fast_domain_factory = IsomorphicEvaluationDomainFactory::<FFastExt<Tower>>::default();
 
-	let mut transcript = ProverTranscript::<Challenger_>::new();
 	transcript.observe().write_slice(boundaries);
 
 	let ConstraintSystem {
@@ -110,45 +367,52 @@ where
 		non_zero_oracle_ids,
 		max_channel_id,
 	} = constraint_system.clone();
+	let CommitParams {
+		commit_meta,
+		oracle_to_commit_index,
+		fri_params,
+		..
+	} = commit_params;
 
 	exponents.sort_by_key(|b| std::cmp::Reverse(b.n_vars(&oracles)));
 
 	// We must generate multiplication witnesses before committing, as this function
 	// adds the committed witnesses for exponentiation results to the witness index.
+	let phase_start = Instant::now();
 	let exp_witnesses = exp::make_exp_witnesses::<U, Tower>(&mut witness, &oracles, &exponents)?;
+	witness_loading += phase_start.elapsed();
 
 	// Stable sort constraint sets in descending order by number of variables.
 	table_constraints.sort_by_key(|constraint_set| Reverse(constraint_set.n_vars));
 
 	// Commit polynomials
+	let phase_start = Instant::now();
 	let merkle_prover = BinaryMerkleTreeProver::<_, Hash, _>::new(Compress::default());
-	let merkle_scheme = merkle_prover.scheme();
 
-	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
+	let collect_start = Instant::now();
 	let committed_multilins = piop::collect_committed_witnesses::<U, _>(
-		&commit_meta,
-		&oracle_to_commit_index,
+		commit_meta,
+		oracle_to_commit_index,
 		&oracles,
 		&witness,
 	)?;
+	let collect_time = collect_start.elapsed();
+	witness_loading += collect_time;
 
-	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
-		&commit_meta,
-		merkle_scheme,
-		security_bits,
-		log_inv_rate,
-	)?;
 	let CommitOutput {
 		commitment,
 		committed,
 		codeword,
-	} = piop::commit(&fri_params, &merkle_prover, &committed_multilins)?;
+	} = piop::commit(fri_params, &merkle_prover, &committed_multilins)?;
 
 	// Observe polynomial commitment
 	let mut writer = transcript.message();
 	writer.write(&commitment);
+	pcs += phase_start.elapsed() - collect_time;
+	end_step(transcript)?;
 
 	// GKR exp
+	let phase_start = Instant::now();
 	let exp_challenge = transcript.sample_vec(exp::max_n_vars(&exponents, &oracles));
 
 	let exp_evals = gkr_exp::get_evals_in_point_from_witnesses(&exp_witnesses, &exp_challenge)?
@@ -174,12 +438,13 @@ where
 		exp_witnesses,
 		&exp_claims,
 		fast_domain_factory.clone(),
-		&mut transcript,
+		transcript,
 		backend,
 	)?
 	.isomorphic();
 
 	let exp_eval_claims = exp::make_eval_claims(&exponents, base_exp_output)?;
+	end_step(transcript)?;
 
 	// Grand product arguments
 	// Grand products for non-zero checking
@@ -258,7 +523,7 @@ where
 			all_gpa_witnesses,
 			&all_gpa_claims,
 			&fast_domain_factory,
-			&mut transcript,
+			transcript,
 			backend,
 		)?;
 
@@ -270,6 +535,9 @@ where
 
 	let non_zero_final_layer_claims = final_layer_claims.split_off(flush_oracle_ids.len());
 	let flush_final_layer_claims = final_layer_claims;
+	let gkr = phase_start.elapsed();
+	end_step(transcript)?;
+	let phase_start = Instant::now();
 
 	// Reduce non_zero_final_layer_claims to evalcheck claims
 	let non_zero_prodcheck_eval_claims =
@@ -304,7 +572,7 @@ where
 		backend,
 	)?;
 
-	let flush_sumcheck_output = sumcheck::prove::batch_prove(provers, &mut transcript)?;
+	let flush_sumcheck_output = sumcheck::prove::batch_prove(provers, transcript)?;
 
 	let flush_eval_claims = get_post_flush_sumcheck_eval_claims_without_eq(
 		&oracles,
@@ -312,6 +580,7 @@ where
 		&flush_oracle_ids_by_claim,
 		&flush_sumcheck_output,
 	)?;
+	end_step(transcript)?;
 
 	// Zerocheck
 	let (zerocheck_claims, zerocheck_oracle_metas) = table_constraints
@@ -388,7 +657,7 @@ where
 	let univariate_output = sumcheck::prove::batch_prove_zerocheck_univariate_round(
 		univariate_provers,
 		skip_rounds,
-		&mut transcript,
+		transcript,
 	)?;
 
 	let univariate_challenge = univariate_output.univariate_challenge;
@@ -396,7 +665,7 @@ where
 	let sumcheck_output = sumcheck::prove::batch_prove_with_start(
 		univariate_output.batch_prove_start,
 		tail_regular_zerocheck_provers,
-		&mut transcript,
+		transcript,
 	)?;
 
 	let zerocheck_output = sumcheck::eq_ind::verify_sumcheck_outputs(
@@ -438,7 +707,7 @@ where
 		reduction_provers.push(reduction_prover);
 	}
 
-	let univariatizing_output = sumcheck::prove::batch_prove(reduction_provers, &mut transcript)?;
+	let univariatizing_output = sumcheck::prove::batch_prove(reduction_provers, transcript)?;
 
 	let multilinear_zerocheck_output = sumcheck::univariate::verify_sumcheck_outputs(
 		&reduction_claims,
@@ -452,6 +721,7 @@ where
 		zerocheck_oracle_metas,
 		multilinear_zerocheck_output,
 	)?;
+	end_step(transcript)?;
 
 	// Prove evaluation claims
 	let GreedyEvalcheckProveOutput {
@@ -468,16 +738,20 @@ where
 			exp_eval_claims,
 		),
 		switchover_fn,
-		&mut transcript,
+		transcript,
 		&domain_factory,
 		backend,
 	)?;
 
+	let sumcheck = phase_start.elapsed();
+	end_step(transcript)?;
+
 	// Reduce committed evaluation claims to PIOP sumcheck claims
+	let phase_start = Instant::now();
 	let system = ring_switch::EvalClaimSystem::new(
 		&oracles,
-		&commit_meta,
-		&oracle_to_commit_index,
+		commit_meta,
+		oracle_to_commit_index,
 		&eval_claims,
 	)?;
 
@@ -487,28 +761,35 @@ where
 	} = ring_switch::prove::<_, _, _, Tower, _, _>(
 		&system,
 		&committed_multilins,
-		&mut transcript,
+		transcript,
 		memoized_data,
 		backend,
 	)?;
+	end_step(transcript)?;
 
 	// Prove evaluation claims using PIOP compiler
 	piop::prove::<_, FDomain<Tower>, _, _, _, _, _, _, _, _>(
-		&fri_params,
+		fri_params,
 		&merkle_prover,
 		domain_factory,
-		&commit_meta,
+		commit_meta,
 		committed,
 		&codeword,
 		&committed_multilins,
 		&transparent_multilins,
 		&piop_sumcheck_claims,
-		&mut transcript,
+		transcript,
 		&backend,
 	)?;
-
-	Ok(Proof {
-		transcript: transcript.finalize(),
+	pcs += phase_start.elapsed();
+
+	Ok(ProveStats {
+		prove_ms: start.elapsed().as_millis() as u64,
+		witness_loading_ms: witness_loading.as_millis() as u64,
+		gkr_ms: gkr.as_millis() as u64,
+		sumcheck_ms: sumcheck.as_millis() as u64,
+		pcs_ms: pcs.as_millis() as u64,
+		proof_bytes: 0,
 	})
 }
 
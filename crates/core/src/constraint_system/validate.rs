@@ -15,13 +15,15 @@ use crate::{
 		ConstraintPredicate, MultilinearOracleSet, MultilinearPolyOracle, MultilinearPolyVariant,
 		ShiftVariant,
 	},
-	polynomial::{
-		test_utils::decompose_index_to_hypercube_point, ArithCircuitPoly, MultilinearComposite,
-	},
+	polynomial::{decompose_index_to_hypercube_point, ArithCircuitPoly, MultilinearComposite},
 	protocols::sumcheck::prove::zerocheck,
 	witness::MultilinearExtensionIndex,
 };
 
+/// Checks that a witness satisfies a constraint system, without generating a proof.
+///
+/// The checks cover the zero constraints, the non-zero oracles, the channel balance with the
+/// given boundaries, and the consistency of the virtual oracle witnesses.
 pub fn validate_witness<F, P>(
 	constraint_system: &ConstraintSystem<F>,
 	boundaries: &[Boundary<F>],
@@ -63,12 +65,7 @@ where
 	)?;
 
 	// Check that the channels balance with flushes and boundaries
-	channel::validate_witness(
-		witness,
-		&constraint_system.flushes,
-		boundaries,
-		constraint_system.max_channel_id,
-	)?;
+	verify_channel_balance(constraint_system, boundaries, witness)?;
 
 	// Check consistency of virtual oracle witnesses (eg. that shift polynomials are actually shifts).
 	for oracle in constraint_system.oracles.iter() {
@@ -78,6 +75,27 @@ where
 	Ok(())
 }
 
+/// Checks only that the channels balance with the flushes and boundaries.
+///
+/// This is much cheaper than [`validate_witness`] and is useful to debug the channel structure of
+/// a circuit. The flushed values are read from the witness, since no proof is involved.
+pub fn verify_channel_balance<F, P>(
+	constraint_system: &ConstraintSystem<F>,
+	boundaries: &[Boundary<F>],
+	witness: &MultilinearExtensionIndex<'_, P>,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = F>,
+	F: TowerField,
+{
+	channel::validate_witness(
+		witness,
+		&constraint_system.flushes,
+		boundaries,
+		constraint_system.max_channel_id,
+	)
+}
+
 pub fn validate_virtual_oracle_witness<F, P>(
 	oracle: MultilinearPolyOracle<F>,
 	oracles: &MultilinearOracleSet<F>,
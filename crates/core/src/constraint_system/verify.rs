@@ -5,7 +5,7 @@ use std::{cmp::Reverse, iter};
 use binius_field::{BinaryField, PackedField, TowerField};
 use binius_hash::PseudoCompressionFunction;
 use binius_math::{ArithExpr, CompositionPoly, EvaluationOrder};
-use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
+use binius_utils::{bail, checked_arithmetics::log2_ceil_usize, sparse_index::SparseIndex};
 use digest::{core_api::BlockSizeUser, Digest, Output};
 use itertools::{chain, izip, multiunzip, Itertools};
 use tracing::instrument;
@@ -24,10 +24,11 @@ use crate::{
 	fiat_shamir::{CanSample, Challenger},
 	merkle_tree::BinaryMerkleTreeScheme,
 	oracle::{MultilinearOracleSet, OracleId},
-	piop,
+	piop::{self, CommitMeta},
 	polynomial::MultivariatePoly,
 	protocols::{
 		evalcheck::EvalcheckMultilinearClaim,
+		fri::FRIParams,
 		gkr_exp,
 		gkr_gpa::{self, LayerClaim},
 		greedy_evalcheck,
@@ -58,6 +59,250 @@ where
 	Hash: Digest + BlockSizeUser,
 	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
 	Challenger_: Challenger + Default,
+{
+	verify_batch::<U, Tower, Hash, Compress, Challenger_>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		[(boundaries, proof)],
+	)
+}
+
+/// Verifies several proofs against the same constraint system.
+///
+/// The polynomial commitment parameters only depend on the constraint system, so they are
+/// derived once and shared by all proofs. Fails on the first proof that does not verify.
+#[instrument("constraint_system::verify_batch", skip_all, level = "debug")]
+pub fn verify_batch<'a, U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	proofs: impl IntoIterator<Item = (&'a [Boundary<FExt<Tower>>], Proof)>,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+{
+	let commit_params = CommitParams::<Tower, BinaryMerkleTreeScheme<_, Hash, Compress>>::new(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+	)?;
+
+	for (boundaries, proof) in proofs {
+		verify_with_commit_params::<Tower, Hash, Compress, Challenger_>(
+			constraint_system,
+			&commit_params,
+			boundaries,
+			proof,
+			&mut VerifyDiagnostics::default(),
+			&mut |_, _| {},
+		)?;
+	}
+	Ok(())
+}
+
+/// The outcome of each step of the verification of a proof.
+///
+/// The steps run in order, and the steps after a failing one are not run, so the first step with
+/// a `false` flag is the one that failed. The claim counts are only set for the steps that passed.
+#[derive(Debug, Default)]
+pub struct VerifyDiagnostics {
+	/// Whether the GKR exponentiation and grand product arguments verified.
+	pub gkr_ok: bool,
+	pub gkr_claims: usize,
+	/// Whether the channels balance and the flush sumchecks verified.
+	pub flush_ok: bool,
+	pub flush_claims: usize,
+	/// Whether the zerocheck on the table constraints verified.
+	pub zerocheck_ok: bool,
+	pub zerocheck_claims: usize,
+	/// Whether the evalcheck reduction verified.
+	pub evalcheck_ok: bool,
+	pub evalcheck_claims: usize,
+	/// Whether the ring switching reduction and the opening of the commitment verified.
+	pub pcs_ok: bool,
+	pub pcs_claims: usize,
+	/// The error of the failing step, if any.
+	pub failure: Option<Error>,
+}
+
+impl VerifyDiagnostics {
+	/// Whether all the steps verified.
+	pub const fn is_ok(&self) -> bool {
+		self.failure.is_none()
+	}
+}
+
+/// A step of the verification of a proof, as reported by [`replay_transcript`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyStep {
+	/// The GKR exponentiation and grand product arguments.
+	Gkr,
+	/// The flush sumchecks.
+	Flush,
+	/// The zerocheck on the table constraints.
+	Zerocheck,
+	/// The evalcheck reduction.
+	Evalcheck,
+	/// The ring switching reduction and the opening of the commitment.
+	Pcs,
+}
+
+/// Verifies a proof against a constraint system, and calls `on_step` after every protocol step.
+///
+/// `on_step` receives the challenges sampled by the verifier during the step, in the order they
+/// were sampled from the transcript: the exponentiation point, the mixing challenge and the
+/// permutation challenges for [`VerifyStep::Gkr`], and the sumcheck evaluation points for
+/// [`VerifyStep::Flush`] and [`VerifyStep::Zerocheck`]. The challenges sampled inside the
+/// evalcheck and the commitment opening are not reported. This is meant for debugging a circuit,
+/// by replaying a proof transcript step by step; the steps after a failing one are not run.
+#[instrument("constraint_system::replay_transcript", skip_all, level = "debug")]
+pub fn replay_transcript<U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	proof: Proof,
+	mut on_step: impl FnMut(VerifyStep, &[FExt<Tower>]),
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+{
+	let commit_params = CommitParams::<Tower, BinaryMerkleTreeScheme<_, Hash, Compress>>::new(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+	)?;
+	verify_with_commit_params::<Tower, Hash, Compress, Challenger_>(
+		constraint_system,
+		&commit_params,
+		boundaries,
+		proof,
+		&mut VerifyDiagnostics::default(),
+		&mut on_step,
+	)
+}
+
+/// Verifies a proof against a constraint system, and reports the outcome of every protocol step.
+///
+/// This is meant for debugging a proof that fails [`verify`]: a protocol level failure is recorded
+/// in the returned [`VerifyDiagnostics`] instead of being returned as an error. Errors are only
+/// returned if the constraint system is invalid, or if a read made between the protocol steps
+/// fails: the commitment, the claimed evaluations and products that start a step, and the final
+/// check that the transcript is fully consumed. A transcript that ends inside the sumchecks or the
+/// opening of a step is reported as a failure of that step.
+#[instrument(
+	"constraint_system::verify_with_diagnostics",
+	skip_all,
+	level = "debug"
+)]
+pub fn verify_with_diagnostics<U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	proof: Proof,
+) -> Result<VerifyDiagnostics, Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+{
+	let commit_params = CommitParams::<Tower, BinaryMerkleTreeScheme<_, Hash, Compress>>::new(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+	)?;
+
+	let mut diagnostics = VerifyDiagnostics::default();
+	match verify_with_commit_params::<Tower, Hash, Compress, Challenger_>(
+		constraint_system,
+		&commit_params,
+		boundaries,
+		proof,
+		&mut diagnostics,
+		&mut |_, _| {},
+	) {
+		Ok(()) => Ok(diagnostics),
+		Err(
+			err @ (Error::TranscriptError(_)
+			| Error::TranscriptParseError { .. }
+			| Error::AdviceParseError { .. }),
+		) => Err(err),
+		Err(err) => {
+			diagnostics.failure = Some(err);
+			Ok(diagnostics)
+		}
+	}
+}
+
+/// The polynomial commitment parameters derived from a constraint system.
+///
+/// These only depend on the constraint system, so they are shared by the prover and the verifier
+/// of a batch of proofs.
+pub(super) struct CommitParams<Tower: TowerFamily, MerkleScheme> {
+	pub(super) merkle_scheme: MerkleScheme,
+	pub(super) commit_meta: CommitMeta,
+	pub(super) oracle_to_commit_index: SparseIndex<usize>,
+	pub(super) fri_params: FRIParams<FExt<Tower>, FEncode<Tower>>,
+}
+
+impl<Tower, Hash, Compress> CommitParams<Tower, BinaryMerkleTreeScheme<FExt<Tower>, Hash, Compress>>
+where
+	Tower: TowerFamily,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+{
+	pub(super) fn new(
+		constraint_system: &ConstraintSystem<FExt<Tower>>,
+		log_inv_rate: usize,
+		security_bits: usize,
+	) -> Result<Self, Error> {
+		let merkle_scheme = BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default());
+		let (commit_meta, oracle_to_commit_index) =
+			piop::make_oracle_commit_meta(&constraint_system.oracles)?;
+		let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
+			&commit_meta,
+			&merkle_scheme,
+			security_bits,
+			log_inv_rate,
+		)?;
+		Ok(Self {
+			merkle_scheme,
+			commit_meta,
+			oracle_to_commit_index,
+			fri_params,
+		})
+	}
+}
+
+fn verify_with_commit_params<Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	commit_params: &CommitParams<Tower, BinaryMerkleTreeScheme<FExt<Tower>, Hash, Compress>>,
+	boundaries: &[Boundary<FExt<Tower>>],
+	proof: Proof,
+	diagnostics: &mut VerifyDiagnostics,
+	on_step: &mut dyn FnMut(VerifyStep, &[FExt<Tower>]),
+) -> Result<(), Error>
+where
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
 {
 	let ConstraintSystem {
 		mut oracles,
@@ -68,6 +313,12 @@ where
 		mut exponents,
 		..
 	} = constraint_system.clone();
+	let CommitParams {
+		merkle_scheme,
+		commit_meta,
+		oracle_to_commit_index,
+		fri_params,
+	} = commit_params;
 
 	// Stable sort constraint sets in descending order by number of variables.
 	table_constraints.sort_by_key(|constraint_set| Reverse(constraint_set.n_vars));
@@ -77,15 +328,6 @@ where
 	let mut transcript = VerifierTranscript::<Challenger_>::new(transcript);
 	transcript.observe().write_slice(boundaries);
 
-	let merkle_scheme = BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default());
-	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
-	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
-		&commit_meta,
-		&merkle_scheme,
-		security_bits,
-		log_inv_rate,
-	)?;
-
 	// Read polynomial commitment polynomials
 	let mut reader = transcript.message();
 	let commitment = reader.read::<Output<Hash>>()?;
@@ -140,13 +382,6 @@ where
 	let flush_products = transcript
 		.message()
 		.read_scalar_slice(flush_oracle_ids.len())?;
-	verify_channels_balance(
-		&flushes,
-		&flush_products,
-		boundaries,
-		mixing_challenge,
-		&permutation_challenges,
-	)?;
 
 	let flush_prodcheck_claims =
 		gkr_gpa::construct_grand_product_claims(&flush_oracle_ids, &oracles, &flush_products)?;
@@ -158,6 +393,27 @@ where
 		&mut transcript,
 	)?;
 
+	diagnostics.gkr_ok = true;
+	diagnostics.gkr_claims = exp_claims.len() + final_layer_claims.len();
+	on_step(
+		VerifyStep::Gkr,
+		&[
+			exp_challenge.as_slice(),
+			&[mixing_challenge],
+			&permutation_challenges,
+		]
+		.concat(),
+	);
+
+	// Checked after the grand products, so that an unbalanced channel fails the flush step.
+	verify_channels_balance(
+		&flushes,
+		&flush_products,
+		boundaries,
+		mixing_challenge,
+		&permutation_challenges,
+	)?;
+
 	let non_zero_final_layer_claims = final_layer_claims.split_off(flush_oracle_ids.len());
 	let flush_final_layer_claims = final_layer_claims;
 
@@ -226,6 +482,9 @@ where
 			return Err(Error::FalseEqEvaluationClaim);
 		}
 	}
+	diagnostics.flush_ok = true;
+	diagnostics.flush_claims = eq_ind_sumcheck_claims.len();
+	on_step(VerifyStep::Flush, &flush_sumcheck_output.challenges);
 
 	// Zerocheck
 	let (zerocheck_claims, zerocheck_oracle_metas) = table_constraints
@@ -301,6 +560,9 @@ where
 		zerocheck_oracle_metas,
 		multilinear_zerocheck_output,
 	)?;
+	diagnostics.zerocheck_ok = true;
+	diagnostics.zerocheck_claims = zerocheck_claims.len();
+	on_step(VerifyStep::Zerocheck, &zerocheck_output.challenges);
 
 	// Evalcheck
 	let eval_claims = greedy_evalcheck::verify(
@@ -314,12 +576,15 @@ where
 		),
 		&mut transcript,
 	)?;
+	diagnostics.evalcheck_ok = true;
+	diagnostics.evalcheck_claims = eval_claims.len();
+	on_step(VerifyStep::Evalcheck, &[]);
 
 	// Reduce committed evaluation claims to PIOP sumcheck claims
 	let system = ring_switch::EvalClaimSystem::new(
 		&oracles,
-		&commit_meta,
-		&oracle_to_commit_index,
+		commit_meta,
+		oracle_to_commit_index,
 		&eval_claims,
 	)?;
 
@@ -330,14 +595,17 @@ where
 
 	// Prove evaluation claims using PIOP compiler
 	piop::verify(
-		&commit_meta,
-		&merkle_scheme,
-		&fri_params,
+		commit_meta,
+		merkle_scheme,
+		fri_params,
 		&commitment,
 		&transparents,
 		&piop_sumcheck_claims,
 		&mut transcript,
 	)?;
+	diagnostics.pcs_ok = true;
+	diagnostics.pcs_claims = piop_sumcheck_claims.len();
+	on_step(VerifyStep::Pcs, &[]);
 
 	transcript.finalize()?;
 
@@ -11,25 +11,27 @@ use digest::{
 use super::Challenger;
 
 /// Challenger type which implements `[Buf]` that has similar functionality as `[CanSample]`
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct HasherSampler<H: Digest> {
 	index: usize,
 	buffer: Output<H>,
 	hasher: H,
+	n_forks: u64,
 }
 
 /// Challenger type which implements `[BufMut]` that has similar functionality as `[CanObserve]`
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct HasherObserver<H: Digest + BlockSizeUser> {
 	index: usize,
 	buffer: Block<H>,
 	hasher: H,
+	n_forks: u64,
 }
 
 /// Challenger interface over hashes that implement `[Digest]` trait,
 ///
 /// This challenger works over bytes instead of Field elements
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum HasherChallenger<H: Digest + BlockSizeUser> {
 	Observer(HasherObserver<H>),
 	Sampler(HasherSampler<H>),
@@ -47,8 +49,17 @@ where
 			hasher,
 			index: 0,
 			buffer: initial_digest,
+			n_forks: 0,
 		})
 	}
+
+	/// The number of forks taken from this challenger.
+	const fn n_forks_mut(&mut self) -> &mut u64 {
+		match self {
+			Self::Observer(observer) => &mut observer.n_forks,
+			Self::Sampler(sampler) => &mut sampler.n_forks,
+		}
+	}
 }
 
 impl<H> Default for HasherChallenger<H>
@@ -60,6 +71,43 @@ where
 	}
 }
 
+/// Domain separator observed by forked challengers.
+const FORK_DOMAIN_SEPARATOR: &[u8] = b"binius fork";
+
+impl<H> HasherChallenger<H>
+where
+	H: Digest + BlockSizeUser + FixedOutputReset + Default + Clone,
+{
+	/// Returns a copy of the challenger that continues from the current state.
+	///
+	/// The fork observes a domain separator followed by the number of forks taken from `self`
+	/// before it, so its challenges differ from the ones of `self` and of its sibling forks.
+	/// Forking only advances the fork counter of `self`; the transcript state is unchanged.
+	pub fn fork(&mut self) -> Self {
+		let fork_index = mem::replace(self.n_forks_mut(), 0);
+		let mut fork = self.clone();
+		*self.n_forks_mut() = fork_index + 1;
+		let observer = fork.observer();
+		observer.put_slice(FORK_DOMAIN_SEPARATOR);
+		observer.put_u64_le(fork_index);
+		fork
+	}
+
+	/// Combines two challengers into one whose challenges depend on the states of both.
+	///
+	/// Both states are summarized by sampling a digest from each, and the merged challenger is
+	/// seeded with the hash of the two digests. The order of `left` and `right` matters.
+	pub fn merge(mut left: Self, mut right: Self) -> Self {
+		let mut hasher = H::new();
+		for challenger in [&mut left, &mut right] {
+			let mut digest = Output::<H>::default();
+			challenger.sampler().copy_to_slice(&mut digest);
+			Digest::update(&mut hasher, &digest);
+		}
+		Self::new(hasher.finalize())
+	}
+}
+
 impl<H: Digest + BlockSizeUser + FixedOutputReset + Default> Challenger for HasherChallenger<H> {
 	/// This returns the inner challenger which implements `[BufMut]`
 	fn observer(&mut self) -> &mut impl BufMut {
@@ -101,6 +149,7 @@ where
 			hasher: self.hasher,
 			index: 0,
 			buffer: Block::<H>::default(),
+			n_forks: self.n_forks,
 		}
 	}
 }
@@ -130,6 +179,7 @@ where
 			hasher: self.hasher,
 			index: <H as Digest>::output_size(),
 			buffer: Output::<H>::default(),
+			n_forks: self.n_forks,
 		}
 	}
 }
@@ -304,4 +354,57 @@ mod tests {
 		let final_hasher_out = hasher.finalize_reset();
 		assert_eq!(final_hasher_out[..7], out_again);
 	}
+
+	fn sample_bytes(challenger: &mut HasherChallenger<Groestl256>) -> [u8; 32] {
+		let mut out = [0u8; 32];
+		challenger.sampler().copy_to_slice(&mut out);
+		out
+	}
+
+	#[test]
+	fn test_fork_and_merge() {
+		let mut challenger = HasherChallenger::<Groestl256>::default();
+		challenger.observer().put_slice(b"common prefix");
+
+		let mut left = challenger.fork();
+		let mut right = challenger.fork();
+		left.observer().put_slice(b"left");
+		right.observer().put_slice(b"right");
+
+		let original_out = sample_bytes(&mut challenger.clone());
+		let left_out = sample_bytes(&mut left.clone());
+		let right_out = sample_bytes(&mut right.clone());
+		assert_ne!(left_out, right_out);
+		assert_ne!(left_out, original_out);
+		assert_ne!(right_out, original_out);
+
+		// Forking does not affect the original challenger.
+		let mut unforked = HasherChallenger::<Groestl256>::default();
+		unforked.observer().put_slice(b"common prefix");
+		assert_eq!(sample_bytes(&mut unforked), original_out);
+
+		let mut merged = HasherChallenger::merge(left.clone(), right.clone());
+		let mut merged_again = HasherChallenger::merge(left.clone(), right.clone());
+		let mut swapped = HasherChallenger::merge(right, left);
+		let merged_out = sample_bytes(&mut merged);
+		assert_eq!(merged_out, sample_bytes(&mut merged_again));
+		assert_ne!(merged_out, sample_bytes(&mut swapped));
+		assert_ne!(merged_out, left_out);
+		assert_ne!(merged_out, right_out);
+	}
+
+	#[test]
+	fn test_sibling_forks_differ() {
+		let mut challenger = HasherChallenger::<Groestl256>::default();
+		challenger.observer().put_slice(b"common prefix");
+
+		let first_out = sample_bytes(&mut challenger.fork());
+		let second_out = sample_bytes(&mut challenger.fork());
+		assert_ne!(first_out, second_out);
+		assert_ne!(first_out, sample_bytes(&mut challenger.clone()));
+
+		// Forks are numbered from the state of the challenger, so a clone forks identically.
+		let mut cloned = challenger.clone();
+		assert_eq!(sample_bytes(&mut challenger.fork()), sample_bytes(&mut cloned.fork()));
+	}
 }
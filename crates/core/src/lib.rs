@@ -23,6 +23,8 @@ pub mod protocols;
 #[allow(clippy::module_inception)]
 pub mod reed_solomon;
 pub mod ring_switch;
+#[cfg(feature = "serde")]
+mod serde_utils;
 pub mod tensor_algebra;
 pub mod tower;
 pub mod transcript;
@@ -1,12 +1,16 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use core::iter::IntoIterator;
-use std::sync::Arc;
+use std::{collections::BTreeSet, sync::Arc};
 
 use binius_field::{Field, TowerField};
 use binius_macros::{DeserializeBytes, SerializeBytes};
 use binius_math::{ArithExpr, CompositionPoly};
-use binius_utils::bail;
+use binius_utils::{
+	bail,
+	bytes::{Buf, BufMut},
+	DeserializeBytes, SerializationError, SerializationMode, SerializeBytes,
+};
 use itertools::Itertools;
 
 use super::{Error, MultilinearOracleSet, MultilinearPolyVariant, OracleId};
@@ -17,6 +21,11 @@ pub type TypeErasedComposition<P> = Arc<dyn CompositionPoly<P>>;
 
 /// Constraint is a type erased composition along with a predicate on its values on the boolean hypercube
 #[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(bound = "")
+)]
 pub struct Constraint<F: Field> {
 	pub name: String,
 	pub composition: ArithExpr<F>,
@@ -26,21 +35,168 @@ pub struct Constraint<F: Field> {
 /// Predicate can either be a sum of values of a composition on the hypercube (sumcheck) or equality to zero
 /// on the hypercube (zerocheck)
 #[derive(Clone, Debug, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(bound = "")
+)]
 pub enum ConstraintPredicate<F: Field> {
-	Sum(F),
+	Sum(#[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::bytes"))] F),
 	Zero,
 }
 
 /// Constraint set is a group of constraints that operate over the same set of oracle-identified multilinears
-#[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(bound = "", from = "ConstraintSetFields<F>")
+)]
 pub struct ConstraintSet<F: Field> {
 	pub n_vars: usize,
 	pub oracle_ids: Vec<OracleId>,
 	pub constraints: Vec<Constraint<F>>,
+	/// The maximum degree of the constraint compositions, as returned by
+	/// [`Self::compute_degree`] when the set is created.
+	#[cfg_attr(feature = "serde", serde(skip_serializing))]
+	max_degree: usize,
+}
+
+/// The serialized fields of a [`ConstraintSet`], which recomputes its degree when deserialized.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(bound = "")]
+struct ConstraintSetFields<F: Field> {
+	n_vars: usize,
+	oracle_ids: Vec<OracleId>,
+	constraints: Vec<Constraint<F>>,
+}
+
+#[cfg(feature = "serde")]
+impl<F: Field> From<ConstraintSetFields<F>> for ConstraintSet<F> {
+	fn from(fields: ConstraintSetFields<F>) -> Self {
+		Self::new(fields.n_vars, fields.oracle_ids, fields.constraints)
+	}
+}
+
+/// The cached degree is not serialized, so that it is recomputed from the compositions rather than
+/// read from untrusted bytes.
+impl<F: Field> SerializeBytes for ConstraintSet<F> {
+	fn serialize(
+		&self,
+		mut write_buf: impl BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		SerializeBytes::serialize(&self.n_vars, &mut write_buf, mode)?;
+		SerializeBytes::serialize(&self.oracle_ids, &mut write_buf, mode)?;
+		SerializeBytes::serialize(&self.constraints, write_buf, mode)
+	}
+}
+
+impl<F: Field> DeserializeBytes for ConstraintSet<F> {
+	fn deserialize(
+		mut read_buf: impl Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError>
+	where
+		Self: Sized,
+	{
+		let n_vars = DeserializeBytes::deserialize(&mut read_buf, mode)?;
+		let oracle_ids = DeserializeBytes::deserialize(&mut read_buf, mode)?;
+		let constraints = DeserializeBytes::deserialize(read_buf, mode)?;
+		Ok(Self::new(n_vars, oracle_ids, constraints))
+	}
+}
+
+impl<F: Field> ConstraintSet<F> {
+	/// Creates a constraint set and caches the degree of its constraints.
+	pub fn new(n_vars: usize, oracle_ids: Vec<OracleId>, constraints: Vec<Constraint<F>>) -> Self {
+		let mut constraint_set = Self {
+			n_vars,
+			oracle_ids,
+			constraints,
+			max_degree: 0,
+		};
+		constraint_set.max_degree = constraint_set.compute_degree();
+		constraint_set
+	}
+
+	/// Returns the maximum degree of the constraint compositions, computed when the set was
+	/// created.
+	pub const fn max_degree(&self) -> usize {
+		self.max_degree
+	}
+
+	/// Computes the maximum degree of the constraint compositions, or zero if the set is empty.
+	///
+	/// This traverses every composition; prefer the cached [`Self::max_degree`].
+	pub fn compute_degree(&self) -> usize {
+		self.constraints
+			.iter()
+			.map(|constraint| constraint.composition.degree())
+			.max()
+			.unwrap_or(0)
+	}
+
+	/// Restricts the constraint set to the constraints that exclusively reference oracles in `ids`.
+	///
+	/// The oracle ids of the resulting set are the ones referenced by the retained constraints,
+	/// and the compositions are remapped accordingly. Returns `None` if no constraint is retained.
+	pub fn oracle_subset(&self, ids: &[OracleId]) -> Option<Self> {
+		let referenced_ids = |constraint: &Constraint<F>| {
+			constraint
+				.composition
+				.vars_usage()
+				.into_iter()
+				.zip(&self.oracle_ids)
+				.filter_map(|(used, &id)| used.then_some(id))
+				.collect::<Vec<_>>()
+		};
+
+		let constraints = self
+			.constraints
+			.iter()
+			.filter(|constraint| referenced_ids(constraint).iter().all(|id| ids.contains(id)))
+			.collect::<Vec<_>>();
+
+		let oracle_ids = constraints
+			.iter()
+			.flat_map(|constraint| referenced_ids(constraint))
+			.sorted()
+			.dedup()
+			.collect::<Vec<_>>();
+		if oracle_ids.is_empty() {
+			return None;
+		}
+
+		// Oracles that are not referenced by any retained constraint are never looked up, so
+		// their position is irrelevant.
+		let indices = self
+			.oracle_ids
+			.iter()
+			.map(|id| oracle_ids.binary_search(id).unwrap_or_default())
+			.collect::<Vec<_>>();
+
+		let constraints = constraints
+			.into_iter()
+			.map(|constraint| Constraint {
+				name: constraint.name.clone(),
+				composition: constraint
+					.composition
+					.clone()
+					.remap_vars(&indices)
+					.expect("indices has an entry for every oracle in the constraint set"),
+				predicate: constraint.predicate.clone(),
+			})
+			.collect();
+
+		Some(Self::new(self.n_vars, oracle_ids, constraints))
+	}
 }
 
 // A deferred constraint constructor that instantiates index composition after the superset of oracles is known
 #[allow(clippy::type_complexity)]
+#[derive(Clone)]
 struct UngroupedConstraint<F: Field> {
 	name: String,
 	oracle_ids: Vec<OracleId>,
@@ -50,7 +206,7 @@ struct UngroupedConstraint<F: Field> {
 
 /// A builder struct that turns individual compositions over oraclized multilinears into a set of
 /// type erased `IndexComposition` instances operating over a superset of oracles of all constraints.
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct ConstraintSetBuilder<F: Field> {
 	constraints: Vec<UngroupedConstraint<F>>,
 }
@@ -90,6 +246,30 @@ impl<F: Field> ConstraintSetBuilder<F> {
 		});
 	}
 
+	/// Returns the number of sumcheck and zerocheck constraints added so far.
+	pub fn constraints_count(&self) -> usize {
+		self.constraints.len()
+	}
+
+	/// Returns the ids of all oracles referenced by the constraints added so far.
+	pub fn oracle_ids_referenced(&self) -> BTreeSet<OracleId> {
+		self.constraints
+			.iter()
+			.flat_map(|constraint| constraint.oracle_ids.iter().copied())
+			.collect()
+	}
+
+	/// Adds all the constraints of `other`, with their oracle ids shifted by `offset`.
+	pub fn append_with_offset(&mut self, other: Self, offset: usize) {
+		self.constraints
+			.extend(other.constraints.into_iter().map(|mut constraint| {
+				for id in &mut constraint.oracle_ids {
+					*id += offset;
+				}
+				constraint
+			}));
+	}
+
 	/// Build a single constraint set, requiring that all included oracle n_vars are the same
 	pub fn build_one(
 		self,
@@ -143,11 +323,7 @@ impl<F: Field> ConstraintSetBuilder<F> {
 				})
 				.collect();
 
-		Ok(ConstraintSet {
-			n_vars,
-			oracle_ids,
-			constraints,
-		})
+		Ok(ConstraintSet::new(n_vars, oracle_ids, constraints))
 	}
 
 	/// Create one ConstraintSet for every unique n_vars used.
@@ -244,11 +420,7 @@ impl<F: Field> ConstraintSetBuilder<F> {
 					})
 					.collect();
 
-				ConstraintSet {
-					constraints,
-					oracle_ids,
-					n_vars,
-				}
+				ConstraintSet::new(n_vars, oracle_ids, constraints)
 			})
 			.collect();
 
@@ -270,3 +442,74 @@ fn positions<T: Eq>(subset: &[T], superset: &[T]) -> Option<Vec<usize>> {
 		})
 		.collect()
 }
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, Field};
+	use binius_math::ArithExpr;
+	use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+
+	use super::{ConstraintPredicate, ConstraintSet, ConstraintSetBuilder};
+	use crate::oracle::MultilinearOracleSet;
+
+	#[test]
+	fn test_constraint_set_builder_introspection() {
+		let mut builder = ConstraintSetBuilder::<BinaryField128b>::new();
+		assert_eq!(builder.constraints_count(), 0);
+		assert!(builder.oracle_ids_referenced().is_empty());
+
+		builder.add_zerocheck("a_b", [3, 1], ArithExpr::Var(0) * ArithExpr::Var(1));
+		builder.add_sumcheck([1, 5], ArithExpr::Var(0) + ArithExpr::Var(1), BinaryField128b::ONE);
+		assert_eq!(builder.constraints_count(), 2);
+		assert!(builder.oracle_ids_referenced().into_iter().eq([1, 3, 5]));
+	}
+
+	#[test]
+	fn test_constraint_set_max_degree() {
+		let mut oracles = MultilinearOracleSet::<BinaryField128b>::new();
+		let [a, b, c] = oracles.add_committed_multiple(4, 0);
+
+		let mut builder = ConstraintSetBuilder::<BinaryField128b>::new();
+		builder.add_zerocheck("a_b", [a, b], ArithExpr::Var(0) * ArithExpr::Var(1));
+		builder.add_zerocheck("c_cubed", [c], ArithExpr::Var(0).pow(3) + ArithExpr::Var(0));
+		let constraint_set = builder.build_one(&oracles).unwrap();
+		assert_eq!(constraint_set.max_degree(), 3);
+		assert_eq!(constraint_set.compute_degree(), 3);
+
+		// The degree is recomputed when deserialized.
+		let mode = SerializationMode::CanonicalTower;
+		let mut bytes = Vec::new();
+		constraint_set.serialize(&mut bytes, mode).unwrap();
+		let deserialized =
+			ConstraintSet::<BinaryField128b>::deserialize(bytes.as_slice(), mode).unwrap();
+		assert_eq!(deserialized.max_degree(), 3);
+	}
+
+	#[test]
+	fn test_constraint_predicate_serialization_roundtrip() {
+		type F = BinaryField128b;
+		for value in [F::ONE, F::new(0x1234_5678_9abc_def0)] {
+			let mut buf = Vec::new();
+			ConstraintPredicate::Sum(value)
+				.serialize(&mut buf, SerializationMode::CanonicalTower)
+				.unwrap();
+			let predicate = ConstraintPredicate::<F>::deserialize(
+				buf.as_slice(),
+				SerializationMode::CanonicalTower,
+			)
+			.unwrap();
+			assert!(matches!(predicate, ConstraintPredicate::Sum(sum) if sum == value));
+		}
+
+		let mut buf = Vec::new();
+		ConstraintPredicate::<F>::Zero
+			.serialize(&mut buf, SerializationMode::CanonicalTower)
+			.unwrap();
+		let predicate = ConstraintPredicate::<F>::deserialize(
+			buf.as_slice(),
+			SerializationMode::CanonicalTower,
+		)
+		.unwrap();
+		assert!(matches!(predicate, ConstraintPredicate::Zero));
+	}
+}
@@ -1,8 +1,8 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{array, fmt::Debug, sync::Arc};
+use std::{array, collections::HashMap, fmt::Debug, sync::Arc};
 
-use binius_field::{BinaryField128b, Field, TowerField};
+use binius_field::{BinaryField128b, BinaryField1b, Field, TowerField};
 use binius_macros::{DeserializeBytes, SerializeBytes};
 use binius_math::ArithExpr;
 use binius_utils::{bail, DeserializeBytes, SerializationError, SerializationMode, SerializeBytes};
@@ -13,6 +13,7 @@ use crate::{
 	polynomial::{
 		ArithCircuitPoly, Error as PolynomialError, IdentityCompositionPoly, MultivariatePoly,
 	},
+	transparent::{constant::Constant, select_row::SelectRow},
 };
 
 /// Identifier for a multilinear oracle in a [`MultilinearOracleSet`].
@@ -46,6 +47,32 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 		Ok(self.mut_ref.add_to_set(oracle))
 	}
 
+	/// Adds the transparent multilinear that is zero everywhere on the hypercube.
+	pub fn zero_constant(self, n_vars: usize) -> OracleId {
+		self.transparent(Constant::new(n_vars, BinaryField1b::ZERO))
+			.expect("a binary constant fits in any tower field")
+	}
+
+	/// Adds the transparent multilinear that is one everywhere on the hypercube.
+	pub fn one_constant(self, n_vars: usize) -> OracleId {
+		self.transparent(Constant::new(n_vars, BinaryField1b::ONE))
+			.expect("a binary constant fits in any tower field")
+	}
+
+	/// Adds the transparent multilinear that is one at the hypercube vertex `index` and zero
+	/// everywhere else.
+	///
+	/// Panics if `index` is not a vertex of the `n_vars`-dimensional hypercube.
+	pub fn selector_at(self, n_vars: usize, index: usize) -> OracleId {
+		assert!(
+			index < 1 << n_vars,
+			"selector index {index} is out of range for {n_vars} variables"
+		);
+		let selector = SelectRow::new(n_vars, index).expect("index is in range");
+		self.transparent(selector)
+			.expect("a binary selector fits in any tower field")
+	}
+
 	pub fn committed(mut self, n_vars: usize, tower_level: usize) -> OracleId {
 		let name = self.name.take();
 		self.add_committed_with_name(n_vars, tower_level, name)
@@ -68,11 +95,7 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 	}
 
 	pub fn repeating(self, inner_id: OracleId, log_count: usize) -> Result<OracleId, Error> {
-		if inner_id >= self.mut_ref.oracles.len() {
-			bail!(Error::InvalidOracleId(inner_id));
-		}
-
-		let inner = self.mut_ref.get_from_set(inner_id);
+		let inner = self.mut_ref.get_from_set(inner_id)?;
 
 		let oracle = |id: OracleId| MultilinearPolyOracle {
 			id,
@@ -95,11 +118,7 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 		block_bits: usize,
 		variant: ShiftVariant,
 	) -> Result<OracleId, Error> {
-		if inner_id >= self.mut_ref.oracles.len() {
-			bail!(Error::InvalidOracleId(inner_id));
-		}
-
-		let inner = self.mut_ref.get_from_set(inner_id);
+		let inner = self.mut_ref.get_from_set(inner_id)?;
 		if block_bits > inner.n_vars {
 			bail!(PolynomialError::InvalidBlockSize {
 				n_vars: inner.n_vars,
@@ -127,11 +146,8 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 	}
 
 	pub fn packed(self, inner_id: OracleId, log_degree: usize) -> Result<OracleId, Error> {
-		if inner_id >= self.mut_ref.oracles.len() {
-			bail!(Error::InvalidOracleId(inner_id));
-		}
-
-		let inner_n_vars = self.mut_ref.n_vars(inner_id);
+		let inner = self.mut_ref.get(inner_id)?;
+		let inner_n_vars = inner.n_vars();
 		if log_degree > inner_n_vars {
 			bail!(Error::NotEnoughVarsForPacking {
 				n_vars: inner_n_vars,
@@ -139,7 +155,7 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 			});
 		}
 
-		let inner_tower_level = self.mut_ref.tower_level(inner_id);
+		let inner_tower_level = inner.binary_tower_level();
 
 		let packed = Packed {
 			id: inner_id,
@@ -163,7 +179,7 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 		values: Vec<F>,
 		start_index: usize,
 	) -> Result<OracleId, Error> {
-		let inner_n_vars = self.mut_ref.n_vars(inner_id);
+		let inner_n_vars = self.mut_ref.get(inner_id)?.n_vars();
 		let values_len = values.len();
 		if values_len > inner_n_vars {
 			bail!(Error::InvalidProjection {
@@ -172,9 +188,8 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 			});
 		}
 
-		let inner = self.mut_ref.get_from_set(inner_id);
-		// TODO: This is wrong, should be F::TOWER_LEVEL
-		let tower_level = inner.binary_tower_level();
+		let inner = self.mut_ref.get_from_set(inner_id)?;
+		let tower_level = projected_tower_level(&inner, &values);
 		let projected = Projected::new(&inner, values, start_index)?;
 
 		let oracle = |id: OracleId| MultilinearPolyOracle {
@@ -193,7 +208,7 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 		inner_id: OracleId,
 		values: Vec<F>,
 	) -> Result<OracleId, Error> {
-		let inner_n_vars = self.mut_ref.n_vars(inner_id);
+		let inner_n_vars = self.mut_ref.get(inner_id)?.n_vars();
 		let start_index = inner_n_vars - values.len();
 		let values_len = values.len();
 		if values_len > inner_n_vars {
@@ -203,9 +218,8 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 			});
 		}
 
-		let inner = self.mut_ref.get_from_set(inner_id);
-		// TODO: This is wrong, should be F::TOWER_LEVEL
-		let tower_level = inner.binary_tower_level();
+		let inner = self.mut_ref.get_from_set(inner_id)?;
+		let tower_level = projected_tower_level(&inner, &values);
 		let projected = Projected::new(&inner, values, start_index)?;
 
 		let oracle = |id: OracleId| MultilinearPolyOracle {
@@ -236,13 +250,11 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 		let inner = inner
 			.into_iter()
 			.map(|(inner_id, coeff)| {
-				if inner_id >= self.mut_ref.oracles.len() {
-					return Err(Error::InvalidOracleId(inner_id));
-				}
-				if self.mut_ref.n_vars(inner_id) != n_vars {
+				let inner = self.mut_ref.get_from_set(inner_id)?;
+				if inner.n_vars != n_vars {
 					return Err(Error::IncorrectNumberOfVariables { expected: n_vars });
 				}
-				Ok((self.mut_ref.get_from_set(inner_id), coeff))
+				Ok((inner, coeff))
 			})
 			.collect::<Result<Vec<_>, _>>()?;
 
@@ -275,13 +287,11 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 		let inner = inner
 			.into_iter()
 			.map(|inner_id| {
-				if inner_id >= self.mut_ref.oracles.len() {
-					return Err(Error::InvalidOracleId(inner_id));
-				}
-				if self.mut_ref.n_vars(inner_id) != n_vars {
+				let inner = self.mut_ref.get_from_set(inner_id)?;
+				if inner.n_vars != n_vars {
 					return Err(Error::IncorrectNumberOfVariables { expected: n_vars });
 				}
-				Ok(self.mut_ref.get_from_set(inner_id))
+				Ok(inner)
 			})
 			.collect::<Result<Vec<_>, _>>()?;
 
@@ -305,18 +315,13 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 	}
 
 	pub fn zero_padded(self, inner_id: OracleId, n_vars: usize) -> Result<OracleId, Error> {
-		if inner_id >= self.mut_ref.oracles.len() {
-			bail!(Error::InvalidOracleId(inner_id));
-		}
-
-		if self.mut_ref.n_vars(inner_id) > n_vars {
+		let inner = self.mut_ref.get_from_set(inner_id)?;
+		if inner.n_vars > n_vars {
 			bail!(Error::IncorrectNumberOfVariables {
-				expected: self.mut_ref.n_vars(inner_id),
+				expected: inner.n_vars,
 			});
 		};
 
-		let inner = self.mut_ref.get_from_set(inner_id);
-
 		let oracle = |id: OracleId| MultilinearPolyOracle {
 			id,
 			n_vars,
@@ -346,6 +351,18 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 	}
 }
 
+/// Returns the tower level of the projection of `inner` onto `values`.
+///
+/// The projected values are linear combinations of the values of `inner`, with coefficients given
+/// by the equality indicator of `values`. Projecting onto hypercube vertices, with 0 and 1 values,
+/// keeps the tower level of `inner`, whereas extension field values lift it to theirs.
+fn projected_tower_level<F: TowerField>(inner: &MultilinearPolyOracle<F>, values: &[F]) -> usize {
+	values
+		.iter()
+		.map(|value| value.min_tower_level())
+		.fold(inner.binary_tower_level(), usize::max)
+}
+
 /// An ordered set of multilinear polynomial oracles.
 ///
 /// The multilinear polynomial oracles form a directed acyclic graph, where each multilinear oracle
@@ -356,8 +373,17 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 /// together with a polynomial commitment scheme.
 #[derive(Default, Debug, Clone, SerializeBytes, DeserializeBytes)]
 #[deserialize_bytes(eval_generics(F = BinaryField128b))]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(bound(
+		serialize = "",
+		deserialize = "crate::oracle::TransparentPolyOracle<F>: serde::Deserialize<'de>"
+	))
+)]
 pub struct MultilinearOracleSet<F: TowerField> {
-	oracles: Vec<MultilinearPolyOracle<F>>,
+	/// Oracles indexed by id, with `None` for removed oracles.
+	oracles: Vec<Option<MultilinearPolyOracle<F>>>,
 }
 
 impl<F: TowerField> MultilinearOracleSet<F> {
@@ -367,12 +393,27 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		}
 	}
 
+	/// Returns the number of oracle ids allocated, including the ids of removed oracles.
 	pub fn size(&self) -> usize {
 		self.oracles.len()
 	}
 
+	/// Iterates over the oracles in id order, skipping removed oracles.
 	pub fn iter(&self) -> impl Iterator<Item = MultilinearPolyOracle<F>> + '_ {
-		(0..self.oracles.len()).map(|id| self.oracle(id))
+		self.oracles.iter().flatten().cloned()
+	}
+
+	/// Iterates over the ids of the oracles in order, skipping removed oracles.
+	pub fn ids(&self) -> impl Iterator<Item = OracleId> + '_ {
+		self.oracles.iter().flatten().map(|oracle| oracle.id)
+	}
+
+	/// Iterates over the ids and names of the named oracles in id order, skipping removed oracles.
+	pub fn names(&self) -> impl Iterator<Item = (OracleId, &str)> + '_ {
+		self.oracles
+			.iter()
+			.flatten()
+			.filter_map(|oracle| Some((oracle.id, oracle.name()?)))
 	}
 
 	pub const fn add(&mut self) -> MultilinearOracleSetAddition<F> {
@@ -390,7 +431,35 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 	}
 
 	pub fn is_valid_oracle_id(&self, id: OracleId) -> bool {
-		id < self.oracles.len()
+		self.oracles.get(id).is_some_and(Option::is_some)
+	}
+
+	/// Returns whether the oracle `id` was removed with [`Self::remove_oracle`].
+	pub fn is_oracle_removed(&self, id: OracleId) -> bool {
+		self.oracles.get(id).is_some_and(Option::is_none)
+	}
+
+	/// Removes the oracle `id` from the set.
+	///
+	/// The ids of the other oracles are unchanged, and `id` is not reused. Fails if `id` is not a
+	/// valid oracle id, or if another oracle is defined over it.
+	pub fn remove_oracle(&mut self, id: OracleId) -> Result<(), Error> {
+		if !self.is_valid_oracle_id(id) {
+			bail!(Error::InvalidOracleId(id));
+		}
+		if let Some(dependent) = self
+			.oracles
+			.iter()
+			.flatten()
+			.find(|oracle| oracle.variant.inner_ids().contains(&id))
+		{
+			bail!(Error::OracleInUse {
+				id,
+				dependent: dependent.id,
+			});
+		}
+		self.oracles[id] = None;
+		Ok(())
 	}
 
 	fn add_to_set(
@@ -398,12 +467,26 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		oracle: impl FnOnce(OracleId) -> MultilinearPolyOracle<F>,
 	) -> OracleId {
 		let id = self.oracles.len();
-		self.oracles.push(oracle(id));
+		self.oracles.push(Some(oracle(id)));
 		id
 	}
 
-	fn get_from_set(&self, id: OracleId) -> MultilinearPolyOracle<F> {
-		self.oracles[id].clone()
+	fn get_from_set(&self, id: OracleId) -> Result<MultilinearPolyOracle<F>, Error> {
+		self.get(id).cloned()
+	}
+
+	/// Returns the oracle `id`, panicking if it is out of range or was removed.
+	fn expect(&self, id: OracleId) -> &MultilinearPolyOracle<F> {
+		self.get(id)
+			.unwrap_or_else(|_| panic!("oracle {id} does not exist or was removed"))
+	}
+
+	/// Returns the oracle `id`, failing if it is out of range or was removed.
+	fn get(&self, id: OracleId) -> Result<&MultilinearPolyOracle<F>, Error> {
+		self.oracles
+			.get(id)
+			.and_then(Option::as_ref)
+			.ok_or(Error::InvalidOracleId(id))
 	}
 
 	pub fn add_transparent(
@@ -413,6 +496,18 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.add().transparent(poly)
 	}
 
+	pub fn add_zero_constant(&mut self, n_vars: usize) -> OracleId {
+		self.add().zero_constant(n_vars)
+	}
+
+	pub fn add_one_constant(&mut self, n_vars: usize) -> OracleId {
+		self.add().one_constant(n_vars)
+	}
+
+	pub fn add_selector_at(&mut self, n_vars: usize, index: usize) -> OracleId {
+		self.add().selector_at(n_vars, index)
+	}
+
 	pub fn add_committed(&mut self, n_vars: usize, tower_level: usize) -> OracleId {
 		self.add().committed(n_vars, tower_level)
 	}
@@ -425,6 +520,34 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.add().committed_multiple(n_vars, tower_level)
 	}
 
+	/// Adds `n` committed oracles named `{base_name}_0`, ..., `{base_name}_{n - 1}`.
+	pub fn batch_add_committed(
+		&mut self,
+		n: usize,
+		n_vars: usize,
+		tower_level: usize,
+		base_name: impl ToString,
+	) -> Vec<OracleId> {
+		let base_name = base_name.to_string();
+		(0..n)
+			.map(|i| {
+				self.add_named(format!("{base_name}_{i}"))
+					.committed(n_vars, tower_level)
+			})
+			.collect()
+	}
+
+	/// Same as [`Self::batch_add_committed`], with the number of oracles known at compile time.
+	pub fn batch_add_committed_array<const N: usize>(
+		&mut self,
+		n_vars: usize,
+		tower_level: usize,
+		base_name: impl ToString,
+	) -> [OracleId; N] {
+		self.add_named(base_name)
+			.committed_multiple(n_vars, tower_level)
+	}
+
 	pub fn add_repeating(&mut self, id: OracleId, log_count: usize) -> Result<OracleId, Error> {
 		self.add().repeating(id, log_count)
 	}
@@ -493,21 +616,130 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.add().composite_mle(n_vars, inner, comp)
 	}
 
+	/// Returns the oracle `id`.
+	///
+	/// Panics if `id` does not exist or was removed, see [`Self::try_oracle`].
 	pub fn oracle(&self, id: OracleId) -> MultilinearPolyOracle<F> {
-		self.oracles[id].clone()
+		self.expect(id).clone()
+	}
+
+	/// Returns the oracle `id`, or [`Error::InvalidOracleId`] if it does not exist or was removed.
+	pub fn try_oracle(&self, id: OracleId) -> Result<MultilinearPolyOracle<F>, Error> {
+		self.get_from_set(id)
 	}
 
 	pub fn n_vars(&self, id: OracleId) -> usize {
-		self.oracles[id].n_vars()
+		self.expect(id).n_vars()
+	}
+
+	/// Same as [`Self::n_vars`], but fails instead of panicking if `id` does not exist or was
+	/// removed.
+	pub fn try_n_vars(&self, id: OracleId) -> Result<usize, Error> {
+		Ok(self.get(id)?.n_vars())
 	}
 
 	pub fn label(&self, id: OracleId) -> String {
-		self.oracles[id].label()
+		self.expect(id).label()
+	}
+
+	/// Same as [`Self::label`], but fails instead of panicking if `id` does not exist or was
+	/// removed.
+	pub fn try_label(&self, id: OracleId) -> Result<String, Error> {
+		Ok(self.get(id)?.label())
+	}
+
+	/// Returns the id of the first oracle named `name`, skipping removed oracles.
+	///
+	/// Oracle names are not required to be unique, and lookups scan the whole set, so prefer
+	/// keeping the returned [`OracleId`]s where possible.
+	pub fn get_id_by_name(&self, name: &str) -> Option<OracleId> {
+		self.oracles
+			.iter()
+			.flatten()
+			.find(|oracle| oracle.name() == Some(name))
+			.map(|oracle| oracle.id)
+	}
+
+	/// Returns the ids of all oracles whose name starts with `prefix`, in id order.
+	///
+	/// Matching is on the raw string, so the prefix `"carry_1"` also matches `"carry_10"`.
+	pub fn get_ids_by_prefix(&self, prefix: &str) -> Vec<OracleId> {
+		self.oracles
+			.iter()
+			.flatten()
+			.filter(|oracle| oracle.name().is_some_and(|name| name.starts_with(prefix)))
+			.map(|oracle| oracle.id)
+			.collect()
+	}
+
+	/// Extracts the oracles `ids`, together with all the oracles they are defined over, into a new
+	/// oracle set.
+	///
+	/// Oracles keep their relative order. Returns the new set along with the mapping from oracle
+	/// ids in `self` to oracle ids in the new set.
+	pub fn subset(&self, ids: &[OracleId]) -> Result<(Self, HashMap<OracleId, OracleId>), Error> {
+		let mut included = vec![false; self.oracles.len()];
+		let mut stack = ids.to_vec();
+		while let Some(id) = stack.pop() {
+			let oracle = self.get(id)?;
+			if !included[id] {
+				included[id] = true;
+				stack.extend(oracle.variant.inner_ids());
+			}
+		}
+
+		let id_map = (0..self.oracles.len())
+			.filter(|&id| included[id])
+			.enumerate()
+			.map(|(new_id, id)| (id, new_id))
+			.collect::<HashMap<_, _>>();
+
+		let mut oracles = Vec::with_capacity(id_map.len());
+		for oracle in self
+			.oracles
+			.iter()
+			.flatten()
+			.filter(|oracle| included[oracle.id])
+		{
+			oracles.push(Some(MultilinearPolyOracle {
+				id: id_map[&oracle.id],
+				variant: oracle.variant.remap_ids(&id_map),
+				..oracle.clone()
+			}));
+		}
+		Ok((Self { oracles }, id_map))
+	}
+
+	/// Appends a copy of all the oracles in `other` to this set.
+	///
+	/// The copied oracles are shifted by the current size of this set, so that references
+	/// between oracles of `other` are preserved. Removed oracles of `other` stay removed. Returns
+	/// the ids of the new oracles, in the order of `other`.
+	pub fn append(&mut self, other: &Self) -> Vec<OracleId> {
+		let offset = self.oracles.len();
+		let id_map = (0..other.oracles.len())
+			.map(|id| (id, id + offset))
+			.collect::<HashMap<_, _>>();
+
+		self.oracles.extend(other.oracles.iter().map(|oracle| {
+			oracle.as_ref().map(|oracle| MultilinearPolyOracle {
+				id: id_map[&oracle.id],
+				variant: oracle.variant.remap_ids(&id_map),
+				..oracle.clone()
+			})
+		}));
+		(offset..self.oracles.len()).collect()
 	}
 
 	/// Maximum tower level of the oracle's values over the boolean hypercube.
 	pub fn tower_level(&self, id: OracleId) -> usize {
-		self.oracles[id].binary_tower_level()
+		self.expect(id).binary_tower_level()
+	}
+
+	/// Same as [`Self::tower_level`], but fails instead of panicking if `id` does not exist or was
+	/// removed.
+	pub fn try_tower_level(&self, id: OracleId) -> Result<usize, Error> {
+		Ok(self.get(id)?.binary_tower_level())
 	}
 }
 
@@ -533,6 +765,14 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 /// [DP23]: <https://eprint.iacr.org/2023/1784>
 #[derive(Debug, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
 #[deserialize_bytes(eval_generics(F = BinaryField128b))]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(bound(
+		serialize = "",
+		deserialize = "crate::oracle::TransparentPolyOracle<F>: serde::Deserialize<'de>"
+	))
+)]
 pub struct MultilinearPolyOracle<F: TowerField> {
 	pub id: OracleId,
 	pub name: Option<String>,
@@ -542,6 +782,14 @@ pub struct MultilinearPolyOracle<F: TowerField> {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, SerializeBytes)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(bound(
+		serialize = "",
+		deserialize = "crate::oracle::TransparentPolyOracle<F>: serde::Deserialize<'de>"
+	))
+)]
 pub enum MultilinearPolyVariant<F: TowerField> {
 	Committed,
 	Transparent(TransparentPolyOracle<F>),
@@ -554,6 +802,48 @@ pub enum MultilinearPolyVariant<F: TowerField> {
 	Composite(CompositeMLE<F>),
 }
 
+impl<F: TowerField> MultilinearPolyVariant<F> {
+	/// Returns the ids of the oracles this oracle is defined over.
+	pub fn inner_ids(&self) -> Vec<OracleId> {
+		match self {
+			Self::Committed | Self::Transparent(_) => vec![],
+			Self::Repeating { id, .. } | Self::ZeroPadded(id) => vec![*id],
+			Self::Projected(projected) => vec![projected.id],
+			Self::Shifted(shifted) => vec![shifted.id],
+			Self::Packed(packed) => vec![packed.id],
+			Self::LinearCombination(linear_combination) => linear_combination.polys().collect(),
+			Self::Composite(composite) => composite.polys().collect(),
+		}
+	}
+
+	/// Returns a copy of the variant with the inner oracle ids replaced according to `id_map`.
+	///
+	/// ## Preconditions
+	///
+	/// * `id_map` must contain all the inner oracle ids of the variant
+	fn remap_ids(&self, id_map: &HashMap<OracleId, OracleId>) -> Self {
+		let mut variant = self.clone();
+		match &mut variant {
+			Self::Committed | Self::Transparent(_) => {}
+			Self::Repeating { id, .. } | Self::ZeroPadded(id) => *id = id_map[id],
+			Self::Projected(projected) => projected.id = id_map[&projected.id],
+			Self::Shifted(shifted) => shifted.id = id_map[&shifted.id],
+			Self::Packed(packed) => packed.id = id_map[&packed.id],
+			Self::LinearCombination(linear_combination) => {
+				for (id, _) in &mut linear_combination.inner {
+					*id = id_map[id];
+				}
+			}
+			Self::Composite(composite) => {
+				for id in &mut composite.inner {
+					*id = id_map[id];
+				}
+			}
+		}
+		variant
+	}
+}
+
 impl DeserializeBytes for MultilinearPolyVariant<BinaryField128b> {
 	fn deserialize(
 		mut buf: impl bytes::Buf,
@@ -643,11 +933,87 @@ impl<F: Field> PartialEq for TransparentPolyOracle<F> {
 
 impl<F: Field> Eq for TransparentPolyOracle<F> {}
 
+/// [`serde`] support for transparent polynomials.
+///
+/// The polynomial is a trait object, so it is stored as the byte encoding produced by its
+/// `erased_serialize` implementation, tagged with the name of its concrete type. Deserialization
+/// goes through the same deserializer registry as [`DeserializeBytes`], hence it is only
+/// supported over [`BinaryField128b`].
+#[cfg(feature = "serde")]
+mod transparent_serde {
+	use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+	use super::*;
+	use crate::serde_utils::MODE;
+
+	#[derive(Serialize, Deserialize)]
+	#[serde(tag = "kind")]
+	enum TransparentPoly {
+		Constant { data: Vec<u8> },
+		StepDown { data: Vec<u8> },
+		EqIndPartialEval { data: Vec<u8> },
+		MultilinearExtensionTransparent { data: Vec<u8> },
+	}
+
+	impl<F: TowerField> Serialize for TransparentPolyOracle<F> {
+		fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+			let mut bytes = Vec::new();
+			self.poly
+				.erased_serialize(&mut bytes, MODE)
+				.map_err(ser::Error::custom)?;
+
+			let mut read_buf = bytes.as_slice();
+			let name = <String as DeserializeBytes>::deserialize(&mut read_buf, MODE)
+				.map_err(ser::Error::custom)?;
+			let data = read_buf.to_vec();
+			let poly = match name.as_str() {
+				"Constant" => TransparentPoly::Constant { data },
+				"StepDown" => TransparentPoly::StepDown { data },
+				"EqIndPartialEval" => TransparentPoly::EqIndPartialEval { data },
+				"MultilinearExtensionTransparent" => {
+					TransparentPoly::MultilinearExtensionTransparent { data }
+				}
+				_ => {
+					return Err(ser::Error::custom(format!(
+						"transparent polynomial {name} does not support serde"
+					)))
+				}
+			};
+			Serialize::serialize(&poly, serializer)
+		}
+	}
+
+	impl<'de> Deserialize<'de> for TransparentPolyOracle<BinaryField128b> {
+		fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+			let (name, data) = match <TransparentPoly as Deserialize>::deserialize(deserializer)? {
+				TransparentPoly::Constant { data } => ("Constant", data),
+				TransparentPoly::StepDown { data } => ("StepDown", data),
+				TransparentPoly::EqIndPartialEval { data } => ("EqIndPartialEval", data),
+				TransparentPoly::MultilinearExtensionTransparent { data } => {
+					("MultilinearExtensionTransparent", data)
+				}
+			};
+
+			let mut bytes = Vec::new();
+			SerializeBytes::serialize(&name, &mut bytes, MODE).map_err(de::Error::custom)?;
+			bytes.extend(data);
+			<Self as DeserializeBytes>::deserialize(bytes.as_slice(), MODE)
+				.map_err(de::Error::custom)
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(bound = "")
+)]
 pub struct Projected<F: TowerField> {
 	#[get_copy = "pub"]
 	id: OracleId,
 	#[get = "pub"]
+	#[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::bytes"))]
 	values: Vec<F>,
 	#[get_copy = "pub"]
 	start_index: usize,
@@ -674,6 +1040,7 @@ impl<F: TowerField> Projected<F> {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ShiftVariant {
 	CircularLeft,
 	LogicalLeft,
@@ -681,6 +1048,7 @@ pub enum ShiftVariant {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shifted {
 	#[get_copy = "pub"]
 	id: OracleId,
@@ -722,6 +1090,7 @@ impl Shifted {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Packed {
 	#[get_copy = "pub"]
 	id: OracleId,
@@ -736,11 +1105,18 @@ pub struct Packed {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(bound = "")
+)]
 pub struct LinearCombination<F: TowerField> {
 	#[get_copy = "pub"]
 	n_vars: usize,
 	#[get_copy = "pub"]
+	#[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::bytes"))]
 	offset: F,
+	#[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::bytes"))]
 	inner: Vec<(OracleId, F)>,
 }
 
@@ -760,6 +1136,16 @@ impl<F: TowerField> LinearCombination<F> {
 				}
 			})
 			.collect::<Result<Vec<_>, _>>()?;
+		#[cfg(debug_assertions)]
+		{
+			let n_zero_terms = inner.iter().filter(|(_, coeff)| *coeff == F::ZERO).count();
+			if n_zero_terms > 0 {
+				tracing::warn!(
+					"Linear combination has {n_zero_terms} terms with zero coefficients, consider \
+					removing them with LinearCombination::simplify"
+				);
+			}
+		}
 		Ok(Self {
 			n_vars,
 			offset,
@@ -767,6 +1153,38 @@ impl<F: TowerField> LinearCombination<F> {
 		})
 	}
 
+	/// Returns the same linear combination without the terms with zero coefficients.
+	pub fn simplify(&self) -> Self {
+		Self {
+			n_vars: self.n_vars,
+			offset: self.offset,
+			inner: self
+				.inner
+				.iter()
+				.copied()
+				.filter(|(_, coeff)| *coeff != F::ZERO)
+				.collect(),
+		}
+	}
+
+	/// Splits off the terms with unit coefficients, which can be evaluated with additions only.
+	///
+	/// Returns the ids of the oracles with unit coefficients, and the linear combination of the
+	/// remaining terms, including the offset.
+	pub fn simplify_unit_coefficients(&self) -> (Vec<OracleId>, Self) {
+		let (unit_terms, other_terms): (Vec<_>, Vec<_>) = self
+			.inner
+			.iter()
+			.copied()
+			.partition(|(_, coeff)| *coeff == F::ONE);
+		let remainder = Self {
+			n_vars: self.n_vars,
+			offset: self.offset,
+			inner: other_terms,
+		};
+		(unit_terms.into_iter().map(|(id, _)| id).collect(), remainder)
+	}
+
 	pub fn n_polys(&self) -> usize {
 		self.inner.len()
 	}
@@ -788,6 +1206,11 @@ impl<F: TowerField> LinearCombination<F> {
 ///
 /// ($C$ should be sufficiently lightweight to be evaluated by the verifier)
 #[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters, SerializeBytes)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(bound = "")
+)]
 pub struct CompositeMLE<F: TowerField> {
 	/// $\mu$
 	#[get_copy = "pub"]
@@ -797,6 +1220,7 @@ pub struct CompositeMLE<F: TowerField> {
 	inner: Vec<OracleId>,
 	/// $C$
 	#[getset(get = "pub")]
+	#[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::bytes"))]
 	c: ArithCircuitPoly<F>,
 }
 
@@ -878,9 +1302,20 @@ impl<F: TowerField> MultilinearPolyOracle<F> {
 
 #[cfg(test)]
 mod tests {
-	use binius_field::{BinaryField128b, BinaryField1b, Field, TowerField};
+	use binius_field::{BinaryField128b, BinaryField1b, BinaryField8b, Field, TowerField};
+
+	use super::{MultilinearOracleSet, MultilinearPolyVariant};
+	use crate::{oracle::Error, polynomial::test_utils::hypercube_evals_from_oracle};
 
-	use super::MultilinearOracleSet;
+	fn transparent_hypercube_evals(
+		oracles: &MultilinearOracleSet<BinaryField128b>,
+		id: usize,
+	) -> Vec<BinaryField128b> {
+		let MultilinearPolyVariant::Transparent(transparent) = oracles.oracle(id).variant else {
+			panic!("expected a transparent oracle");
+		};
+		hypercube_evals_from_oracle(transparent.poly().as_ref())
+	}
 
 	#[test]
 	fn add_projection_with_all_vars() {
@@ -893,4 +1328,217 @@ mod tests {
 			.unwrap();
 		let _ = oracles.oracle(projected);
 	}
+
+	#[test]
+	fn add_projected_tower_level() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let data = oracles.add_committed(5, BinaryField1b::TOWER_LEVEL);
+
+		let challenge = F::new(0x1234_5678_9abc_def0_0fed_cba9_8765_4321);
+		let projected = oracles
+			.add_projected(data, vec![challenge, F::ONE], 1)
+			.unwrap();
+		assert_eq!(oracles.tower_level(projected), BinaryField128b::TOWER_LEVEL);
+		let projected = oracles
+			.add_projected_last_vars(data, vec![F::from(BinaryField8b::new(0x80))])
+			.unwrap();
+		assert_eq!(oracles.tower_level(projected), BinaryField8b::TOWER_LEVEL);
+
+		// Projecting onto a hypercube vertex selects values of the inner oracle.
+		let projected = oracles
+			.add_projected(data, vec![F::ONE, F::ZERO], 0)
+			.unwrap();
+		assert_eq!(oracles.tower_level(projected), BinaryField1b::TOWER_LEVEL);
+		let packed = oracles.add_packed(projected, 3).unwrap();
+		assert_eq!(oracles.tower_level(packed), BinaryField8b::TOWER_LEVEL);
+	}
+
+	#[test]
+	fn add_constants_and_selector() {
+		type F = BinaryField128b;
+		let n_vars = 4;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let zero = oracles.add_zero_constant(n_vars);
+		let one = oracles.add_one_constant(n_vars);
+		let selector = oracles.add_selector_at(n_vars, 11);
+
+		for id in [zero, one, selector] {
+			assert_eq!(oracles.n_vars(id), n_vars);
+			assert_eq!(oracles.tower_level(id), BinaryField1b::TOWER_LEVEL);
+		}
+		assert_eq!(transparent_hypercube_evals(&oracles, zero), vec![F::ZERO; 1 << n_vars]);
+		assert_eq!(transparent_hypercube_evals(&oracles, one), vec![F::ONE; 1 << n_vars]);
+		for (i, eval) in transparent_hypercube_evals(&oracles, selector)
+			.into_iter()
+			.enumerate()
+		{
+			assert_eq!(eval, if i == 11 { F::ONE } else { F::ZERO });
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "selector index 16 is out of range for 4 variables")]
+	fn add_selector_out_of_range() {
+		MultilinearOracleSet::<BinaryField128b>::new().add_selector_at(4, 16);
+	}
+
+	#[test]
+	fn batch_add_committed() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let ids = oracles.batch_add_committed(3, 5, BinaryField1b::TOWER_LEVEL, "state");
+		let array: [_; 2] =
+			oracles.batch_add_committed_array(4, BinaryField8b::TOWER_LEVEL, "byte");
+
+		assert_eq!(ids, vec![0, 1, 2]);
+		assert_eq!(array, [3, 4]);
+		for (i, &id) in ids.iter().enumerate() {
+			assert_eq!(oracles.oracle(id).name(), Some(format!("state_{i}").as_str()));
+			assert_eq!(oracles.n_vars(id), 5);
+			assert_eq!(oracles.tower_level(id), BinaryField1b::TOWER_LEVEL);
+		}
+		assert_eq!(oracles.oracle(array[1]).name(), Some("byte_1"));
+		assert_eq!(oracles.tower_level(array[1]), BinaryField8b::TOWER_LEVEL);
+		assert!(oracles
+			.batch_add_committed(0, 5, BinaryField1b::TOWER_LEVEL, "empty")
+			.is_empty());
+	}
+
+	#[test]
+	fn get_ids_by_name() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let unnamed = oracles.add_committed(5, BinaryField1b::TOWER_LEVEL);
+		let carries = oracles.batch_add_committed(10, 5, BinaryField1b::TOWER_LEVEL, "carry");
+		let sum = oracles
+			.add_named("sum")
+			.linear_combination(5, [(carries[0], F::ONE), (carries[1], F::ONE)])
+			.unwrap();
+
+		for (i, &id) in carries.iter().enumerate() {
+			assert_eq!(oracles.get_id_by_name(&format!("carry_{i}")), Some(id));
+		}
+		assert_eq!(oracles.get_id_by_name("sum"), Some(sum));
+		assert_eq!(oracles.get_id_by_name("carry"), None);
+		assert_eq!(oracles.get_id_by_name(&format!("id={unnamed}")), None);
+
+		assert_eq!(oracles.get_ids_by_prefix("carry_"), carries);
+		assert_eq!(oracles.get_ids_by_prefix("carry_1"), vec![carries[1]]);
+		assert!(oracles.get_ids_by_prefix("missing").is_empty());
+
+		oracles.remove_oracle(sum).unwrap();
+		assert_eq!(oracles.get_id_by_name("sum"), None);
+	}
+
+	#[test]
+	fn subset_includes_inner_oracles() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let a = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		let b = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		let c = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		let sum = oracles
+			.add_linear_combination(4, [(a, F::ONE), (c, F::ONE)])
+			.unwrap();
+		let packed = oracles.add_packed(sum, 2).unwrap();
+
+		let (subset, id_map) = oracles.subset(&[packed]).unwrap();
+		assert_eq!(subset.size(), 4);
+		assert!(!id_map.contains_key(&b));
+		assert_eq!(
+			[a, c, sum, packed].map(|id| id_map[&id]),
+			[0, 1, 2, 3],
+			"oracles keep their relative order"
+		);
+		assert_eq!(subset.oracle(3).variant.inner_ids(), vec![2]);
+		assert_eq!(subset.oracle(2).variant.inner_ids(), vec![0, 1]);
+
+		assert!(oracles.subset(&[oracles.size()]).is_err());
+	}
+
+	#[test]
+	fn simplify_linear_combination() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let [a, b, c] = oracles.add_committed_multiple(4, BinaryField1b::TOWER_LEVEL);
+		let two = F::new(2);
+		let id = oracles
+			.add_linear_combination_with_offset(4, two, [(a, F::ONE), (b, F::ZERO), (c, two)])
+			.unwrap();
+		let MultilinearPolyVariant::LinearCombination(linear_combination) =
+			oracles.oracle(id).variant
+		else {
+			panic!("expected a linear combination");
+		};
+
+		let simplified = linear_combination.simplify();
+		assert_eq!(simplified.offset(), two);
+		assert_eq!(simplified.polys().collect::<Vec<_>>(), vec![a, c]);
+		assert_eq!(simplified.coefficients().collect::<Vec<_>>(), vec![F::ONE, two]);
+
+		let (unit_ids, remainder) = simplified.simplify_unit_coefficients();
+		assert_eq!(unit_ids, vec![a]);
+		assert_eq!(remainder.offset(), two);
+		assert_eq!(remainder.polys().collect::<Vec<_>>(), vec![c]);
+	}
+
+	#[test]
+	fn remove_oracle() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let [a, b, c] = oracles.add_committed_multiple(4, BinaryField1b::TOWER_LEVEL);
+		let packed = oracles.add_packed(b, 2).unwrap();
+
+		assert!(matches!(
+			oracles.remove_oracle(b),
+			Err(Error::OracleInUse { id, dependent }) if id == b && dependent == packed
+		));
+		oracles.remove_oracle(packed).unwrap();
+		oracles.remove_oracle(b).unwrap();
+		assert!(matches!(oracles.remove_oracle(b), Err(Error::InvalidOracleId(id)) if id == b));
+		assert!(oracles.remove_oracle(oracles.size()).is_err());
+
+		assert!(oracles.is_oracle_removed(b));
+		assert!(!oracles.is_valid_oracle_id(b));
+		assert!(!oracles.is_oracle_removed(a));
+		assert!(!oracles.is_oracle_removed(oracles.size()));
+		assert_eq!(oracles.size(), 4);
+		assert_eq!(oracles.iter().map(|oracle| oracle.id()).collect::<Vec<_>>(), vec![a, c]);
+		assert_eq!(oracles.ids().collect::<Vec<_>>(), vec![a, c]);
+		assert!(oracles.add_packed(b, 2).is_err());
+
+		// The fallible accessors report removed ids as invalid instead of panicking.
+		assert!(matches!(oracles.try_oracle(b), Err(Error::InvalidOracleId(id)) if id == b));
+		assert!(matches!(oracles.try_n_vars(b), Err(Error::InvalidOracleId(id)) if id == b));
+		assert!(matches!(oracles.try_label(b), Err(Error::InvalidOracleId(id)) if id == b));
+		assert!(matches!(oracles.try_tower_level(b), Err(Error::InvalidOracleId(id)) if id == b));
+		assert!(oracles.try_n_vars(oracles.size()).is_err());
+		assert_eq!(oracles.try_oracle(a).unwrap().id(), a);
+		assert_eq!(oracles.try_n_vars(a).unwrap(), 4);
+
+		// Removed ids are not reused.
+		let d = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		assert_eq!(d, 4);
+
+		let (subset, id_map) = oracles.subset(&[a, d]).unwrap();
+		assert_eq!(subset.size(), 2);
+		assert_eq!([a, d].map(|id| id_map[&id]), [0, 1]);
+
+		let mut appended = MultilinearOracleSet::<F>::new();
+		appended.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		let new_ids = appended.append(&oracles);
+		assert_eq!(new_ids, vec![1, 2, 3, 4, 5]);
+		assert!(appended.is_oracle_removed(new_ids[b]));
+		assert_eq!(appended.oracle(new_ids[d]).id(), new_ids[d]);
+	}
+
+	#[test]
+	#[should_panic(expected = "was removed")]
+	fn test_removed_oracle_accessor_panics() {
+		let mut oracles = MultilinearOracleSet::<BinaryField128b>::new();
+		let id = oracles.add_committed(4, BinaryField1b::TOWER_LEVEL);
+		oracles.remove_oracle(id).unwrap();
+		oracles.n_vars(id);
+	}
 }
@@ -291,6 +291,18 @@ pub fn composition_hash<P: PackedField, C: CompositionPoly<P>>(composition: &C)
 		.expect("Failed to evaluate composition")
 }
 
+/// Returns the point of the `n_vars`-dimensional boolean hypercube with the given index.
+///
+/// Bit `k` of `index` is the coordinate of variable `k`.
+pub fn decompose_index_to_hypercube_point<F: Field>(n_vars: usize, index: usize) -> Vec<F> {
+	(0..n_vars)
+		.map(|k| match (index >> k) & 1 {
+			0 => F::ZERO,
+			_ => F::ONE,
+		})
+		.collect()
+}
+
 #[cfg(test)]
 mod tests {
 	use binius_math::{ArithExpr, CompositionPoly};
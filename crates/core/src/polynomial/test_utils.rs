@@ -2,6 +2,7 @@
 
 use binius_field::{packed::set_packed_slice, BinaryField1b, Field, PackedField};
 
+pub use crate::polynomial::decompose_index_to_hypercube_point;
 use crate::polynomial::MultivariatePoly;
 
 pub fn hypercube_evals_from_oracle<F: Field>(oracle: &dyn MultivariatePoly<F>) -> Vec<F> {
@@ -14,15 +15,6 @@ pub fn hypercube_evals_from_oracle<F: Field>(oracle: &dyn MultivariatePoly<F>) -
 		.collect()
 }
 
-pub fn decompose_index_to_hypercube_point<F: Field>(n_vars: usize, index: usize) -> Vec<F> {
-	(0..n_vars)
-		.map(|k| match (index >> k) & 1 {
-			0 => F::ZERO,
-			_ => F::ONE,
-		})
-		.collect()
-}
-
 pub fn packed_slice<P>(assignments: &[(std::ops::Range<usize>, u8)]) -> Vec<P>
 where
 	P: PackedField<Scalar = BinaryField1b>,
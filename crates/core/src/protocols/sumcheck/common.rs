@@ -87,6 +87,18 @@ where
 	pub fn composite_sums(&self) -> &[CompositeSumClaim<F, Composition>] {
 		&self.composite_sums
 	}
+
+	/// Returns the number of multilinear polynomials the composite polynomials are evaluated on.
+	///
+	/// This is also the number of multilinear evaluations output by the sumcheck for this claim.
+	pub const fn n_polys(&self) -> usize {
+		self.n_multilinears
+	}
+
+	/// Returns the composite sum claims batched into this sumcheck claim.
+	pub fn composite_sum_claims(&self) -> &[CompositeSumClaim<F, Composition>] {
+		&self.composite_sums
+	}
 }
 
 /// A univariate polynomial in monomial basis.
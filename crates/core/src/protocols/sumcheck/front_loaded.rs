@@ -89,7 +89,7 @@ where
 				batch_weighted_value(
 					batch_coeff,
 					claim
-						.composite_sums()
+						.composite_sum_claims()
 						.iter()
 						.map(|composite_claim| composite_claim.sum),
 				)
@@ -99,7 +99,7 @@ where
 		let mut claims = iter::zip(claims.iter().cloned(), batch_coeffs)
 			.map(|(claim, batch_coeff)| {
 				let degree = claim
-					.composite_sums()
+					.composite_sum_claims()
 					.iter()
 					.map(|composite_claim| composite_claim.composition.degree())
 					.max()
@@ -146,7 +146,7 @@ where
 				let SumcheckClaimWithContext {
 					claim, batch_coeff, ..
 				} = self.claims.pop_front().expect("front returned Some");
-				let multilinear_evals = transcript.read_scalar_slice(claim.n_multilinears())?;
+				let multilinear_evals = transcript.read_scalar_slice(claim.n_polys())?;
 
 				match self.last_coeffs_or_sum {
 					CoeffsOrSums::Coeffs(_) => {
@@ -88,6 +88,7 @@ fn split_constraint_set<F: Field>(
 		oracle_ids,
 		constraints,
 		n_vars,
+		..
 	} = constraint_set;
 	let meta = OracleClaimMeta { n_vars, oracle_ids };
 	(constraints, meta)
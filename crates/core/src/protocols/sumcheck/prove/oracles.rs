@@ -176,6 +176,7 @@ where
 		oracle_ids,
 		constraints,
 		n_vars,
+		..
 	} = constraint_set;
 
 	let multilinears = oracle_ids
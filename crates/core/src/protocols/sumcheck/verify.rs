@@ -115,7 +115,7 @@ where
 			sum += batch_weighted_value(
 				next_batch_coeff,
 				claim
-					.composite_sums()
+					.composite_sum_claims()
 					.iter()
 					.map(|inner_claim| inner_claim.sum),
 			);
@@ -143,7 +143,7 @@ where
 		sum += batch_weighted_value(
 			next_batch_coeff,
 			claim
-				.composite_sums()
+				.composite_sum_claims()
 				.iter()
 				.map(|inner_claim| inner_claim.sum),
 		);
@@ -153,7 +153,7 @@ where
 	let mut multilinear_evals = Vec::with_capacity(claims.len());
 	let mut reader = transcript.message();
 	for claim in claims {
-		let evals = reader.read_scalar_slice::<F>(claim.n_multilinears())?;
+		let evals = reader.read_scalar_slice::<F>(claim.n_polys())?;
 		multilinear_evals.push(evals);
 	}
 
@@ -186,7 +186,7 @@ where
 	Composition: CompositionPoly<F>,
 {
 	let composite_evals = claim
-		.composite_sums()
+		.composite_sum_claims()
 		.iter()
 		.map(|sum_claim| sum_claim.composition.evaluate(multilinear_evals))
 		.collect::<Result<Vec<_>, _>>()?;
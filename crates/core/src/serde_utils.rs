@@ -0,0 +1,36 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Helpers for the [`serde`] implementations enabled by the `serde` feature.
+//!
+//! Field elements and other leaf values do not implement the [`serde`] traits. They are instead
+//! stored as the bytes of their [`SerializeBytes`] encoding in
+//! [`SerializationMode::CanonicalTower`] mode.
+
+use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+pub(crate) const MODE: SerializationMode = SerializationMode::CanonicalTower;
+
+/// Serializes a value as its byte encoding, for use with `#[serde(with = "...")]`.
+pub(crate) mod bytes {
+	use super::*;
+
+	pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		T: SerializeBytes,
+		S: Serializer,
+	{
+		let mut bytes = Vec::new();
+		SerializeBytes::serialize(value, &mut bytes, MODE).map_err(ser::Error::custom)?;
+		Serialize::serialize(&bytes, serializer)
+	}
+
+	pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+	where
+		T: DeserializeBytes,
+		D: Deserializer<'de>,
+	{
+		let bytes = <Vec<u8> as Deserialize>::deserialize(deserializer)?;
+		DeserializeBytes::deserialize(bytes.as_slice(), MODE).map_err(de::Error::custom)
+	}
+}
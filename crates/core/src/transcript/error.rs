@@ -4,6 +4,17 @@
 pub enum Error {
 	#[error("Transcript is not empty, {remaining} bytes")]
 	TranscriptNotEmpty { remaining: usize },
+	#[error("Malformed transcript at byte {position}: expected {expected}, got {got}")]
+	TranscriptParseError {
+		position: usize,
+		expected: String,
+		got: String,
+	},
+	#[error("Malformed advice at byte {position}: {detail}")]
+	AdviceParseError { position: usize, detail: String },
+	#[deprecated(
+		note = "the readers report a truncated transcript as TranscriptParseError or AdviceParseError"
+	)]
 	#[error("Not enough bytes in the buffer")]
 	NotEnoughBytes,
 	#[error("Serialization error: {0}")]
@@ -14,7 +14,12 @@
 
 mod error;
 
-use std::{iter::repeat_with, slice};
+use std::{
+	any::type_name,
+	io::{self, Write},
+	iter::repeat_with,
+	slice,
+};
 
 use binius_field::{PackedField, TowerField};
 use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
@@ -41,6 +46,7 @@ pub struct ProverTranscript<Challenger> {
 #[derive(Debug)]
 pub struct VerifierTranscript<Challenger> {
 	combined: FiatShamirBuf<Bytes, Challenger>,
+	tape_len: usize,
 	debug_assertions: bool,
 }
 
@@ -122,6 +128,20 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 		self.combined.buffer.to_vec()
 	}
 
+	/// Writes the bytes of the proof tape written since the last call to `writer`, and removes
+	/// them from the tape.
+	///
+	/// The Fiat-Shamir state is unaffected, so the concatenation of the bytes written by all the
+	/// calls and by [`Self::finalize`] is the full proof tape. Returns the number of bytes
+	/// written.
+	pub fn write_pending(&mut self, writer: &mut impl Write) -> io::Result<usize> {
+		let pending = &mut self.combined.buffer;
+		writer.write_all(pending)?;
+		let len = pending.len();
+		pending.clear();
+		Ok(len)
+	}
+
 	/// Sets the debug flag.
 	///
 	/// This flag is used to enable debug assertions in the [`TranscriptReader`] and
@@ -175,6 +195,7 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 impl<Challenger_: Default + Challenger> VerifierTranscript<Challenger_> {
 	pub fn new(vec: Vec<u8>) -> Self {
 		Self {
+			tape_len: vec.len(),
 			combined: FiatShamirBuf {
 				challenger: Challenger_::default(),
 				buffer: Bytes::from(vec),
@@ -218,6 +239,8 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 	pub fn decommitment(&mut self) -> TranscriptReader<impl Buf + '_> {
 		TranscriptReader {
 			buffer: &mut self.combined.buffer,
+			tape_len: self.tape_len,
+			advice: true,
 			debug_assertions: self.debug_assertions,
 		}
 	}
@@ -231,6 +254,8 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 	{
 		TranscriptReader {
 			buffer: &mut self.combined,
+			tape_len: self.tape_len,
+			advice: false,
 			debug_assertions: self.debug_assertions,
 		}
 	}
@@ -250,6 +275,10 @@ impl<Challenger> Drop for VerifierTranscript<Challenger> {
 
 pub struct TranscriptReader<'a, B: Buf> {
 	buffer: &'a mut B,
+	/// Length of the whole proof tape, used to report the position of parse errors.
+	tape_len: usize,
+	/// Whether the reader only reads advice, without observing it.
+	advice: bool,
 	debug_assertions: bool,
 }
 
@@ -258,25 +287,48 @@ impl<B: Buf> TranscriptReader<'_, B> {
 		self.buffer
 	}
 
+	/// Returns the byte offset of the reader in the proof tape.
+	pub fn position(&self) -> usize {
+		self.tape_len - self.buffer.remaining()
+	}
+
+	fn parse_error(&self, position: usize, expected: impl ToString, got: impl ToString) -> Error {
+		if self.advice {
+			Error::AdviceParseError {
+				position,
+				detail: format!("expected {}, got {}", expected.to_string(), got.to_string()),
+			}
+		} else {
+			Error::TranscriptParseError {
+				position,
+				expected: expected.to_string(),
+				got: got.to_string(),
+			}
+		}
+	}
+
 	pub fn read<T: DeserializeBytes>(&mut self) -> Result<T, Error> {
 		let mode = SerializationMode::CanonicalTower;
-		T::deserialize(self.buffer(), mode).map_err(Into::into)
+		// A failed read may have consumed part of the value, so the error reports where it starts.
+		let position = self.position();
+		T::deserialize(self.buffer(), mode)
+			.map_err(|err| self.parse_error(position, type_name::<T>(), err))
 	}
 
 	pub fn read_vec<T: DeserializeBytes>(&mut self, n: usize) -> Result<Vec<T>, Error> {
-		let mode = SerializationMode::CanonicalTower;
-		let mut buffer = self.buffer();
-		repeat_with(move || T::deserialize(&mut buffer, mode).map_err(Into::into))
-			.take(n)
-			.collect()
+		repeat_with(|| self.read()).take(n).collect()
 	}
 
 	pub fn read_bytes(&mut self, buf: &mut [u8]) -> Result<(), Error> {
-		let buffer = self.buffer();
-		if buffer.remaining() < buf.len() {
-			return Err(Error::NotEnoughBytes);
+		let remaining = self.buffer().remaining();
+		if remaining < buf.len() {
+			return Err(self.parse_error(
+				self.position(),
+				format!("{} bytes", buf.len()),
+				format!("{remaining} remaining bytes"),
+			));
 		}
-		buffer.copy_to_slice(buf);
+		self.buffer().copy_to_slice(buf);
 		Ok(())
 	}
 
@@ -287,10 +339,8 @@ impl<B: Buf> TranscriptReader<'_, B> {
 	}
 
 	pub fn read_scalar_slice_into<F: TowerField>(&mut self, buf: &mut [F]) -> Result<(), Error> {
-		let mut buffer = self.buffer();
 		for elem in buf {
-			let mode = SerializationMode::CanonicalTower;
-			*elem = DeserializeBytes::deserialize(&mut buffer, mode)?;
+			*elem = self.read()?;
 		}
 		Ok(())
 	}
@@ -490,6 +540,31 @@ mod tests {
 	use super::*;
 	use crate::fiat_shamir::HasherChallenger;
 
+	#[test]
+	fn test_write_pending() {
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let mut streamed = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let mut output = Vec::new();
+
+		for (i, value) in [0x96u8, 0x52, 0x17].into_iter().enumerate() {
+			transcript.message().write_scalar(BinaryField8b::new(value));
+			streamed.message().write_scalar(BinaryField8b::new(value));
+			let _: BinaryField128b = transcript.sample();
+			let _: BinaryField128b = streamed.sample();
+			// The second message stays pending until after the third one.
+			match i {
+				0 => assert_eq!(streamed.write_pending(&mut output).unwrap(), 1),
+				2 => assert_eq!(streamed.write_pending(&mut output).unwrap(), 2),
+				_ => {}
+			}
+		}
+		let challenge: BinaryField128b = transcript.sample();
+		assert_eq!(CanSample::<BinaryField128b>::sample(&mut streamed), challenge);
+
+		output.extend(streamed.finalize());
+		assert_eq!(output, transcript.finalize());
+	}
+
 	#[test]
 	fn test_transcripting() {
 		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
@@ -663,4 +738,33 @@ mod tests {
 			.message()
 			.read_debug("test_transcript_debug_should_fail");
 	}
+
+	#[test]
+	fn test_transcript_parse_errors() {
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		transcript
+			.message()
+			.write_scalar(BinaryField32b::new(0xDEADBEEF));
+		transcript.decommitment().write_bytes(&[1, 2]);
+		let mut transcript = transcript.into_verifier();
+
+		let _: BinaryField32b = transcript.message().read_scalar().unwrap();
+		assert_matches::assert_matches!(
+			transcript.decommitment().read_vec::<u32>(1),
+			Err(Error::AdviceParseError { position: 4, .. })
+		);
+
+		let mut transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(vec![0; 3]);
+		assert_matches::assert_matches!(
+			transcript.message().read_scalar::<BinaryField32b>(),
+			Err(Error::TranscriptParseError { position: 0, .. })
+		);
+
+		// The position is the start of the value, even if a prefix of it was read.
+		let mut transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(vec![0; 6]);
+		assert_matches::assert_matches!(
+			transcript.message().read::<(u32, u32)>(),
+			Err(Error::TranscriptParseError { position: 0, .. })
+		);
+	}
 }
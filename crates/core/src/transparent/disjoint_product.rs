@@ -1,13 +1,59 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use binius_field::Field;
-use binius_utils::bail;
+use std::sync::Arc;
+
+use binius_field::{BinaryField128b, Field};
+use binius_macros::{DeserializeBytes, SerializeBytes};
+use binius_utils::{bail, DeserializeBytes, SerializationError, SerializationMode, SerializeBytes};
+use bytes::BufMut;
 
 use crate::polynomial::{Error, MultivariatePoly};
 
+/// Which factor of a [`DisjointProduct`] is evaluated over the low variables of the query.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+pub enum ProductOrdering {
+	/// The first `p0.n_vars()` query variables are passed to `p0`, the rest to `p1`.
+	#[default]
+	P0First,
+	/// The first `p1.n_vars()` query variables are passed to `p1`, the rest to `p0`.
+	P1First,
+}
+
 /// Represents a product of two multilinear polynomials over disjoint variables.
+///
+/// The factors are serialized as type-erased polynomials, so a deserialized product holds them as
+/// `Arc<dyn MultivariatePoly<_>>`.
 #[derive(Debug)]
-pub struct DisjointProduct<P0, P1>(pub P0, pub P1);
+pub struct DisjointProduct<P0, P1>(pub P0, pub P1, ProductOrdering);
+
+inventory::submit! {
+	<dyn MultivariatePoly<BinaryField128b>>::register_deserializer(
+		"DisjointProduct",
+		|buf, mode| {
+			let p0: Arc<dyn MultivariatePoly<BinaryField128b>> =
+				Box::<dyn MultivariatePoly<BinaryField128b>>::deserialize(&mut *buf, mode)?.into();
+			let p1: Arc<dyn MultivariatePoly<BinaryField128b>> =
+				Box::<dyn MultivariatePoly<BinaryField128b>>::deserialize(&mut *buf, mode)?.into();
+			let ordering = ProductOrdering::deserialize(&mut *buf, mode)?;
+			Ok(Box::new(DisjointProduct::new(p0, p1).with_ordering(ordering)))
+		}
+	)
+}
+
+impl<P0, P1> DisjointProduct<P0, P1> {
+	pub const fn new(p0: P0, p1: P1) -> Self {
+		Self(p0, p1, ProductOrdering::P0First)
+	}
+
+	pub fn with_ordering(mut self, ordering: ProductOrdering) -> Self {
+		self.2 = ordering;
+		self
+	}
+
+	pub const fn ordering(&self) -> ProductOrdering {
+		self.2
+	}
+}
 
 impl<F: Field, P0, P1> MultivariatePoly<F> for DisjointProduct<P0, P1>
 where
@@ -31,12 +77,75 @@ where
 			bail!(Error::IncorrectQuerySize { expected: n_vars });
 		}
 
-		let eval0 = self.0.evaluate(&query[..p0_vars])?;
-		let eval1 = self.1.evaluate(&query[p0_vars..])?;
+		let (query0, query1) = match self.2 {
+			ProductOrdering::P0First => {
+				let (query0, query1) = query.split_at(p0_vars);
+				(query0, query1)
+			}
+			ProductOrdering::P1First => {
+				let (query1, query0) = query.split_at(p1_vars);
+				(query0, query1)
+			}
+		};
+		let eval0 = self.0.evaluate(query0)?;
+		let eval1 = self.1.evaluate(query1)?;
 		Ok(eval0 * eval1)
 	}
 
 	fn binary_tower_level(&self) -> usize {
 		self.0.binary_tower_level().max(self.1.binary_tower_level())
 	}
+
+	// Not generated with `#[erased_serialize_bytes]`, because the factors must be written with
+	// their own type names for the deserializer to rebuild them.
+	fn erased_serialize(
+		&self,
+		write_buf: &mut dyn BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		SerializeBytes::serialize(&"DisjointProduct", &mut *write_buf, mode)?;
+		self.0.erased_serialize(&mut *write_buf, mode)?;
+		self.1.erased_serialize(&mut *write_buf, mode)?;
+		SerializeBytes::serialize(&self.2, &mut *write_buf, mode)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField128b;
+
+	use super::{DisjointProduct, ProductOrdering};
+	use crate::{
+		polynomial::MultivariatePoly,
+		transparent::{eq_ind::EqIndPartialEval, select_row::SelectRow},
+	};
+
+	#[test]
+	fn test_evaluate_with_ordering() {
+		type F = BinaryField128b;
+		let eq = EqIndPartialEval::new(vec![F::new(3), F::new(5)]);
+		let select = SelectRow::new(1, 1).unwrap();
+		let query = [F::new(7), F::new(11), F::new(13)];
+
+		let p0_first = DisjointProduct::new(eq.clone(), select.clone());
+		assert_eq!(MultivariatePoly::<F>::n_vars(&p0_first), 3);
+		assert_eq!(
+			MultivariatePoly::<F>::evaluate(&p0_first, &query).unwrap(),
+			eq.evaluate(&query[..2]).unwrap() * select.evaluate(&query[2..]).unwrap()
+		);
+
+		let p1_first = DisjointProduct::new(eq.clone(), select.clone())
+			.with_ordering(ProductOrdering::P1First);
+		assert_eq!(MultivariatePoly::<F>::n_vars(&p1_first), 3);
+		assert_eq!(
+			MultivariatePoly::<F>::evaluate(&p1_first, &query).unwrap(),
+			select.evaluate(&query[..1]).unwrap() * eq.evaluate(&query[1..]).unwrap()
+		);
+		assert_ne!(
+			MultivariatePoly::<F>::evaluate(&p0_first, &query).unwrap(),
+			MultivariatePoly::<F>::evaluate(&p1_first, &query).unwrap()
+		);
+
+		assert!(MultivariatePoly::<F>::evaluate(&p1_first, &query[..2]).is_err());
+	}
 }
@@ -1,9 +1,10 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use binius_field::{Field, PackedField, TowerField};
+use binius_field::{BinaryField128b, Field, PackedField, TowerField};
 use binius_hal::ComputationBackend;
-use binius_math::MultilinearExtension;
-use binius_utils::bail;
+use binius_macros::{erased_serialize_bytes, DeserializeBytes, SerializeBytes};
+use binius_math::{eq_ind_partial_eval, MultilinearExtension};
+use binius_utils::{bail, DeserializeBytes};
 
 use crate::polynomial::{Error, MultivariatePoly};
 
@@ -26,11 +27,18 @@ use crate::polynomial::{Error, MultivariatePoly};
 /// $$
 /// \text{eq}(X, Y) = \prod_{i=0}^{\mu - 1} \left(X_i Y_i + (1 - X_i)(1 - Y_i)\right).
 /// $$
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
 pub struct EqIndPartialEval<F: Field> {
 	r: Vec<F>,
 }
 
+inventory::submit! {
+	<dyn MultivariatePoly<BinaryField128b>>::register_deserializer(
+		"EqIndPartialEval",
+		|buf, mode| Ok(Box::new(EqIndPartialEval::<BinaryField128b>::deserialize(&mut *buf, mode)?))
+	)
+}
+
 impl<F: Field> EqIndPartialEval<F> {
 	pub fn new(r: impl Into<Vec<F>>) -> Self {
 		Self { r: r.into() }
@@ -47,8 +55,35 @@ impl<F: Field> EqIndPartialEval<F> {
 		let multilin_query = backend.tensor_product_full_query(&self.r)?;
 		Ok(MultilinearExtension::new(self.n_vars(), multilin_query)?)
 	}
+
+	/// Materializes the evaluations of $\text{eq}(X, r)$ over the whole boolean hypercube.
+	pub fn to_mle(&self) -> MultilinearExtension<F> {
+		MultilinearExtension::new(self.n_vars(), eq_ind_partial_eval(&self.r))
+			.expect("eq_ind_partial_eval returns 2^n_vars evaluations")
+	}
+
+	/// Evaluates $\text{eq}(x, r)$ at the hypercube vertex $x$ whose coordinates are the bits of
+	/// `index`, least significant first.
+	///
+	/// ## Preconditions
+	///
+	/// * `index` must be less than `2^n_vars`
+	pub fn evaluate_on_hypercube(&self, index: usize) -> F {
+		self.r
+			.iter()
+			.enumerate()
+			.map(|(i, &r_i)| {
+				if (index >> i) & 1 == 1 {
+					r_i
+				} else {
+					F::ONE - r_i
+				}
+			})
+			.product()
+	}
 }
 
+#[erased_serialize_bytes]
 impl<F: TowerField, P: PackedField<Scalar = F>> MultivariatePoly<P> for EqIndPartialEval<F> {
 	fn n_vars(&self) -> usize {
 		self.r.len()
@@ -116,6 +151,35 @@ mod tests {
 		assert_eq!(eval_mle, eval_mvp);
 	}
 
+	#[test]
+	fn test_to_mle_matches_evaluate_on_hypercube() {
+		type F = BinaryField32b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let r = repeat_with(|| F::random(&mut rng))
+			.take(5)
+			.collect::<Vec<_>>();
+		let eq_r = EqIndPartialEval::new(r);
+
+		let mle = eq_r.to_mle();
+		assert_eq!(mle.n_vars(), 5);
+		for index in 0..1 << 5 {
+			let expected = eq_r.evaluate_on_hypercube(index);
+			assert_eq!(mle.evaluate_on_hypercube(index).unwrap(), expected);
+
+			let vertex = (0..5)
+				.map(|i| {
+					if (index >> i) & 1 == 1 {
+						F::one()
+					} else {
+						F::zero()
+					}
+				})
+				.collect::<Vec<_>>();
+			assert_eq!(eq_r.evaluate(&vertex).unwrap(), expected);
+		}
+	}
+
 	#[test]
 	fn test_eq_consistency_schwartz_zippel() {
 		for n_vars in 2..=10 {
@@ -0,0 +1,237 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{collections::BTreeSet, sync::Arc};
+
+use binius_field::{BinaryField128b, Field, PackedField};
+use binius_macros::erased_serialize_bytes;
+use binius_math::MultilinearExtension;
+use binius_utils::{bail, DeserializeBytes, SerializationError, SerializationMode, SerializeBytes};
+
+use crate::polynomial::{Error, MultivariatePoly};
+
+/// Represents a multilinear F2-polynomial whose evaluations over the hypercube are 1 at an
+/// arbitrary subset of hypercube indices, and 0 everywhere else.
+///
+/// ```txt
+///     (1 << n_vars)
+/// <-------------------->
+/// 0,1,0,0,1,1,0, .. 0,1
+///   ^     ^ ^         ^
+///   indices of 1
+/// ```
+///
+/// This generalizes [`SelectRow`](super::select_row::SelectRow) to multiple rows. Evaluation at
+/// an arbitrary point costs one equality indicator evaluation per selected index, so it is
+/// intended for sparse subsets.
+#[derive(Debug, Clone)]
+pub struct Indicator {
+	n_vars: usize,
+	indices: Arc<BTreeSet<usize>>,
+}
+
+inventory::submit! {
+	<dyn MultivariatePoly<BinaryField128b>>::register_deserializer(
+		"Indicator",
+		|buf, mode| Ok(Box::new(Indicator::deserialize(&mut *buf, mode)?))
+	)
+}
+
+impl Indicator {
+	pub fn new(n_vars: usize, indices: impl IntoIterator<Item = usize>) -> Result<Self, Error> {
+		let indices = indices.into_iter().collect::<BTreeSet<_>>();
+		if let Some(&last) = indices.last() {
+			if last >= 1 << n_vars {
+				bail!(Error::ArgumentRangeError {
+					arg: "indices".into(),
+					range: 0..(1 << n_vars),
+				})
+			}
+		}
+		Ok(Self {
+			n_vars,
+			indices: Arc::new(indices),
+		})
+	}
+
+	/// Creates an indicator of the contiguous range of hypercube indices `start..end`.
+	pub fn range(n_vars: usize, start: usize, end: usize) -> Result<Self, Error> {
+		if start > end || end > 1 << n_vars {
+			bail!(Error::ArgumentRangeError {
+				arg: "end".into(),
+				range: start..(1 << n_vars) + 1,
+			})
+		}
+		Self::new(n_vars, start..end)
+	}
+
+	pub const fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	pub fn indices(&self) -> &BTreeSet<usize> {
+		&self.indices
+	}
+
+	pub fn multilinear_extension<P: PackedField>(&self) -> Result<MultilinearExtension<P>, Error> {
+		let log_packed_length = self.n_vars.saturating_sub(P::LOG_WIDTH);
+		let mut data = vec![P::zero(); 1 << log_packed_length];
+		self.populate(&mut data);
+		Ok(MultilinearExtension::new(self.n_vars, data)?)
+	}
+
+	pub fn populate<P: PackedField>(&self, data: &mut [P]) {
+		data.fill(P::zero());
+		for &index in self.indices.iter() {
+			data[index / P::WIDTH].set(index % P::WIDTH, P::Scalar::ONE);
+		}
+	}
+}
+
+impl SerializeBytes for Indicator {
+	fn serialize(
+		&self,
+		mut write_buf: impl bytes::BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		SerializeBytes::serialize(&self.n_vars, &mut write_buf, mode)?;
+		let indices = self.indices.iter().copied().collect::<Vec<_>>();
+		SerializeBytes::serialize(&indices, write_buf, mode)
+	}
+}
+
+impl DeserializeBytes for Indicator {
+	fn deserialize(
+		mut read_buf: impl bytes::Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError>
+	where
+		Self: Sized,
+	{
+		let n_vars = usize::deserialize(&mut read_buf, mode)?;
+		let indices = Vec::<usize>::deserialize(read_buf, mode)?;
+		Self::new(n_vars, indices)
+			.map_err(|_| SerializationError::InvalidConstruction { name: "Indicator" })
+	}
+}
+
+#[erased_serialize_bytes]
+impl<F: Field> MultivariatePoly<F> for Indicator {
+	fn degree(&self) -> usize {
+		self.n_vars
+	}
+
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn evaluate(&self, query: &[F]) -> Result<F, Error> {
+		let n_vars = MultivariatePoly::<F>::n_vars(self);
+		if query.len() != n_vars {
+			bail!(Error::IncorrectQuerySize { expected: n_vars });
+		}
+		let result = self
+			.indices
+			.iter()
+			.map(|&index| {
+				query
+					.iter()
+					.enumerate()
+					.map(|(i, &q)| if (index >> i) & 1 == 1 { q } else { F::ONE - q })
+					.product::<F>()
+			})
+			.sum();
+		Ok(result)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, BinaryField1b, Field, PackedBinaryField128x1b};
+	use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::Indicator;
+	use crate::polynomial::{
+		test_utils::{decompose_index_to_hypercube_point, hypercube_evals_from_oracle},
+		MultivariatePoly,
+	};
+
+	#[test]
+	fn test_indicator_hypercube_evals() {
+		let indices = [1, 4, 5, 13];
+		let indicator = Indicator::new(4, indices).unwrap();
+		let evals = hypercube_evals_from_oracle::<BinaryField1b>(&indicator);
+		for (i, eval) in evals.into_iter().enumerate() {
+			let expected = if indices.contains(&i) {
+				BinaryField1b::ONE
+			} else {
+				BinaryField1b::ZERO
+			};
+			assert_eq!(eval, expected);
+		}
+	}
+
+	#[test]
+	fn test_indicator_sparse_evaluation() {
+		type F = BinaryField128b;
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 6;
+		let indices = [0, 7, 33, 63];
+		let indicator = Indicator::new(n_vars, indices).unwrap();
+
+		// The indicator is the sum of the equality indicators of its selected points.
+		let query = (0..n_vars).map(|_| F::random(&mut rng)).collect::<Vec<_>>();
+		let expected = indices
+			.iter()
+			.map(|&index| {
+				let point = decompose_index_to_hypercube_point::<F>(n_vars, index);
+				query
+					.iter()
+					.zip(point)
+					.map(|(&q, p)| q * p + (F::ONE - q) * (F::ONE - p))
+					.product::<F>()
+			})
+			.sum::<F>();
+		assert_eq!(indicator.evaluate(&query).unwrap(), expected);
+	}
+
+	#[test]
+	fn test_indicator_range() {
+		let indicator = Indicator::range(5, 3, 9).unwrap();
+		assert_eq!(
+			indicator.indices().iter().copied().collect::<Vec<_>>(),
+			(3..9).collect::<Vec<_>>()
+		);
+		assert!(Indicator::range(5, 3, 33).is_err());
+		assert!(Indicator::new(5, [32]).is_err());
+	}
+
+	#[test]
+	fn test_consistency_between_multilinear_extension_and_multilinear_poly_oracle() {
+		let indicator = Indicator::new(8, [0, 100, 127, 128, 255]).unwrap();
+		let mle = indicator
+			.multilinear_extension::<PackedBinaryField128x1b>()
+			.unwrap();
+		let evals = hypercube_evals_from_oracle::<BinaryField1b>(&indicator);
+		for (i, eval) in evals.into_iter().enumerate() {
+			assert_eq!(mle.evaluate_on_hypercube(i).unwrap(), eval);
+		}
+	}
+
+	#[test]
+	fn test_serialization_roundtrip() {
+		let indicator = Indicator::new(6, [2, 3, 40]).unwrap();
+		let mut buf = Vec::new();
+		indicator
+			.serialize(&mut buf, SerializationMode::CanonicalTower)
+			.unwrap();
+		let deserialized =
+			Indicator::deserialize(buf.as_slice(), SerializationMode::CanonicalTower).unwrap();
+		assert_eq!(deserialized.n_vars(), indicator.n_vars());
+		assert_eq!(deserialized.indices(), indicator.indices());
+	}
+}
@@ -3,6 +3,7 @@
 pub mod constant;
 pub mod disjoint_product;
 pub mod eq_ind;
+pub mod indicator;
 pub mod multilinear_extension;
 pub mod powers;
 pub mod select_row;
@@ -41,15 +41,22 @@ where
 {
 	fn serialize(
 		&self,
-		write_buf: impl bytes::BufMut,
+		mut write_buf: impl bytes::BufMut,
 		mode: SerializationMode,
 	) -> Result<(), SerializationError> {
-		let elems = PE::iter_slice(
-			self.data
-				.packed_evals()
-				.expect("Evals should always be available here"),
-		)
-		.collect::<Vec<_>>();
+		// The packed evaluations may be padded beyond the hypercube, so the number of variables
+		// is written alongside the evaluations on the hypercube.
+		let n_vars = self.data.n_vars();
+		let elems = (0..1 << n_vars)
+			.map(|index| {
+				self.data
+					.evaluate_on_hypercube(index)
+					.expect("index is on the hypercube")
+			})
+			.collect::<Vec<_>>();
+		let tower_level = PE::Scalar::TOWER_LEVEL - self.data.log_extension_degree();
+		SerializeBytes::serialize(&n_vars, &mut write_buf, mode)?;
+		SerializeBytes::serialize(&tower_level, &mut write_buf, mode)?;
 		SerializeBytes::serialize(&elems, write_buf, mode)
 	}
 }
@@ -59,31 +66,55 @@ inventory::submit! {
 		"MultilinearExtensionTransparent",
 		|buf, mode| {
 			type U = OptimalUnderlier;
-			type F = BinaryField128b;
-			type P = PackedType<U, F>;
-			let hypercube_evals = Vec::<F>::deserialize(&mut *buf, mode)?;
-			let result: Box<dyn MultivariatePoly<F>> = if let Some(packed_evals) = try_pack_slice(&hypercube_evals) {
-				Box::new(MultilinearExtensionTransparent::<PackedType<U, BinaryField1b>, P, _>::from_values(packed_evals).unwrap())
-			} else if let Some(packed_evals) = try_pack_slice(&hypercube_evals) {
-				Box::new(MultilinearExtensionTransparent::<PackedType<U, BinaryField2b>, P, _>::from_values(packed_evals).unwrap())
-			} else if let Some(packed_evals) = try_pack_slice(&hypercube_evals) {
-				Box::new(MultilinearExtensionTransparent::<PackedType<U, BinaryField4b>, P, _>::from_values(packed_evals).unwrap())
-			} else if let Some(packed_evals) = try_pack_slice(&hypercube_evals) {
-				Box::new(MultilinearExtensionTransparent::<PackedType<U, BinaryField8b>, P, _>::from_values(packed_evals).unwrap())
-			} else if let Some(packed_evals) = try_pack_slice(&hypercube_evals) {
-				Box::new(MultilinearExtensionTransparent::<PackedType<U, BinaryField16b>, P, _>::from_values(packed_evals).unwrap())
-			} else if let Some(packed_evals) = try_pack_slice(&hypercube_evals) {
-				Box::new(MultilinearExtensionTransparent::<PackedType<U, BinaryField32b>, P, _>::from_values(packed_evals).unwrap())
-			} else if let Some(packed_evals) = try_pack_slice(&hypercube_evals) {
-				Box::new(MultilinearExtensionTransparent::<PackedType<U, BinaryField64b>, P, _>::from_values(packed_evals).unwrap())
-			} else {
-				Box::new(MultilinearExtensionTransparent::<P, P, _>::from_values(pack_slice(&hypercube_evals)).unwrap())
-			};
-			Ok(result)
+			let n_vars = usize::deserialize(&mut *buf, mode)?;
+			let tower_level = usize::deserialize(&mut *buf, mode)?;
+			let hypercube_evals = Vec::<BinaryField128b>::deserialize(&mut *buf, mode)?;
+			if u32::try_from(n_vars).ok().and_then(|n_vars| 1usize.checked_shl(n_vars))
+				!= Some(hypercube_evals.len())
+			{
+				return Err(invalid_construction());
+			}
+			match tower_level {
+				0 => erased_from_evals::<PackedType<U, BinaryField1b>>(&hypercube_evals, n_vars),
+				1 => erased_from_evals::<PackedType<U, BinaryField2b>>(&hypercube_evals, n_vars),
+				2 => erased_from_evals::<PackedType<U, BinaryField4b>>(&hypercube_evals, n_vars),
+				3 => erased_from_evals::<PackedType<U, BinaryField8b>>(&hypercube_evals, n_vars),
+				4 => erased_from_evals::<PackedType<U, BinaryField16b>>(&hypercube_evals, n_vars),
+				5 => erased_from_evals::<PackedType<U, BinaryField32b>>(&hypercube_evals, n_vars),
+				6 => erased_from_evals::<PackedType<U, BinaryField64b>>(&hypercube_evals, n_vars),
+				7 => erased_from_evals::<PackedType<U, BinaryField128b>>(&hypercube_evals, n_vars),
+				_ => Err(invalid_construction()),
+			}
 		}
 	)
 }
 
+/// Packs the evaluations on the hypercube into the packed field `PS`, in which they must lie.
+fn erased_from_evals<PS>(
+	hypercube_evals: &[BinaryField128b],
+	n_vars: usize,
+) -> Result<Box<dyn MultivariatePoly<BinaryField128b>>, SerializationError>
+where
+	PS: PackedField,
+	BinaryField128b: ExtensionField<PS::Scalar>,
+	PackedType<OptimalUnderlier, BinaryField128b>: RepackedExtension<PS>,
+{
+	let packed_evals = try_pack_slice::<PS, _>(hypercube_evals).ok_or_else(invalid_construction)?;
+	let mle = MultilinearExtensionTransparent::<
+		PS,
+		PackedType<OptimalUnderlier, BinaryField128b>,
+		_,
+	>::from_values_and_mu(packed_evals, n_vars)
+	.map_err(|_| invalid_construction())?;
+	Ok(Box::new(mle))
+}
+
+const fn invalid_construction() -> SerializationError {
+	SerializationError::InvalidConstruction {
+		name: "MultilinearExtensionTransparent",
+	}
+}
+
 fn try_pack_slice<PS, F>(xs: &[F]) -> Option<Vec<PS>>
 where
 	PS: PackedField,
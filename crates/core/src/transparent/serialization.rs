@@ -80,3 +80,67 @@ type ErasedDeserializeBytes<F> = fn(
 	&mut dyn bytes::Buf,
 	mode: SerializationMode,
 ) -> Result<Box<dyn MultivariatePoly<F>>, SerializationError>;
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{
+		arch::OptimalUnderlier, as_packed_field::PackedType, packed::pack_slice, BinaryField128b,
+		BinaryField8b, Field,
+	};
+	use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use crate::{
+		polynomial::MultivariatePoly,
+		transparent::{
+			constant::Constant,
+			disjoint_product::{DisjointProduct, ProductOrdering},
+			eq_ind::EqIndPartialEval,
+			multilinear_extension::MultilinearExtensionTransparent,
+			step_down::StepDown,
+		},
+	};
+
+	type F = BinaryField128b;
+
+	fn assert_roundtrip(poly: Box<dyn MultivariatePoly<F>>) {
+		let mode = SerializationMode::CanonicalTower;
+		let mut buf = Vec::new();
+		poly.serialize(&mut buf, mode).unwrap();
+		let deserialized =
+			Box::<dyn MultivariatePoly<F>>::deserialize(buf.as_slice(), mode).unwrap();
+
+		assert_eq!(deserialized.n_vars(), poly.n_vars());
+		assert_eq!(deserialized.degree(), poly.degree());
+		assert_eq!(deserialized.binary_tower_level(), poly.binary_tower_level());
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let query = repeat_with(|| F::random(&mut rng))
+			.take(poly.n_vars())
+			.collect::<Vec<_>>();
+		assert_eq!(deserialized.evaluate(&query).unwrap(), poly.evaluate(&query).unwrap());
+	}
+
+	#[test]
+	fn test_transparent_serialization_roundtrip() {
+		let eq_ind = || EqIndPartialEval::new(vec![F::new(3), F::new(5), F::new(7)]);
+		let step_down = || StepDown::new(2, 3).unwrap();
+		let evals = (0..16).map(BinaryField8b::new).collect::<Vec<_>>();
+
+		assert_roundtrip(Box::new(Constant::<F>::new(3, BinaryField8b::new(7))));
+		assert_roundtrip(Box::new(step_down()));
+		assert_roundtrip(Box::new(eq_ind()));
+		assert_roundtrip(Box::new(
+			MultilinearExtensionTransparent::<_, PackedType<OptimalUnderlier, F>, _>::from_values(
+				pack_slice::<PackedType<OptimalUnderlier, BinaryField8b>>(&evals),
+			)
+			.unwrap(),
+		));
+		assert_roundtrip(Box::new(DisjointProduct::new(eq_ind(), step_down())));
+		assert_roundtrip(Box::new(
+			DisjointProduct::new(eq_ind(), step_down()).with_ordering(ProductOrdering::P1First),
+		));
+	}
+}
@@ -2,10 +2,21 @@
 
 use std::{fmt::Debug, sync::Arc};
 
-use binius_field::PackedField;
-use binius_math::MultilinearPoly;
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	underlier::UnderlierType,
+	ExtensionField, PackedField, TowerField,
+};
+use binius_math::{MLEEmbeddingAdapter, MultilinearExtension, MultilinearPoly};
+use binius_maybe_rayon::prelude::*;
 
-use crate::{oracle::OracleId, polynomial::Error as PolynomialError};
+use crate::{
+	oracle::{
+		MultilinearOracleSet, MultilinearPolyOracle, MultilinearPolyVariant, OracleId, ShiftVariant,
+	},
+	polynomial::Error as PolynomialError,
+	tower::{TowerFamily, TowerUnderlier},
+};
 
 pub type MultilinearWitness<'a, P> = Arc<dyn MultilinearPoly<P> + Send + Sync + 'a>;
 
@@ -39,6 +50,8 @@ pub enum Error {
 		field_log_extension_degree: usize,
 		entry_log_extension_degree: usize,
 	},
+	#[error("cannot fill the witness of oracle {id} with tower level {tower_level}")]
+	UnsupportedTowerLevel { id: OracleId, tower_level: usize },
 	#[error("polynomial error: {0}")]
 	Polynomial(#[from] PolynomialError),
 	#[error("HAL error: {0}")]
@@ -74,6 +87,16 @@ where
 		self.entries.get(id).is_some_and(Option::is_some)
 	}
 
+	/// Iterates over the oracle ids that have data, in increasing order.
+	///
+	/// Comparing this with the ids of an oracle set shows which witnesses are missing.
+	pub fn populated_oracle_ids(&self) -> impl Iterator<Item = OracleId> + '_ {
+		self.entries
+			.iter()
+			.enumerate()
+			.filter_map(|(id, entry)| entry.as_ref().map(|_| id))
+	}
+
 	pub fn update_multilin_poly(
 		&mut self,
 		witnesses: impl IntoIterator<Item = (OracleId, MultilinearWitness<'a, P>)>,
@@ -103,3 +126,193 @@ where
 		Ok(())
 	}
 }
+
+/// Computes the witnesses of the virtual oracles of `oracles` that are missing from `witness`.
+///
+/// Witnesses are filled for [`MultilinearPolyVariant::Shifted`], [`MultilinearPolyVariant::Packed`],
+/// [`MultilinearPolyVariant::LinearCombination`], [`MultilinearPolyVariant::ZeroPadded`] and
+/// [`MultilinearPolyVariant::Repeating`] oracles, in the order of their ids, so that virtual
+/// oracles defined over other virtual oracles are handled too. Each witness is stored in the
+/// tower field of its oracle. The witnesses of all other oracles must already be present.
+pub fn fill_virtual_oracles<U, Tower>(
+	witness: &mut MultilinearExtensionIndex<PackedType<U, Tower::B128>>,
+	oracles: &MultilinearOracleSet<Tower::B128>,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+{
+	for oracle in oracles.iter() {
+		if witness.has(oracle.id()) {
+			continue;
+		}
+		let Some(values) = virtual_oracle_values(witness, oracles, &oracle)? else {
+			continue;
+		};
+		let (id, tower_level) = (oracle.id(), oracle.binary_tower_level());
+		let poly = match tower_level {
+			0 => subfield_witness::<U, Tower::B1, _>(id, tower_level, oracle.n_vars(), values)?,
+			3 => subfield_witness::<U, Tower::B8, _>(id, tower_level, oracle.n_vars(), values)?,
+			4 => subfield_witness::<U, Tower::B16, _>(id, tower_level, oracle.n_vars(), values)?,
+			5 => subfield_witness::<U, Tower::B32, _>(id, tower_level, oracle.n_vars(), values)?,
+			6 => subfield_witness::<U, Tower::B64, _>(id, tower_level, oracle.n_vars(), values)?,
+			7 => subfield_witness::<U, Tower::B128, _>(id, tower_level, oracle.n_vars(), values)?,
+			_ => return Err(Error::UnsupportedTowerLevel { id, tower_level }),
+		};
+		witness.update_multilin_poly([(id, poly)])?;
+	}
+	Ok(())
+}
+
+/// Computes the hypercube evaluations of a virtual oracle from the witnesses of its inner oracles.
+///
+/// Returns `None` for the oracle variants that are not computed by [`fill_virtual_oracles`].
+fn virtual_oracle_values<P, F>(
+	witness: &MultilinearExtensionIndex<P>,
+	oracles: &MultilinearOracleSet<F>,
+	oracle: &MultilinearPolyOracle<F>,
+) -> Result<Option<Vec<F>>, Error>
+where
+	P: PackedField<Scalar = F>,
+	F: TowerField,
+{
+	let n_vars = oracle.n_vars();
+	let values = match &oracle.variant {
+		MultilinearPolyVariant::Shifted(shifted) => {
+			let inner = witness.get_multilin_poly(shifted.id())?;
+			let block_len = 1 << shifted.block_size();
+			let shift_offset = shifted.shift_offset();
+			(0..1 << n_vars)
+				.into_par_iter()
+				.map(|i| {
+					let block_start = i & !(block_len - 1);
+					let offset = i - block_start;
+					let inner_offset = match shifted.shift_variant() {
+						ShiftVariant::CircularLeft => {
+							Some((offset + block_len - shift_offset) % block_len)
+						}
+						ShiftVariant::LogicalLeft => offset.checked_sub(shift_offset),
+						ShiftVariant::LogicalRight => {
+							Some(offset + shift_offset).filter(|&offset| offset < block_len)
+						}
+					};
+					inner_offset.map_or(Ok(F::ZERO), |inner_offset| {
+						inner.evaluate_on_hypercube(block_start + inner_offset)
+					})
+				})
+				.collect::<Result<Vec<_>, _>>()?
+		}
+		MultilinearPolyVariant::Packed(packed) => {
+			let inner = witness.get_multilin_poly(packed.id())?;
+			let inner_tower_level = oracles.tower_level(packed.id());
+			let basis = (0..1 << packed.log_degree())
+				.map(|j| {
+					<F as TowerField>::basis(inner_tower_level, j)
+						.expect("the packed oracle tower level is at most the top tower level")
+				})
+				.collect::<Vec<_>>();
+			(0..1 << n_vars)
+				.into_par_iter()
+				.map(|i| {
+					basis
+						.iter()
+						.enumerate()
+						.try_fold(F::ZERO, |acc, (j, &basis)| {
+							let index = (i << packed.log_degree()) | j;
+							Ok(acc + inner.evaluate_on_hypercube_and_scale(index, basis)?)
+						})
+				})
+				.collect::<Result<Vec<_>, Error>>()?
+		}
+		MultilinearPolyVariant::LinearCombination(linear_combination) => {
+			let inner = linear_combination
+				.polys()
+				.map(|id| witness.get_multilin_poly(id))
+				.collect::<Result<Vec<_>, _>>()?;
+			(0..1 << n_vars)
+				.into_par_iter()
+				.map(|i| {
+					linear_combination.coefficients().zip(&inner).try_fold(
+						linear_combination.offset(),
+						|acc, (coeff, poly)| {
+							Ok(acc + poly.evaluate_on_hypercube_and_scale(i, coeff)?)
+						},
+					)
+				})
+				.collect::<Result<Vec<_>, Error>>()?
+		}
+		MultilinearPolyVariant::ZeroPadded(inner_id) => {
+			let inner = witness.get_multilin_poly(*inner_id)?;
+			let inner_size = 1 << inner.n_vars();
+			(0..1 << n_vars)
+				.into_par_iter()
+				.map(|i| {
+					if i < inner_size {
+						inner.evaluate_on_hypercube(i)
+					} else {
+						Ok(F::ZERO)
+					}
+				})
+				.collect::<Result<Vec<_>, _>>()?
+		}
+		MultilinearPolyVariant::Repeating { id, .. } => {
+			let inner = witness.get_multilin_poly(*id)?;
+			let inner_size = 1 << inner.n_vars();
+			(0..1 << n_vars)
+				.into_par_iter()
+				.map(|i| inner.evaluate_on_hypercube(i % inner_size))
+				.collect::<Result<Vec<_>, _>>()?
+		}
+		_ => return Ok(None),
+	};
+	Ok(Some(values))
+}
+
+fn subfield_witness<'a, U, FS, F>(
+	id: OracleId,
+	tower_level: usize,
+	n_vars: usize,
+	values: Vec<F>,
+) -> Result<MultilinearWitness<'a, PackedType<U, F>>, Error>
+where
+	U: UnderlierType + PackScalar<FS> + PackScalar<F>,
+	FS: TowerField + TryFrom<F>,
+	F: TowerField + ExtensionField<FS>,
+{
+	let values = values
+		.into_iter()
+		.map(FS::try_from)
+		.collect::<Result<Vec<_>, _>>()
+		.map_err(|_| Error::UnsupportedTowerLevel { id, tower_level })?;
+	let width = PackedType::<U, FS>::WIDTH;
+	let packed = (0..1 << n_vars.saturating_sub(PackedType::<U, FS>::LOG_WIDTH))
+		.map(|i| {
+			PackedType::<U, FS>::from_fn(|j| values.get(i * width + j).copied().unwrap_or(FS::ZERO))
+		})
+		.collect::<Vec<_>>();
+	let mle = MultilinearExtension::new(n_vars, packed)?;
+	Ok(MLEEmbeddingAdapter::from(mle).upcast_arc_dyn())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{PackedBinaryField1x128b, PackedField};
+	use binius_math::MultilinearExtension;
+
+	use super::MultilinearExtensionIndex;
+
+	#[test]
+	fn test_populated_oracle_ids() {
+		type P = PackedBinaryField1x128b;
+		let mut witness = MultilinearExtensionIndex::<P>::new();
+		assert_eq!(witness.populated_oracle_ids().count(), 0);
+
+		let poly = MultilinearExtension::from_values(vec![P::one(); 4])
+			.unwrap()
+			.specialize_arc_dyn::<P>();
+		witness
+			.update_multilin_poly([5, 1, 3].map(|id| (id, poly.clone())))
+			.unwrap();
+		assert_eq!(witness.populated_oracle_ids().collect::<Vec<_>>(), vec![1, 3, 5]);
+	}
+}
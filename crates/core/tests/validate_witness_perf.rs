@@ -0,0 +1,142 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Performance regression tests for [`validate_witness`] on circuits with many large channels.
+//!
+//! The time limits only hold for optimized builds, so the tests are ignored by default. Run them
+//! with `cargo test --release -p binius_core --test validate_witness_perf -- --ignored`.
+
+use std::time::{Duration, Instant};
+
+use binius_core::{
+	constraint_system::{
+		channel::{Flush, FlushDirection, OracleOrConst},
+		error::{Error, VerificationError},
+		validate::validate_witness,
+		ConstraintSystem,
+	},
+	oracle::{MultilinearOracleSet, OracleId},
+	witness::MultilinearExtensionIndex,
+};
+use binius_field::{
+	BinaryField128b, BinaryField32b, PackedBinaryField1x128b, PackedBinaryField4x32b, PackedField,
+	TowerField,
+};
+use binius_math::MultilinearExtension;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+type F = BinaryField128b;
+type P = PackedBinaryField1x128b;
+
+const N_CHANNELS: usize = 16;
+const LOG_ROWS: usize = 20;
+const N_FLUSH_ORACLES: usize = 4;
+
+fn pack(values: &[u32]) -> Vec<PackedBinaryField4x32b> {
+	values
+		.chunks(PackedBinaryField4x32b::WIDTH)
+		.map(|chunk| PackedBinaryField4x32b::from_fn(|i| BinaryField32b::new(chunk[i])))
+		.collect()
+}
+
+fn flush(channel_id: usize, direction: FlushDirection, oracles: &[OracleId]) -> Flush<F> {
+	Flush {
+		oracles: oracles.iter().copied().map(OracleOrConst::Oracle).collect(),
+		channel_id,
+		direction,
+		selector: None,
+		multiplicity: 1,
+	}
+}
+
+/// Builds a circuit where every channel pushes `N_FLUSH_ORACLES` random columns and pulls the
+/// same rows in reverse order.
+///
+/// If `unbalanced_channel` is set, one pulled value of that channel is corrupted.
+fn multi_channel_circuit(
+	unbalanced_channel: Option<usize>,
+) -> (ConstraintSystem<F>, MultilinearExtensionIndex<'static, P>) {
+	let mut rng = StdRng::seed_from_u64(0);
+	let mut oracles = MultilinearOracleSet::<F>::new();
+	let mut witness = MultilinearExtensionIndex::<P>::new();
+	let mut flushes = Vec::with_capacity(2 * N_CHANNELS);
+
+	for channel_id in 0..N_CHANNELS {
+		let mut pushed = Vec::with_capacity(N_FLUSH_ORACLES);
+		let mut pulled = Vec::with_capacity(N_FLUSH_ORACLES);
+		for i in 0..N_FLUSH_ORACLES {
+			let push_id = oracles.add_committed(LOG_ROWS, BinaryField32b::TOWER_LEVEL);
+			let pull_id = oracles.add_committed(LOG_ROWS, BinaryField32b::TOWER_LEVEL);
+
+			let values = (0..1 << LOG_ROWS).map(|_| rng.gen()).collect::<Vec<u32>>();
+			let mut reversed = values.iter().rev().copied().collect::<Vec<_>>();
+			if unbalanced_channel == Some(channel_id) && i == 0 {
+				reversed[0] ^= 1;
+			}
+
+			witness
+				.update_multilin_poly([
+					(
+						push_id,
+						MultilinearExtension::from_values(pack(&values))
+							.unwrap()
+							.specialize_arc_dyn::<P>(),
+					),
+					(
+						pull_id,
+						MultilinearExtension::from_values(pack(&reversed))
+							.unwrap()
+							.specialize_arc_dyn::<P>(),
+					),
+				])
+				.unwrap();
+			pushed.push(push_id);
+			pulled.push(pull_id);
+		}
+		flushes.push(flush(channel_id, FlushDirection::Push, &pushed));
+		flushes.push(flush(channel_id, FlushDirection::Pull, &pulled));
+	}
+
+	let constraint_system = ConstraintSystem {
+		oracles,
+		table_constraints: vec![],
+		non_zero_oracle_ids: vec![],
+		flushes,
+		exponents: vec![],
+		max_channel_id: N_CHANNELS - 1,
+	};
+	(constraint_system, witness)
+}
+
+#[test]
+#[ignore = "performance test, run in release mode"]
+fn test_validate_balanced_channels_perf() {
+	let (constraint_system, witness) = multi_channel_circuit(None);
+
+	let start = Instant::now();
+	validate_witness(&constraint_system, &[], &witness).unwrap();
+	let elapsed = start.elapsed();
+
+	assert!(
+		elapsed < Duration::from_secs(10),
+		"validate_witness took {elapsed:?} for {N_CHANNELS} channels of 2^{LOG_ROWS} rows"
+	);
+}
+
+#[test]
+#[ignore = "performance test, run in release mode"]
+fn test_validate_unbalanced_channel_fails_fast() {
+	let (constraint_system, witness) = multi_channel_circuit(Some(0));
+
+	let start = Instant::now();
+	let result = validate_witness(&constraint_system, &[], &witness);
+	let elapsed = start.elapsed();
+
+	assert!(matches!(
+		result,
+		Err(Error::Verification(VerificationError::ChannelUnbalanced { id: 0 }))
+	));
+	assert!(
+		elapsed < Duration::from_secs(2),
+		"validate_witness took {elapsed:?} to report an unbalanced channel"
+	);
+}
@@ -292,11 +292,11 @@ impl<F: TowerField> ConstraintSystem<F> {
 						})
 						.collect::<Vec<_>>();
 
-					table_constraints.push(ConstraintSet {
+					table_constraints.push(ConstraintSet::new(
 						n_vars,
-						oracle_ids: partition_oracle_ids,
-						constraints: compiled_constraints,
-					});
+						partition_oracle_ids,
+						compiled_constraints,
+					));
 				}
 			}
 		}
@@ -1,14 +1,20 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use std::{
+	borrow::Cow,
 	cmp::Ordering,
+	collections::BTreeSet,
+	convert::Infallible,
 	fmt::{self, Display},
-	iter::{Product, Sum},
-	ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+	iter::{self, Product, Sum},
+	ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
 use binius_field::{Field, PackedField, TowerField};
-use binius_macros::{DeserializeBytes, SerializeBytes};
+use binius_utils::{
+	bytes::{Buf, BufMut},
+	DeserializeBytes, SerializationError, SerializationMode, SerializeBytes,
+};
 
 use super::error::Error;
 
@@ -17,7 +23,10 @@ use super::error::Error;
 /// Arithmetic expressions are trees, where the leaves are either constants or variables, and the
 /// non-leaf nodes are arithmetic operations, such as addition, multiplication, etc. They are
 /// specific representations of multivariate polynomials.
-#[derive(Debug, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+///
+/// All the traversals of an expression, including the [`Clone`], [`PartialEq`], [`Drop`] and
+/// formatting implementations, use an explicit stack, so that arbitrarily deep expressions do not
+/// overflow the call stack.
 pub enum ArithExpr<F: Field> {
 	Const(F),
 	Var(usize),
@@ -28,12 +37,93 @@ pub enum ArithExpr<F: Field> {
 
 impl<F: Field + Display> Display for ArithExpr<F> {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		match self {
-			Self::Const(v) => write!(f, "{v}"),
-			Self::Var(i) => write!(f, "x{i}"),
-			Self::Add(x, y) => write!(f, "({} + {})", &**x, &**y),
-			Self::Mul(x, y) => write!(f, "({} * {})", &**x, &**y),
-			Self::Pow(x, p) => write!(f, "({})^{p}", &**x),
+		self.write_tree(f, |expr, f| {
+			Ok(match expr {
+				Self::Const(v) => {
+					write!(f, "{v}")?;
+					vec![]
+				}
+				Self::Var(i) => {
+					write!(f, "x{i}")?;
+					vec![]
+				}
+				Self::Add(x, y) => binary_tokens(["(", " + ", ")"], x, y),
+				Self::Mul(x, y) => binary_tokens(["(", " * ", ")"], x, y),
+				Self::Pow(x, p) => {
+					vec![
+						FormatToken::Text("(".into()),
+						FormatToken::Expr(x),
+						FormatToken::Text(format!(")^{p}").into()),
+					]
+				}
+			})
+		})
+	}
+}
+
+impl<F: Field> fmt::Debug for ArithExpr<F> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.write_tree(f, |expr, f| {
+			Ok(match expr {
+				Self::Const(value) => {
+					write!(f, "Const({value:?})")?;
+					vec![]
+				}
+				Self::Var(index) => {
+					write!(f, "Var({index:?})")?;
+					vec![]
+				}
+				Self::Add(left, right) => binary_tokens(["Add(", ", ", ")"], left, right),
+				Self::Mul(left, right) => binary_tokens(["Mul(", ", ", ")"], left, right),
+				Self::Pow(base, exp) => vec![
+					FormatToken::Text("Pow(".into()),
+					FormatToken::Expr(base),
+					FormatToken::Text(format!(", {exp:?})").into()),
+				],
+			})
+		})
+	}
+}
+
+impl<F: Field> Clone for ArithExpr<F> {
+	fn clone(&self) -> Self {
+		self.fold(|expr, children| expr.with_children(children, |value| value))
+	}
+}
+
+impl<F: Field> PartialEq for ArithExpr<F> {
+	fn eq(&self, other: &Self) -> bool {
+		let mut stack = vec![(self, other)];
+		while let Some(pair) = stack.pop() {
+			match pair {
+				(Self::Const(left), Self::Const(right)) if left == right => {}
+				(Self::Var(left), Self::Var(right)) if left == right => {}
+				(Self::Add(left_0, left_1), Self::Add(right_0, right_1))
+				| (Self::Mul(left_0, left_1), Self::Mul(right_0, right_1)) => {
+					stack.extend([(&**left_1, &**right_1), (&**left_0, &**right_0)]);
+				}
+				(Self::Pow(left, left_exp), Self::Pow(right, right_exp))
+					if left_exp == right_exp =>
+				{
+					stack.push((left, right));
+				}
+				_ => return false,
+			}
+		}
+		true
+	}
+}
+
+impl<F: Field> Eq for ArithExpr<F> {}
+
+/// Dropping detaches the sub-expressions onto an explicit stack, so that every node is dropped
+/// with leaf children only.
+impl<F: Field> Drop for ArithExpr<F> {
+	fn drop(&mut self) {
+		let mut stack = Vec::new();
+		self.detach_children(&mut stack);
+		while let Some(mut expr) = stack.pop() {
+			expr.detach_children(&mut stack);
 		}
 	}
 }
@@ -41,23 +131,24 @@ impl<F: Field + Display> Display for ArithExpr<F> {
 impl<F: Field> ArithExpr<F> {
 	/// The number of variables the expression contains.
 	pub fn n_vars(&self) -> usize {
-		match self {
+		self.fold(|expr, children: Vec<usize>| match expr {
 			Self::Const(_) => 0,
 			Self::Var(index) => *index + 1,
-			Self::Add(left, right) | Self::Mul(left, right) => left.n_vars().max(right.n_vars()),
-			Self::Pow(id, _) => id.n_vars(),
-		}
+			Self::Add(..) | Self::Mul(..) | Self::Pow(..) => {
+				children.into_iter().max().unwrap_or(0)
+			}
+		})
 	}
 
 	/// The total degree of the polynomial the expression represents.
 	pub fn degree(&self) -> usize {
-		match self {
+		self.fold(|expr, children: Vec<usize>| match expr {
 			Self::Const(_) => 0,
 			Self::Var(_) => 1,
-			Self::Add(left, right) => left.degree().max(right.degree()),
-			Self::Mul(left, right) => left.degree() + right.degree(),
-			Self::Pow(base, exp) => base.degree() * *exp as usize,
-		}
+			Self::Add(..) => children[0].max(children[1]),
+			Self::Mul(..) => children[0] + children[1],
+			Self::Pow(_, exp) => children[0] * *exp as usize,
+		})
 	}
 
 	/// Return a new arithmetic expression that contains only the terms of highest degree
@@ -69,28 +160,26 @@ impl<F: Field> ArithExpr<F> {
 
 	/// Same as `leading_term`, but returns the total degree as the first tuple element as well.
 	pub fn leading_term_with_degree(&self) -> (usize, Self) {
-		match self {
-			expr @ Self::Const(_) => (0, expr.clone()),
-			expr @ Self::Var(_) => (1, expr.clone()),
-			Self::Add(left, right) => {
-				let (lhs_degree, lhs) = left.leading_term_with_degree();
-				let (rhs_degree, rhs) = right.leading_term_with_degree();
+		self.fold(|expr, children: Vec<(usize, Self)>| match expr {
+			Self::Const(_) => (0, expr.shallow_clone()),
+			Self::Var(_) => (1, expr.shallow_clone()),
+			Self::Add(..) => {
+				let [(lhs_degree, lhs), (rhs_degree, rhs)] = into_pair(children);
 				match lhs_degree.cmp(&rhs_degree) {
 					Ordering::Less => (rhs_degree, rhs),
 					Ordering::Equal => (lhs_degree, Self::Add(Box::new(lhs), Box::new(rhs))),
 					Ordering::Greater => (lhs_degree, lhs),
 				}
 			}
-			Self::Mul(left, right) => {
-				let (lhs_degree, lhs) = left.leading_term_with_degree();
-				let (rhs_degree, rhs) = right.leading_term_with_degree();
+			Self::Mul(..) => {
+				let [(lhs_degree, lhs), (rhs_degree, rhs)] = into_pair(children);
 				(lhs_degree + rhs_degree, Self::Mul(Box::new(lhs), Box::new(rhs)))
 			}
-			Self::Pow(base, exp) => {
-				let (base_degree, base) = base.leading_term_with_degree();
+			Self::Pow(_, exp) => {
+				let (base_degree, base) = children.into_iter().next().expect("Pow has a base");
 				(base_degree * *exp as usize, Self::Pow(Box::new(base), *exp))
 			}
-		}
+		})
 	}
 
 	pub fn pow(self, exp: u64) -> Self {
@@ -107,7 +196,7 @@ impl<F: Field> ArithExpr<F> {
 
 	/// Creates a new expression with the variable indices remapped.
 	///
-	/// This recursively replaces the variable sub-expressions with an index `i` with the variable
+	/// This replaces the variable sub-expressions with an index `i` with the variable
 	/// `indices[i]`.
 	///
 	/// ## Throws
@@ -115,105 +204,91 @@ impl<F: Field> ArithExpr<F> {
 	/// * [`Error::IncorrectArgumentLength`] if indices has length less than the current number of
 	///   variables
 	pub fn remap_vars(self, indices: &[usize]) -> Result<Self, Error> {
-		let expr = match self {
-			Self::Const(_) => self,
+		self.try_fold(|expr, children| match expr {
 			Self::Var(index) => {
 				let new_index =
 					indices
-						.get(index)
+						.get(*index)
 						.ok_or_else(|| Error::IncorrectArgumentLength {
 							arg: "subset".to_string(),
-							expected: index,
+							expected: *index,
 						})?;
-				Self::Var(*new_index)
-			}
-			Self::Add(left, right) => {
-				let new_left = left.remap_vars(indices)?;
-				let new_right = right.remap_vars(indices)?;
-				Self::Add(Box::new(new_left), Box::new(new_right))
-			}
-			Self::Mul(left, right) => {
-				let new_left = left.remap_vars(indices)?;
-				let new_right = right.remap_vars(indices)?;
-				Self::Mul(Box::new(new_left), Box::new(new_right))
+				Ok(Self::Var(*new_index))
 			}
-			Self::Pow(base, exp) => {
-				let new_base = base.remap_vars(indices)?;
-				Self::Pow(Box::new(new_base), exp)
-			}
-		};
-		Ok(expr)
+			_ => Ok(expr.with_children(children, |value| value)),
+		})
 	}
 
-	/// Substitute variable with index `var` with a constant `value`
-	pub fn const_subst(self, var: usize, value: F) -> Self {
-		match self {
-			Self::Const(_) => self,
-			Self::Var(index) => {
-				if index == var {
-					Self::Const(value)
-				} else {
-					self
+	/// Creates a new expression with the variables permuted, replacing `Var(i)` with
+	/// `Var(permutation[i])`.
+	///
+	/// Unlike [`Self::remap_vars`], the mapping must be a permutation of `0..permutation.len()`,
+	/// so that the result has the same variables as the original expression, in a different order.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::IncorrectArgumentLength`] if `permutation` has length less than the current
+	///   number of variables
+	/// * [`Error::ArgumentRangeError`] if an index is not less than the length of `permutation`
+	/// * [`Error::DuplicateIndex`] if an index appears more than once
+	pub fn reorder_variables(self, permutation: &[usize]) -> Result<Self, Error> {
+		if permutation.len() < self.n_vars() {
+			return Err(Error::IncorrectArgumentLength {
+				arg: "permutation".to_string(),
+				expected: self.n_vars(),
+			});
+		}
+		let mut seen = vec![false; permutation.len()];
+		for &index in permutation {
+			match seen.get_mut(index) {
+				None => {
+					return Err(Error::ArgumentRangeError {
+						arg: "permutation".to_string(),
+						range: 0..permutation.len(),
+					})
 				}
-			}
-			Self::Add(left, right) => {
-				let new_left = left.const_subst(var, value);
-				let new_right = right.const_subst(var, value);
-				Self::Add(Box::new(new_left), Box::new(new_right))
-			}
-			Self::Mul(left, right) => {
-				let new_left = left.const_subst(var, value);
-				let new_right = right.const_subst(var, value);
-				Self::Mul(Box::new(new_left), Box::new(new_right))
-			}
-			Self::Pow(base, exp) => {
-				let new_base = base.const_subst(var, value);
-				Self::Pow(Box::new(new_base), exp)
+				Some(true) => {
+					return Err(Error::DuplicateIndex {
+						arg: "permutation".to_string(),
+						index,
+					})
+				}
+				Some(seen) => *seen = true,
 			}
 		}
+		self.remap_vars(permutation)
+	}
+
+	/// Substitute variable with index `var` with a constant `value`
+	pub fn const_subst(self, var: usize, value: F) -> Self {
+		self.fold(|expr, children| match expr {
+			Self::Var(index) if *index == var => Self::Const(value),
+			_ => expr.with_children(children, |value| value),
+		})
+	}
+
+	/// Substitutes the variables in `substitutions` with constant values, and simplifies the
+	/// result to fold the new constants.
+	///
+	/// The other variables keep their indices. If an index appears more than once, the first value
+	/// is used.
+	pub fn partial_evaluate(self, substitutions: &[(usize, F)]) -> Self {
+		substitutions
+			.iter()
+			.fold(self, |expr, &(var, value)| expr.const_subst(var, value))
+			.simplify()
 	}
 
 	pub fn convert_field<FTgt: Field + From<F>>(&self) -> ArithExpr<FTgt> {
-		match self {
-			Self::Const(val) => ArithExpr::Const((*val).into()),
-			Self::Var(index) => ArithExpr::Var(*index),
-			Self::Add(left, right) => {
-				let new_left = left.convert_field();
-				let new_right = right.convert_field();
-				ArithExpr::Add(Box::new(new_left), Box::new(new_right))
-			}
-			Self::Mul(left, right) => {
-				let new_left = left.convert_field();
-				let new_right = right.convert_field();
-				ArithExpr::Mul(Box::new(new_left), Box::new(new_right))
-			}
-			Self::Pow(base, exp) => {
-				let new_base = base.convert_field();
-				ArithExpr::Pow(Box::new(new_base), *exp)
-			}
-		}
+		self.fold(|expr, children| expr.with_children(children, FTgt::from))
 	}
 
 	pub fn try_convert_field<FTgt: Field + TryFrom<F>>(
 		&self,
 	) -> Result<ArithExpr<FTgt>, <FTgt as TryFrom<F>>::Error> {
-		Ok(match self {
-			Self::Const(val) => ArithExpr::Const(FTgt::try_from(*val)?),
-			Self::Var(index) => ArithExpr::Var(*index),
-			Self::Add(left, right) => {
-				let new_left = left.try_convert_field()?;
-				let new_right = right.try_convert_field()?;
-				ArithExpr::Add(Box::new(new_left), Box::new(new_right))
-			}
-			Self::Mul(left, right) => {
-				let new_left = left.try_convert_field()?;
-				let new_right = right.try_convert_field()?;
-				ArithExpr::Mul(Box::new(new_left), Box::new(new_right))
-			}
-			Self::Pow(base, exp) => {
-				let new_base = base.try_convert_field()?;
-				ArithExpr::Pow(Box::new(new_base), *exp)
-			}
+		self.try_fold(|expr, children| match expr {
+			Self::Const(val) => Ok(ArithExpr::Const(FTgt::try_from(*val)?)),
+			_ => Ok(expr.with_children(children, |_| unreachable!("only leaves are constants"))),
 		})
 	}
 
@@ -235,58 +310,67 @@ impl<F: Field> ArithExpr<F> {
 
 	/// Creates a new optimized expression.
 	///
-	/// Recursively rewrites the expression for better evaluation performance. Performs constant folding,
-	/// as well as leverages simple rewriting rules around additive/multiplicative identities and addition
-	/// in characteristic 2.
+	/// Rewrites the expression bottom-up for better evaluation performance. Performs constant
+	/// folding, as well as leverages simple rewriting rules around additive/multiplicative
+	/// identities, trivial exponents and addition in characteristic 2.
 	pub fn optimize(&self) -> Self {
-		match self {
-			Self::Const(_) | Self::Var(_) => self.clone(),
-			Self::Add(left, right) => {
-				let left = left.optimize();
-				let right = right.optimize();
-				match (left, right) {
-					// constant folding
-					(Self::Const(left), Self::Const(right)) => Self::Const(left + right),
-					// 0 + a = a + 0 = a
-					(Self::Const(left), right) if left == F::ZERO => right,
-					(left, Self::Const(right)) if right == F::ZERO => left,
-					// a + a = 0 in char 2
-					// REVIEW: relies on precise structural equality, find a better way
-					(left, right) if left == right && F::CHARACTERISTIC == 2 => {
-						Self::Const(F::ZERO)
+		self.fold(|expr, children| match expr {
+			Self::Const(_) | Self::Var(_) => expr.shallow_clone(),
+			Self::Add(..) => match into_pair(children) {
+				// constant folding
+				[Self::Const(left), Self::Const(right)] => Self::Const(left + right),
+				// 0 + a = a + 0 = a
+				[Self::Const(left), right] if left == F::ZERO => right,
+				[left, Self::Const(right)] if right == F::ZERO => left,
+				// a + a = 0 in char 2
+				// REVIEW: relies on precise structural equality, find a better way
+				[left, right] if left == right && F::CHARACTERISTIC == 2 => Self::Const(F::ZERO),
+				// fallback
+				[left, right] => Self::Add(Box::new(left), Box::new(right)),
+			},
+			Self::Mul(..) => match into_pair(children) {
+				// constant folding
+				[Self::Const(left), Self::Const(right)] => Self::Const(left * right),
+				// 0 * a = a * 0 = 0
+				[left, right] if left == Self::Const(F::ZERO) || right == Self::Const(F::ZERO) => {
+					Self::Const(F::ZERO)
+				}
+				// 1 * a = a * 1 = a
+				[Self::Const(left), right] if left == F::ONE => right,
+				[left, Self::Const(right)] if right == F::ONE => left,
+				// fallback
+				[left, right] => Self::Mul(Box::new(left), Box::new(right)),
+			},
+			Self::Pow(_, exp) => {
+				let mut base = children.into_iter().next().expect("Pow has a base");
+				let mut exp = *exp;
+				// (a^m)^n = a^(m*n), unless m*n overflows
+				if let Self::Pow(inner_base, inner_exp) = &mut base {
+					if let Some(outer_exp) = exp.checked_mul(*inner_exp) {
+						exp = outer_exp;
+						base = std::mem::take(&mut **inner_base);
 					}
-					// fallback
-					(left, right) => Self::Add(Box::new(left), Box::new(right)),
 				}
-			}
-			Self::Mul(left, right) => {
-				let left = left.optimize();
-				let right = right.optimize();
-				match (left, right) {
+				match (base, exp) {
+					// a^0 = 1
+					(_, 0) => Self::Const(F::ONE),
+					// a^1 = a
+					(base, 1) => base,
 					// constant folding
-					(Self::Const(left), Self::Const(right)) => Self::Const(left * right),
-					// 0 * a = a * 0 = 0
-					(left, right)
-						if left == Self::Const(F::ZERO) || right == Self::Const(F::ZERO) =>
-					{
-						Self::Const(F::ZERO)
-					}
-					// 1 * a = a * 1 = a
-					(Self::Const(left), right) if left == F::ONE => right,
-					(left, Self::Const(right)) if right == F::ONE => left,
+					(Self::Const(value), exp) => Self::Const(PackedField::pow(value, exp)),
 					// fallback
-					(left, right) => Self::Mul(Box::new(left), Box::new(right)),
+					(base, exp) => Self::Pow(Box::new(base), exp),
 				}
 			}
-			Self::Pow(id, exp) => {
-				let id = id.optimize();
-				match id {
-					Self::Const(value) => Self::Const(PackedField::pow(value, *exp)),
-					Self::Pow(id_inner, exp_inner) => Self::Pow(id_inner, *exp * exp_inner),
-					id => Self::Pow(Box::new(id), *exp),
-				}
-			}
-		}
+		})
+	}
+
+	/// Consumes the expression and returns a simplified one.
+	///
+	/// Applies the same rewriting rules as [`Self::optimize`], which folds constant
+	/// sub-expressions and removes identities such as `a + 0`, `a * 1`, `a^1` and `a^0`.
+	pub fn simplify(self) -> Self {
+		self.optimize()
 	}
 
 	/// Returns the normal form of an expression if it is linear.
@@ -303,7 +387,7 @@ impl<F: Field> ArithExpr<F> {
 
 		// Linear normal form: f(x0, x1, ... x{n-1}) = c + a0*x0 + a1*x1 + ... + a{n-1}*x{n-1}
 		// Evaluating with all variables set to 0, should give the constant term
-		let constant = self.evaluate(&vec![F::ZERO; n_vars]);
+		let constant = self.evaluate(&vec![F::ZERO; n_vars])?;
 
 		// Evaluating with x{k} set to 1 and all other x{i} set to 0, gives us `constant + a{k}`
 		// That means we can subtract the constant from the evaluated expression to get the coefficient a{k}
@@ -311,23 +395,33 @@ impl<F: Field> ArithExpr<F> {
 			.map(|i| {
 				let mut vars = vec![F::ZERO; n_vars];
 				vars[i] = F::ONE;
-				self.evaluate(&vars) - constant
+				Ok(self.evaluate(&vars)? - constant)
 			})
-			.collect();
+			.collect::<Result<_, Error>>()?;
 		Ok(LinearNormalForm {
 			constant,
 			var_coeffs,
 		})
 	}
 
-	fn evaluate(&self, vars: &[F]) -> F {
-		match self {
-			Self::Const(val) => *val,
-			Self::Var(index) => vars[*index],
-			Self::Add(left, right) => left.evaluate(vars) + right.evaluate(vars),
-			Self::Mul(left, right) => left.evaluate(vars) * right.evaluate(vars),
-			Self::Pow(base, exp) => base.evaluate(vars).pow(*exp),
+	/// Evaluates the expression at a point, with `query[i]` substituted for `Var(i)`.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::IncorrectQuerySize`] if `query` has fewer elements than the number of variables
+	pub fn evaluate(&self, query: &[F]) -> Result<F, Error> {
+		let n_vars = self.n_vars();
+		if query.len() < n_vars {
+			return Err(Error::IncorrectQuerySize { expected: n_vars });
 		}
+
+		Ok(self.fold(|expr, children: Vec<F>| match expr {
+			Self::Const(val) => *val,
+			Self::Var(index) => query[*index],
+			Self::Add(..) => children[0] + children[1],
+			Self::Mul(..) => children[0] * children[1],
+			Self::Pow(_, exp) => children[0].pow(*exp),
+		}))
 	}
 
 	/// Returns a vector of booleans indicating which variables are used in the expression.
@@ -336,36 +430,194 @@ impl<F: Field> ArithExpr<F> {
 	/// if the variable is used in the expression.
 	pub fn vars_usage(&self) -> Vec<bool> {
 		let mut usage = vec![false; self.n_vars()];
-		self.mark_vars_usage(&mut usage);
+		for expr in self.pre_order() {
+			if let Self::Var(index) = expr {
+				usage[*index] = true;
+			}
+		}
 		usage
 	}
 
-	fn mark_vars_usage(&self, usage: &mut [bool]) {
+	/// Returns the indices of the variables that appear in the expression.
+	///
+	/// Unlike [`Self::n_vars`], this skips the indices below the highest one that are not used,
+	/// which helps to find oracles that are passed to a constraint but not used by it.
+	pub fn vars(&self) -> BTreeSet<usize> {
+		self.pre_order()
+			.filter_map(|expr| match expr {
+				Self::Var(index) => Some(*index),
+				_ => None,
+			})
+			.collect()
+	}
+
+	/// Returns all non-leaf sub-expressions, including the expression itself, in depth-first
+	/// pre-order.
+	///
+	/// Together with [`Self::count_occurrences`], this allows finding common sub-expressions.
+	pub fn collect_subexpressions(&self) -> Vec<Self> {
+		self.pre_order()
+			.filter(|expr| expr.is_composite())
+			.cloned()
+			.collect()
+	}
+
+	/// Returns the number of times `target` appears as a sub-expression, compared structurally.
+	pub fn count_occurrences(&self, target: &Self) -> usize {
+		let mut count = 0;
+		let mut stack = vec![self];
+		while let Some(expr) = stack.pop() {
+			if expr == target {
+				count += 1;
+			} else {
+				stack.extend(expr.children().rev());
+			}
+		}
+		count
+	}
+
+	/// Returns the direct sub-expressions of a composite node, from left to right.
+	fn children(&self) -> impl DoubleEndedIterator<Item = &Self> {
+		let children = match self {
+			Self::Const(_) | Self::Var(_) => [None, None],
+			Self::Add(left, right) | Self::Mul(left, right) => [Some(left), Some(right)],
+			Self::Pow(base, _) => [Some(base), None],
+		};
+		children.into_iter().flatten().map(|child| &**child)
+	}
+
+	/// Iterates over all sub-expressions, including the expression itself, in depth-first
+	/// pre-order.
+	fn pre_order(&self) -> impl Iterator<Item = &Self> {
+		let mut stack = vec![self];
+		iter::from_fn(move || {
+			let expr = stack.pop()?;
+			stack.extend(expr.children().rev());
+			Some(expr)
+		})
+	}
+
+	/// Computes a value for every node bottom-up, and returns the value of the root.
+	///
+	/// `f` is called with each node and the values of its children, from left to right. The tree
+	/// is traversed with an explicit stack rather than recursion, so that arbitrarily deep
+	/// expressions, such as long folds of additions, do not overflow the call stack.
+	fn try_fold<T, E>(&self, mut f: impl FnMut(&Self, Vec<T>) -> Result<T, E>) -> Result<T, E> {
+		// Each node is pushed twice: first to schedule its children, then to combine their values.
+		let mut stack = vec![(self, false)];
+		let mut values = Vec::new();
+		while let Some((expr, children_done)) = stack.pop() {
+			if children_done || !expr.is_composite() {
+				let n_children = expr.children().count();
+				let children = values.split_off(values.len() - n_children);
+				values.push(f(expr, children)?);
+			} else {
+				stack.push((expr, true));
+				stack.extend(expr.children().rev().map(|child| (child, false)));
+			}
+		}
+		Ok(values.pop().expect("the root has a value"))
+	}
+
+	fn fold<T>(&self, mut f: impl FnMut(&Self, Vec<T>) -> T) -> T {
+		self.try_fold(|expr, children| Ok::<_, Infallible>(f(expr, children)))
+			.unwrap_or_else(|never| match never {})
+	}
+
+	/// Returns the node `self` with its children replaced by `children`, and its constant value
+	/// converted with `convert_const`.
+	fn with_children<FTgt: Field>(
+		&self,
+		children: Vec<ArithExpr<FTgt>>,
+		convert_const: impl FnOnce(F) -> FTgt,
+	) -> ArithExpr<FTgt> {
+		let mut children = children.into_iter().map(Box::new);
+		let mut child = || children.next().expect("composite nodes have children");
+		match self {
+			Self::Const(value) => ArithExpr::Const(convert_const(*value)),
+			Self::Var(index) => ArithExpr::Var(*index),
+			Self::Add(..) => ArithExpr::Add(child(), child()),
+			Self::Mul(..) => ArithExpr::Mul(child(), child()),
+			Self::Pow(_, exp) => ArithExpr::Pow(child(), *exp),
+		}
+	}
+
+	/// Clones a leaf without recursing into sub-expressions.
+	fn shallow_clone(&self) -> Self {
+		self.with_children(Vec::new(), |value| value)
+	}
+
+	/// Moves the direct sub-expressions onto `stack`, leaving leaves in their place.
+	fn detach_children(&mut self, stack: &mut Vec<Self>) {
 		match self {
-			Self::Const(_) => (),
-			Self::Var(index) => usage[*index] = true,
+			Self::Const(_) | Self::Var(_) => {}
 			Self::Add(left, right) | Self::Mul(left, right) => {
-				left.mark_vars_usage(usage);
-				right.mark_vars_usage(usage);
+				stack.push(std::mem::take(&mut **left));
+				stack.push(std::mem::take(&mut **right));
+			}
+			Self::Pow(base, _) => stack.push(std::mem::take(&mut **base)),
+		}
+	}
+
+	/// Writes the expression to `f` in pre-order, without recursion.
+	///
+	/// `write_node` writes a leaf directly, and returns the tokens to write in place of a
+	/// composite node.
+	fn write_tree<'a>(
+		&'a self,
+		f: &mut fmt::Formatter<'_>,
+		mut write_node: impl FnMut(
+			&'a Self,
+			&mut fmt::Formatter<'_>,
+		) -> Result<Vec<FormatToken<'a, F>>, fmt::Error>,
+	) -> fmt::Result {
+		let mut stack = vec![FormatToken::Expr(self)];
+		while let Some(token) = stack.pop() {
+			match token {
+				FormatToken::Expr(expr) => stack.extend(write_node(expr, f)?.into_iter().rev()),
+				FormatToken::Text(text) => f.write_str(&text)?,
 			}
-			Self::Pow(base, _) => base.mark_vars_usage(usage),
 		}
+		Ok(())
 	}
 }
 
 impl<F: TowerField> ArithExpr<F> {
 	pub fn binary_tower_level(&self) -> usize {
-		match self {
+		self.fold(|expr, children| match expr {
 			Self::Const(value) => value.min_tower_level(),
-			Self::Var(_) => 0,
-			Self::Add(left, right) | Self::Mul(left, right) => {
-				left.binary_tower_level().max(right.binary_tower_level())
-			}
-			Self::Pow(base, _) => base.binary_tower_level(),
-		}
+			_ => children.into_iter().max().unwrap_or(0),
+		})
 	}
 }
 
+/// A sub-expression or a piece of text to write, in the formatting of an expression.
+enum FormatToken<'a, F: Field> {
+	Expr(&'a ArithExpr<F>),
+	Text(Cow<'static, str>),
+}
+
+/// Returns the tokens of a binary node, with `open`, `separator` and `close` around its children.
+fn binary_tokens<'a, F: Field>(
+	[open, separator, close]: [&'static str; 3],
+	left: &'a ArithExpr<F>,
+	right: &'a ArithExpr<F>,
+) -> Vec<FormatToken<'a, F>> {
+	vec![
+		FormatToken::Text(open.into()),
+		FormatToken::Expr(left),
+		FormatToken::Text(separator.into()),
+		FormatToken::Expr(right),
+		FormatToken::Text(close.into()),
+	]
+}
+
+fn into_pair<T>(children: Vec<T>) -> [T; 2] {
+	children
+		.try_into()
+		.unwrap_or_else(|_| unreachable!("binary nodes have two children"))
+}
+
 impl<F> Default for ArithExpr<F>
 where
 	F: Field,
@@ -395,6 +647,8 @@ where
 	}
 }
 
+/// Subtraction is addition over fields of characteristic 2, which includes every level of the
+/// binary tower, so it is represented with an `Add` node.
 impl<F> Sub for ArithExpr<F>
 where
 	F: Field,
@@ -402,6 +656,7 @@ where
 	type Output = Self;
 
 	fn sub(self, rhs: Self) -> Self {
+		const { assert!(F::CHARACTERISTIC == 2, "subtraction is only addition in characteristic 2") };
 		Self::Add(Box::new(self), Box::new(rhs))
 	}
 }
@@ -415,6 +670,21 @@ where
 	}
 }
 
+/// Negation is the identity over fields of characteristic 2, so it does not introduce a new node.
+///
+/// It exists so that constraints can be written as `a + (-b)` where this reads more naturally.
+impl<F> Neg for ArithExpr<F>
+where
+	F: Field,
+{
+	type Output = Self;
+
+	fn neg(self) -> Self {
+		const { assert!(F::CHARACTERISTIC == 2, "negation is only a no-op in characteristic 2") };
+		self
+	}
+}
+
 impl<F> Mul for ArithExpr<F>
 where
 	F: Field,
@@ -447,6 +717,124 @@ impl<F: Field> Product for ArithExpr<F> {
 	}
 }
 
+// The variant indices of the byte encoding, which are the ones of a derived implementation.
+const CONST_INDEX: u8 = 0;
+const VAR_INDEX: u8 = 1;
+const ADD_INDEX: u8 = 2;
+const MUL_INDEX: u8 = 3;
+const POW_INDEX: u8 = 4;
+
+/// The encoding is the one of a derived implementation: the index of the variant as a `u8`,
+/// followed by its fields, with the sub-expressions in pre-order.
+impl<F: Field> SerializeBytes for ArithExpr<F> {
+	fn serialize(
+		&self,
+		mut write_buf: impl BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		// The exponent of a `Pow` node is written after its base.
+		enum Item<'a, F: Field> {
+			Expr(&'a ArithExpr<F>),
+			Exponent(u64),
+		}
+
+		let mut stack = vec![Item::Expr(self)];
+		while let Some(item) = stack.pop() {
+			let expr = match item {
+				Item::Expr(expr) => expr,
+				Item::Exponent(exp) => {
+					SerializeBytes::serialize(&exp, &mut write_buf, mode)?;
+					continue;
+				}
+			};
+			match expr {
+				Self::Const(value) => {
+					SerializeBytes::serialize(&CONST_INDEX, &mut write_buf, mode)?;
+					SerializeBytes::serialize(value, &mut write_buf, mode)?;
+				}
+				Self::Var(index) => {
+					SerializeBytes::serialize(&VAR_INDEX, &mut write_buf, mode)?;
+					SerializeBytes::serialize(index, &mut write_buf, mode)?;
+				}
+				Self::Add(left, right) => {
+					SerializeBytes::serialize(&ADD_INDEX, &mut write_buf, mode)?;
+					stack.extend([Item::Expr(right), Item::Expr(left)]);
+				}
+				Self::Mul(left, right) => {
+					SerializeBytes::serialize(&MUL_INDEX, &mut write_buf, mode)?;
+					stack.extend([Item::Expr(right), Item::Expr(left)]);
+				}
+				Self::Pow(base, exp) => {
+					SerializeBytes::serialize(&POW_INDEX, &mut write_buf, mode)?;
+					stack.extend([Item::Exponent(*exp), Item::Expr(base)]);
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+impl<F: Field> DeserializeBytes for ArithExpr<F> {
+	fn deserialize(
+		mut read_buf: impl Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError>
+	where
+		Self: Sized,
+	{
+		// The composite nodes whose children are still being read, with the number of complete
+		// sub-expressions when they were started.
+		let mut pending = Vec::<(u8, usize)>::new();
+		let mut values = Vec::<Self>::new();
+		loop {
+			let variant_index: u8 = DeserializeBytes::deserialize(&mut read_buf, mode)?;
+			match variant_index {
+				CONST_INDEX => {
+					values.push(Self::Const(DeserializeBytes::deserialize(&mut read_buf, mode)?))
+				}
+				VAR_INDEX => {
+					values.push(Self::Var(DeserializeBytes::deserialize(&mut read_buf, mode)?))
+				}
+				ADD_INDEX | MUL_INDEX | POW_INDEX => {
+					pending.push((variant_index, values.len()));
+					continue;
+				}
+				index => {
+					return Err(SerializationError::UnknownEnumVariant {
+						name: "ArithExpr",
+						index,
+					})
+				}
+			}
+
+			// Complete the composite nodes whose children have all been read.
+			while let Some(&(variant_index, start)) = pending.last() {
+				let n_children = if variant_index == POW_INDEX { 1 } else { 2 };
+				if values.len() < start + n_children {
+					break;
+				}
+				pending.pop();
+				let node = if variant_index == POW_INDEX {
+					let base = values.pop().expect("Pow has a base");
+					Self::Pow(Box::new(base), DeserializeBytes::deserialize(&mut read_buf, mode)?)
+				} else {
+					let [left, right] = into_pair(values.split_off(start));
+					let (left, right) = (Box::new(left), Box::new(right));
+					if variant_index == ADD_INDEX {
+						Self::Add(left, right)
+					} else {
+						Self::Mul(left, right)
+					}
+				};
+				values.push(node);
+			}
+			if pending.is_empty() {
+				return Ok(values.pop().expect("the root has been read"));
+			}
+		}
+	}
+}
+
 /// A normal form for a linear expression.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct LinearNormalForm<F: Field> {
@@ -510,6 +898,50 @@ mod tests {
 		assert_eq!(new_expr.unwrap(), expected);
 	}
 
+	#[test]
+	fn test_reorder_variables_cyclic() {
+		type F = BinaryField128b;
+		let expr = (ArithExpr::Var(0) * ArithExpr::Var(1) + ArithExpr::Var(2).pow(3))
+			* (ArithExpr::Var(3) + ArithExpr::Const(F::new(7)));
+		let point = [F::new(3), F::new(5), F::new(11), F::new(13)];
+
+		for shift in 0..4 {
+			let permutation = (0..4).map(|i| (i + shift) % 4).collect::<Vec<_>>();
+			let reordered = expr.clone().reorder_variables(&permutation).unwrap();
+			let mut permuted_point = [F::ZERO; 4];
+			for (i, &j) in permutation.iter().enumerate() {
+				permuted_point[j] = point[i];
+			}
+			assert_eq!(
+				reordered.evaluate(&permuted_point).unwrap(),
+				expr.evaluate(&point).unwrap()
+			);
+			assert_eq!(reordered.degree(), expr.degree());
+		}
+	}
+
+	#[test]
+	fn test_reorder_variables_invalid_permutation() {
+		type F = BinaryField8b;
+		let expr = ArithExpr::<F>::Var(0) * ArithExpr::Var(2);
+		assert_matches!(
+			expr.clone().reorder_variables(&[1, 0]),
+			Err(Error::IncorrectArgumentLength { .. })
+		);
+		assert_matches!(
+			expr.clone().reorder_variables(&[0, 1, 3]),
+			Err(Error::ArgumentRangeError { .. })
+		);
+		assert_matches!(
+			expr.clone().reorder_variables(&[2, 0, 2]),
+			Err(Error::DuplicateIndex { index: 2, .. })
+		);
+		assert_eq!(
+			expr.reorder_variables(&[3, 1, 0, 2]).unwrap(),
+			ArithExpr::Var(3) * ArithExpr::Var(0)
+		);
+	}
+
 	#[test]
 	fn test_optimize_identity_handling() {
 		type F = BinaryField8b;
@@ -528,6 +960,39 @@ mod tests {
 		assert_eq!((ArithExpr::<F>::Var(0) + ArithExpr::Var(0)).optimize(), zero);
 	}
 
+	#[test]
+	fn test_simplify() {
+		type F = BinaryField8b;
+		use ArithExpr::{Const, Var};
+		let zero = ArithExpr::<F>::zero();
+		let one = ArithExpr::<F>::one();
+
+		assert_eq!((Var(0) * one.clone() + zero.clone()).pow(1).simplify(), Var(0));
+		assert_eq!((Var(0) * Var(1)).pow(0).simplify(), one);
+		assert_eq!(Var::<F>(0).pow(2).pow(3).simplify(), Var(0).pow(6));
+
+		let nested = Var::<F>(0).pow(1 << 32).pow(1 << 32);
+		assert_eq!(nested.clone().simplify(), nested);
+		assert_eq!(nested.clone().optimize(), nested);
+
+		let constant = (Const(F::new(3)) * Const(F::new(5)) + one).pow(3) + Var(0) * zero;
+		assert_matches!(constant.clone().simplify(), Const(_));
+		assert_eq!(constant.clone().simplify(), Const(constant.evaluate(&[F::ZERO]).unwrap()));
+	}
+
+	#[test]
+	fn test_partial_evaluate() {
+		type F = BinaryField8b;
+		use ArithExpr::{Const, Var};
+		let expr: ArithExpr<F> = Var(0) + Var(1);
+		assert_eq!(expr.partial_evaluate(&[(0, F::ONE)]), Const(F::ONE) + Var(1));
+
+		let expr: ArithExpr<F> = Var(0) * Var(1) * Var(2);
+		assert_eq!(expr.clone().partial_evaluate(&[(1, F::ZERO)]), Const(F::ZERO));
+		assert_eq!(expr.clone().partial_evaluate(&[(1, F::ONE), (2, F::ONE)]), Var(0));
+		assert_eq!(expr.clone().partial_evaluate(&[]), expr);
+	}
+
 	#[test]
 	fn test_const_subst_and_optimize() {
 		// NB: this is FlushSumcheckComposition from the constraint_system
@@ -580,4 +1045,291 @@ mod tests {
 			vec![F::new(42), F::ZERO, F::ONE, F::new(11) * F::new(37)]
 		);
 	}
+
+	#[test]
+	fn test_neg_is_identity() {
+		type F = BinaryField128b;
+		let expr = ArithExpr::<F>::Var(0) * ArithExpr::Const(F::new(7));
+		assert_eq!(-expr.clone(), expr);
+
+		let a = ArithExpr::<F>::Var(0);
+		let b = ArithExpr::<F>::Var(1);
+		assert_eq!(a.clone() + (-b.clone()), a - b);
+	}
+
+	#[test]
+	fn test_sub_evaluates_to_difference() {
+		fn check<F: TowerField>(x: F, y: F) {
+			let x_minus_x = ArithExpr::<F>::Var(0) - ArithExpr::Var(0);
+			assert_eq!(x_minus_x.evaluate(&[x]).unwrap(), F::ZERO);
+
+			let mut x_minus_y = ArithExpr::<F>::Var(0);
+			x_minus_y -= ArithExpr::Var(1);
+			assert_eq!(x_minus_y.evaluate(&[x, y]).unwrap(), x - y);
+			assert_eq!((x_minus_y + ArithExpr::Var(1)).evaluate(&[x, y]).unwrap(), x);
+		}
+
+		check(BinaryField8b::new(0x53), BinaryField8b::new(0xca));
+		check(BinaryField128b::new(0x1234_5678), BinaryField128b::new(0x9abc_def0));
+	}
+
+	#[test]
+	fn test_evaluate() {
+		type F = BinaryField8b;
+		use ArithExpr::{Const, Var};
+		let expr = (Var(0) * Var(1) + Const(F::new(3))).pow(2) + Var(2);
+		let (x, y, z) = (F::new(0x53), F::new(0xca), F::new(0x11));
+		let base = x * y + F::new(3);
+		assert_eq!(expr.evaluate(&[x, y, z]).unwrap(), base * base + z);
+
+		// Extra query elements are ignored.
+		assert_eq!(expr.evaluate(&[x, y, z, F::ONE]).unwrap(), expr.evaluate(&[x, y, z]).unwrap());
+		assert_eq!(Const(F::new(9)).evaluate(&[]).unwrap(), F::new(9));
+		assert_matches!(expr.evaluate(&[x, y]), Err(Error::IncorrectQuerySize { expected: 3 }));
+	}
+
+	#[test]
+	fn test_vars() {
+		type F = BinaryField8b;
+		use ArithExpr::{Const, Var};
+		let expr: ArithExpr<F> = (Var(0) * Var(2)).pow(3) + Var(0);
+		assert_eq!(expr.vars(), BTreeSet::from([0, 2]));
+		assert_eq!(expr.n_vars(), 3);
+		assert!(Const::<F>(F::ONE).vars().is_empty());
+	}
+
+	#[test]
+	fn test_common_subexpressions() {
+		type F = BinaryField128b;
+		use ArithExpr::{Const, Var};
+		let shared = Var(0) * Var(1);
+		let expr: ArithExpr<F> =
+			(shared.clone() + Const(F::ONE)) * shared.clone() + shared.clone().pow(2);
+
+		let subexpressions = expr.collect_subexpressions();
+		assert_eq!(subexpressions[0], expr);
+		assert_eq!(subexpressions.len(), 7);
+		assert_eq!(subexpressions.iter().filter(|&sub| *sub == shared).count(), 3);
+
+		assert_eq!(expr.count_occurrences(&shared), 3);
+		assert_eq!(expr.count_occurrences(&Var(1)), 3);
+		assert_eq!(expr.count_occurrences(&expr), 1);
+		assert_eq!(expr.count_occurrences(&Var(2)), 0);
+		assert!(Var::<F>(0).collect_subexpressions().is_empty());
+	}
+
+	#[test]
+	fn test_serialization_roundtrip_binary_nodes() {
+		use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+
+		type F = BinaryField128b;
+		use ArithExpr::{Const, Var};
+		for expr in [
+			Var::<F>(0) + Var(1),
+			Var(0) * Var(1),
+			(Var(2) + Const(F::new(3))) * (Var(0) * Var(1)).pow(2),
+		] {
+			let mut buf = Vec::new();
+			expr.serialize(&mut buf, SerializationMode::CanonicalTower)
+				.unwrap();
+			let read =
+				ArithExpr::<F>::deserialize(buf.as_slice(), SerializationMode::CanonicalTower)
+					.unwrap();
+			assert_eq!(read, expr);
+		}
+	}
+
+	#[test]
+	fn test_deep_expression_traversal() {
+		type F = BinaryField8b;
+		use ArithExpr::{Const, Var};
+
+		// A left-deep chain of depth 10,000, which overflows the stack with recursive traversal.
+		let coeff = F::MULTIPLICATIVE_GENERATOR;
+		let deep_expr = || {
+			let mut expr = Var::<F>(0);
+			for i in 1..10_000 {
+				expr = if i % 2 == 0 {
+					expr + Var(i % 3)
+				} else {
+					expr * Const(coeff)
+				};
+			}
+			expr
+		};
+		let expr = deep_expr();
+
+		assert_eq!(expr.n_vars(), 3);
+		assert_eq!(expr.degree(), 1);
+		assert_eq!(expr.leading_term_with_degree().0, 1);
+		assert_eq!(expr.vars_usage(), vec![true; 3]);
+		assert_eq!(expr.count_occurrences(&Var(1)), 1666);
+		assert_eq!(expr.binary_tower_level(), coeff.min_tower_level());
+
+		let normal_form = expr.linear_normal_form().unwrap();
+		assert_eq!(normal_form.constant, F::ZERO);
+
+		let remapped = deep_expr().remap_vars(&[2, 1, 0]).unwrap();
+		let remapped_form = remapped.linear_normal_form().unwrap();
+		let mut reversed_coeffs = normal_form.var_coeffs.clone();
+		reversed_coeffs.reverse();
+		assert_eq!(remapped_form.var_coeffs, reversed_coeffs);
+
+		let converted = expr.convert_field::<BinaryField128b>();
+		let converted_form = converted.linear_normal_form().unwrap();
+		assert_eq!(
+			converted_form.var_coeffs,
+			normal_form
+				.var_coeffs
+				.iter()
+				.map(|&coeff| BinaryField128b::from(coeff))
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(converted.try_convert_field::<F>().unwrap().n_vars(), 3);
+
+		let substituted = deep_expr().const_subst(1, F::ONE);
+		assert_eq!(substituted.vars_usage(), vec![true, false, true]);
+		assert_eq!(substituted.linear_normal_form().unwrap().constant, normal_form.var_coeffs[1]);
+
+		assert_eq!(expr.optimize().n_vars(), 3);
+	}
+
+	#[test]
+	fn test_deep_expression_std_traits() {
+		type F = BinaryField8b;
+		use ArithExpr::{Const, Var};
+
+		const DEPTH: usize = 99_999;
+		let mut expr = Var::<F>(0);
+		for i in 0..DEPTH {
+			expr = match i % 3 {
+				0 => expr + Var(1),
+				1 => expr * Const(F::new(3)),
+				_ => expr.pow(1),
+			};
+		}
+
+		let display = expr.to_string();
+		assert_eq!(display.matches('(').count(), DEPTH);
+		assert!(display.starts_with(&"(".repeat(DEPTH)));
+		assert!(display.ends_with(" + x1) * 0x03))^1"));
+		let debug = format!("{expr:?}");
+		assert!(debug.starts_with("Pow(Mul(Add(Pow(Mul(Add("));
+		assert!(debug.ends_with(", Var(1)), Const(BinaryField8b(0x03))), 1)"));
+
+		let cloned = expr.clone();
+		assert_eq!(cloned, expr);
+		assert_ne!(cloned, expr.clone() + Var(1));
+
+		let mut buf = Vec::new();
+		SerializeBytes::serialize(&expr, &mut buf, SerializationMode::CanonicalTower).unwrap();
+		let read =
+			ArithExpr::<F>::deserialize(buf.as_slice(), SerializationMode::CanonicalTower).unwrap();
+		assert_eq!(read, expr);
+
+		drop(cloned);
+		drop(read);
+		drop(expr);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_deep_expression_serde_json() {
+		type F = BinaryField8b;
+		use ArithExpr::{Const, Var};
+
+		let mut expr = Var::<F>(0);
+		for i in 0..10_000 {
+			expr = match i % 3 {
+				0 => expr + Var(1),
+				1 => Const(F::new(3)) * expr,
+				_ => expr.pow(2),
+			};
+		}
+
+		let json = serde_json::to_string(&expr).unwrap();
+		assert_eq!(serde_json::from_str::<ArithExpr<F>>(&json).unwrap(), expr);
+	}
+
+	#[test]
+	fn test_format() {
+		type F = BinaryField8b;
+		use ArithExpr::{Const, Var};
+		let expr = (Var::<F>(0) + Const(F::new(3))).pow(2) * Var(1);
+		assert_eq!(expr.to_string(), "(((x0 + 0x03))^2 * x1)");
+		assert_eq!(
+			format!("{expr:?}"),
+			"Mul(Pow(Add(Var(0), Const(BinaryField8b(0x03))), 2), Var(1))"
+		);
+	}
+
+	#[test]
+	fn test_traversal_matches_recursive() {
+		type F = BinaryField128b;
+		use ArithExpr::{Const, Var};
+
+		fn degree(expr: &ArithExpr<F>) -> usize {
+			match expr {
+				Const(_) => 0,
+				Var(_) => 1,
+				ArithExpr::Add(left, right) => degree(left).max(degree(right)),
+				ArithExpr::Mul(left, right) => degree(left) + degree(right),
+				ArithExpr::Pow(base, exp) => degree(base) * *exp as usize,
+			}
+		}
+
+		fn n_vars(expr: &ArithExpr<F>) -> usize {
+			match expr {
+				Const(_) => 0,
+				Var(index) => *index + 1,
+				ArithExpr::Add(left, right) | ArithExpr::Mul(left, right) => {
+					n_vars(left).max(n_vars(right))
+				}
+				ArithExpr::Pow(base, _) => n_vars(base),
+			}
+		}
+
+		fn evaluate(expr: &ArithExpr<F>, vars: &[F]) -> F {
+			match expr {
+				Const(value) => *value,
+				Var(index) => vars[*index],
+				ArithExpr::Add(left, right) => evaluate(left, vars) + evaluate(right, vars),
+				ArithExpr::Mul(left, right) => evaluate(left, vars) * evaluate(right, vars),
+				ArithExpr::Pow(base, exp) => evaluate(base, vars).pow(*exp),
+			}
+		}
+
+		fn remap_vars(expr: &ArithExpr<F>, indices: &[usize]) -> ArithExpr<F> {
+			match expr {
+				Const(value) => Const(*value),
+				Var(index) => Var(indices[*index]),
+				ArithExpr::Add(left, right) => {
+					remap_vars(left, indices) + remap_vars(right, indices)
+				}
+				ArithExpr::Mul(left, right) => {
+					remap_vars(left, indices) * remap_vars(right, indices)
+				}
+				ArithExpr::Pow(base, exp) => remap_vars(base, indices).pow(*exp),
+			}
+		}
+
+		let vars = [F::new(3), F::new(0x1234), F::new(77), F::new(1 << 100)];
+		for expr in [
+			Const(F::new(5)),
+			Var(2),
+			Var(0) * (Var(1) * Var(2) * Const(F::new(9)) + Var(3)) + Var(1).pow(3),
+			(Var(3) + Const(F::ONE)).pow(4) * Var(0) + Const(F::new(2)) * Var(2),
+			((Var(0) * Var(1)).pow(2) + Var(1) * Var(0)).pow(3) + Const(F::ZERO),
+		] {
+			assert_eq!(expr.degree(), degree(&expr));
+			assert_eq!(expr.n_vars(), n_vars(&expr));
+			assert_eq!(expr.evaluate(&vars).unwrap(), evaluate(&expr, &vars));
+			assert_eq!(
+				expr.clone().remap_vars(&[3, 2, 1, 0]).unwrap(),
+				remap_vars(&expr, &[3, 2, 1, 0])
+			);
+			assert_eq!(expr.convert_field::<F>(), expr);
+			assert_eq!(expr.optimize().evaluate(&vars).unwrap(), evaluate(&expr, &vars));
+		}
+	}
 }
@@ -0,0 +1,196 @@
+// Copyright 2025 Irreducible Inc.
+
+//! [`serde`] support for [`ArithExpr`], enabled by the `serde` feature.
+//!
+//! Expressions are serialized as a flat list of internally tagged nodes, in pre-order, where
+//! composite nodes refer to their children by index in the list, such as
+//! `[{"type":"Add","lhs":1,"rhs":2},{"type":"Var","index":3},{"type":"Const","value":[7]}]`.
+//! The root is the first node. Keeping the list flat means that neither serialization nor
+//! deserialization recurse, so arbitrarily deep expressions are supported.
+//!
+//! Constants are stored as the bytes of their [`SerializeBytes`] encoding in
+//! [`SerializationMode::CanonicalTower`] mode, so that any field supported by the byte-level
+//! serialization is supported here as well.
+
+use binius_field::Field;
+use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::ArithExpr;
+
+const MODE: SerializationMode = SerializationMode::CanonicalTower;
+
+/// The serialized form of an expression node, with its children as indices in the node list.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+enum Node {
+	Const { value: Vec<u8> },
+	Var { index: usize },
+	Add { lhs: usize, rhs: usize },
+	Mul { lhs: usize, rhs: usize },
+	Pow { base: usize, exponent: u64 },
+}
+
+impl<F: Field + SerializeBytes> Serialize for ArithExpr<F> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		// In pre-order, the first child of a node directly follows it, so only the index of the
+		// right-hand side of a binary node is set once it is reached. The stack holds the
+		// sub-expressions to visit, with the node whose right-hand side they are, if any.
+		let mut nodes = Vec::new();
+		let mut stack = vec![(self, None)];
+		while let Some((expr, parent)) = stack.pop() {
+			let index = nodes.len();
+			if let Some(parent) = parent {
+				match &mut nodes[parent] {
+					Node::Add { rhs, .. } | Node::Mul { rhs, .. } => *rhs = index,
+					_ => unreachable!("only binary nodes have a right-hand side"),
+				}
+			}
+			let node = match expr {
+				Self::Const(value) => {
+					let mut bytes = Vec::new();
+					SerializeBytes::serialize(value, &mut bytes, MODE)
+						.map_err(ser::Error::custom)?;
+					Node::Const { value: bytes }
+				}
+				Self::Var(index) => Node::Var { index: *index },
+				Self::Add(lhs, rhs) => {
+					stack.extend([(&**rhs, Some(index)), (&**lhs, None)]);
+					Node::Add {
+						lhs: index + 1,
+						rhs: 0,
+					}
+				}
+				Self::Mul(lhs, rhs) => {
+					stack.extend([(&**rhs, Some(index)), (&**lhs, None)]);
+					Node::Mul {
+						lhs: index + 1,
+						rhs: 0,
+					}
+				}
+				Self::Pow(base, exponent) => {
+					stack.push((base, None));
+					Node::Pow {
+						base: index + 1,
+						exponent: *exponent,
+					}
+				}
+			};
+			nodes.push(node);
+		}
+		nodes.serialize(serializer)
+	}
+}
+
+impl<'de, F: Field + DeserializeBytes> Deserialize<'de> for ArithExpr<F> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let nodes = Vec::<Node>::deserialize(deserializer)?;
+
+		// Children come after their parents, so the nodes are built from the last one, and every
+		// node takes its children out of the sub-expressions built so far. Taking them ensures
+		// that each node is the child of at most one other node.
+		let mut exprs = Vec::with_capacity(nodes.len());
+		exprs.resize_with(nodes.len(), || None);
+		for (index, node) in nodes.into_iter().enumerate().rev() {
+			let mut child = |child: usize| {
+				if child <= index {
+					return Err(de::Error::custom(format!(
+						"node {index} refers to node {child}, which does not come after it"
+					)));
+				}
+				exprs
+					.get_mut(child)
+					.and_then(Option::take)
+					.map(Box::new)
+					.ok_or_else(|| {
+						de::Error::custom(format!(
+							"node {index} refers to node {child}, which is missing or already used"
+						))
+					})
+			};
+			let expr = match node {
+				Node::Const { value } => {
+					let value = DeserializeBytes::deserialize(value.as_slice(), MODE)
+						.map_err(de::Error::custom)?;
+					Self::Const(value)
+				}
+				Node::Var { index } => Self::Var(index),
+				Node::Add { lhs, rhs } => Self::Add(child(lhs)?, child(rhs)?),
+				Node::Mul { lhs, rhs } => Self::Mul(child(lhs)?, child(rhs)?),
+				Node::Pow { base, exponent } => Self::Pow(child(base)?, exponent),
+			};
+			exprs[index] = Some(expr);
+		}
+
+		let mut exprs = exprs.into_iter();
+		let root = exprs
+			.next()
+			.flatten()
+			.ok_or_else(|| de::Error::custom("an expression has at least one node"))?;
+		if let Some(unused) = exprs.position(|expr| expr.is_some()) {
+			return Err(de::Error::custom(format!(
+				"node {} is not a sub-expression of the root",
+				unused + 1
+			)));
+		}
+		Ok(root)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, BinaryField8b};
+
+	use crate::ArithExpr;
+
+	#[test]
+	fn test_serde_json_roundtrip() {
+		type F = BinaryField128b;
+		use ArithExpr::{Const, Var};
+
+		let expr = ((Var::<F>(0) + Const(F::new(3))) * Var(2).pow(5)
+			+ (Var(1) * Const(F::new(u128::MAX))).pow(2))
+			* (Var(3) + Var(0) * Var(1));
+		let json = serde_json::to_string(&expr).unwrap();
+		assert_eq!(serde_json::from_str::<ArithExpr<F>>(&json).unwrap(), expr);
+	}
+
+	#[test]
+	fn test_serde_json_format() {
+		type F = BinaryField8b;
+		use ArithExpr::{Const, Var};
+
+		let expr = (Var::<F>(3) + Const(F::new(7))).pow(2) * Var(1);
+		assert_eq!(
+			serde_json::to_value(&expr).unwrap(),
+			serde_json::json!([
+				{ "type": "Mul", "lhs": 1, "rhs": 5 },
+				{ "type": "Pow", "base": 2, "exponent": 2 },
+				{ "type": "Add", "lhs": 3, "rhs": 4 },
+				{ "type": "Var", "index": 3 },
+				{ "type": "Const", "value": [7] },
+				{ "type": "Var", "index": 1 },
+			])
+		);
+	}
+
+	#[test]
+	fn test_serde_json_invalid_nodes() {
+		type F = BinaryField8b;
+
+		for json in [
+			// No root.
+			r#"[]"#,
+			// A child before its parent.
+			r#"[{"type":"Var","index":0},{"type":"Pow","base":0,"exponent":2}]"#,
+			// A child that does not exist.
+			r#"[{"type":"Pow","base":1,"exponent":2}]"#,
+			// A node that is the child of two nodes.
+			r#"[{"type":"Add","lhs":1,"rhs":1},{"type":"Var","index":0}]"#,
+			// A node that is not reachable from the root.
+			r#"[{"type":"Var","index":0},{"type":"Var","index":1}]"#,
+		] {
+			assert!(serde_json::from_str::<ArithExpr<F>>(json).is_err(), "{json}");
+		}
+	}
+}
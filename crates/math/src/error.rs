@@ -41,6 +41,8 @@ pub enum Error {
 	PowerOfTwoLengthRequired,
 	#[error("argument {arg} must be in the range {range:?}")]
 	ArgumentRangeError { arg: String, range: Range<usize> },
+	#[error("argument {arg} contains the index {index} more than once")]
+	DuplicateIndex { arg: String, index: usize },
 	#[error("logarithm of embedding degree of {log_embedding_degree} is too large.")]
 	LogEmbeddingDegreeTooLarge { log_embedding_degree: usize },
 	#[error("the polynomial is expected to have {expected} variables, and instead has {actual}")]
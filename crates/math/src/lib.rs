@@ -14,6 +14,8 @@
 //! dependency cycles.
 
 mod arith_expr;
+#[cfg(feature = "serde")]
+mod arith_expr_serde;
 mod binary_subspace;
 mod composition_poly;
 mod error;
@@ -1,12 +1,11 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use anyhow::Result;
-use binius_circuits::builder::{types::U, ConstraintSystemBuilder};
+use binius_circuits::{builder::types::U, prelude::*};
 use binius_core::{constraint_system, fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
 use binius_field::{BinaryField32b, TowerField};
 use binius_hal::make_portable_backend;
 use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
-use binius_macros::arith_expr;
 use binius_utils::{checked_arithmetics::log2_ceil_usize, rayon::adjust_thread_pool};
 use bytesize::ByteSize;
 use clap::{value_parser, Parser};
@@ -43,19 +42,9 @@ fn main() -> Result<()> {
 
 	let trace_gen_scope = tracing::info_span!("generating trace").entered();
 
-	let in_a = binius_circuits::unconstrained::unconstrained::<BinaryField32b>(
-		&mut builder,
-		"in_a",
-		log_n_muls,
-	)
-	.unwrap();
-
-	let in_b = binius_circuits::unconstrained::unconstrained::<BinaryField32b>(
-		&mut builder,
-		"in_b",
-		log_n_muls,
-	)
-	.unwrap();
+	let in_a = unconstrained::<BinaryField32b>(&mut builder, "in_a", log_n_muls).unwrap();
+
+	let in_b = unconstrained::<BinaryField32b>(&mut builder, "in_b", log_n_muls).unwrap();
 	let out = builder.add_committed("out", log_n_muls, BinaryField32b::TOWER_LEVEL);
 
 	if let Some(witness) = builder.witness() {
@@ -2,13 +2,11 @@
 
 use std::{fmt::Display, str::FromStr};
 
+mod common;
+
 use anyhow::Result;
-use binius_circuits::builder::{types::U, ConstraintSystemBuilder};
-use binius_core::{constraint_system, fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
+use binius_circuits::prelude::*;
 use binius_field::{BinaryField1b, BinaryField32b, TowerField};
-use binius_hal::make_portable_backend;
-use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
-use binius_macros::arith_expr;
 use binius_utils::{checked_arithmetics::log2_ceil_usize, rayon::adjust_thread_pool};
 use bytesize::ByteSize;
 use clap::{value_parser, Parser};
@@ -79,54 +77,24 @@ fn main() -> Result<()> {
 
 	let trace_gen_scope = tracing::info_span!("generating trace").entered();
 	// Assuming our 32bit values have been committed as bits
-	let in_a = binius_circuits::unconstrained::unconstrained::<BinaryField1b>(
-		&mut builder,
-		"in_a",
-		log_n_1b_operations,
-	)?;
-	let in_b = binius_circuits::unconstrained::unconstrained::<BinaryField1b>(
-		&mut builder,
-		"in_b",
-		log_n_1b_operations,
-	)?;
+	let in_a = unconstrained::<BinaryField1b>(&mut builder, "in_a", log_n_1b_operations)?;
+	let in_b = unconstrained::<BinaryField1b>(&mut builder, "in_b", log_n_1b_operations)?;
 	let _result = match args.op {
-		BitwiseOp::And => binius_circuits::bitwise::and(&mut builder, "a_and_b", in_a, in_b),
+		BitwiseOp::And => bitwise_and(&mut builder, "a_and_b", in_a, in_b),
 		BitwiseOp::Xor => {
-			let out = binius_circuits::bitwise::xor(&mut builder, "a_xor_b", in_a, in_b)?;
+			let out = xor(&mut builder, "a_xor_b", in_a, in_b)?;
 			// TODO: Assert equality so that something is constrained.
 			builder.assert_zero("zero", [in_a], arith_expr!([x] = x - x).convert_field());
 			Ok(out)
 		}
-		BitwiseOp::Or => binius_circuits::bitwise::or(&mut builder, "a_or_b", in_a, in_b),
+		BitwiseOp::Or => bitwise_or(&mut builder, "a_or_b", in_a, in_b),
 	};
 	drop(trace_gen_scope);
 
-	let witness = builder
-		.take_witness()
-		.expect("builder created with witness");
-	let constraint_system = builder.build()?;
-
-	let backend = make_portable_backend();
-
 	let proof =
-		constraint_system::prove::<
-			U,
-			CanonicalTowerFamily,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
-			_,
-		>(&constraint_system, args.log_inv_rate as usize, SECURITY_BITS, &[], witness, &backend)?;
+		common::prove_and_verify(builder, args.log_inv_rate as usize, SECURITY_BITS, vec![])?;
 
 	println!("Proof size: {}", ByteSize::b(proof.get_proof_size() as u64));
 
-	constraint_system::verify::<
-		U,
-		CanonicalTowerFamily,
-		Groestl256,
-		Groestl256ByteCompression,
-		HasherChallenger<Groestl256>,
-	>(&constraint_system, args.log_inv_rate as usize, SECURITY_BITS, &[], proof)?;
-
 	Ok(())
 }
@@ -3,12 +3,10 @@
 use anyhow::Result;
 use binius_circuits::{
 	blake3::{BLAKE3_STATE_LEN, CHAINING_VALUE_LEN},
-	builder::{types::U, ConstraintSystemBuilder},
-	unconstrained::unconstrained,
-};
-use binius_core::{
-	constraint_system, fiat_shamir::HasherChallenger, oracle::OracleId, tower::CanonicalTowerFamily,
+	builder::types::U,
+	prelude::*,
 };
+use binius_core::{constraint_system, fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
 use binius_field::BinaryField1b;
 use binius_hal::make_portable_backend;
 use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
@@ -31,8 +29,6 @@ struct Args {
 const COMPRESSION_LOG_LEN: usize = 5;
 
 fn main() -> Result<()> {
-	const SECURITY_BITS: usize = 100;
-
 	adjust_thread_pool()
 		.as_ref()
 		.expect("failed to init thread pool");
@@ -41,6 +37,13 @@ fn main() -> Result<()> {
 
 	let _guard = init_tracing().expect("failed to initialize tracing");
 
+	prove_and_verify(&args)
+}
+
+/// Builds the circuit for `args`, then proves and verifies it.
+fn prove_and_verify(args: &Args) -> Result<()> {
+	const SECURITY_BITS: usize = 100;
+
 	println!("Verifying {} Blake3 compressions", args.n_compressions);
 
 	let log_n_compressions = log2_ceil_usize(args.n_compressions as usize);
@@ -100,3 +103,15 @@ fn main() -> Result<()> {
 
 	Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+	use clap::Parser;
+
+	use super::{prove_and_verify, Args};
+
+	#[test]
+	fn test_blake3_circuit() {
+		prove_and_verify(&Args::parse_from(["blake3_circuit", "--n-compressions", "8"])).unwrap();
+	}
+}
@@ -2,8 +2,9 @@
 
 use anyhow::Result;
 use binius_circuits::{
-	builder::{types::U, ConstraintSystemBuilder},
+	builder::types::U,
 	collatz::{Advice, Collatz},
+	prelude::*,
 };
 use binius_core::{
 	constraint_system::{self, Proof},
@@ -0,0 +1,27 @@
+// Copyright 2025 Irreducible Inc.
+
+use anyhow::Result;
+use binius_circuits::builder::ConstraintSystemBuilder;
+use binius_core::{
+	constraint_system::{channel::Boundary, Proof},
+	fiat_shamir::HasherChallenger,
+};
+use binius_field::BinaryField128b;
+use binius_hal::make_portable_backend;
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+
+/// Proves and verifies the circuit in `builder` with Grøstl-256 hashing and the portable backend.
+pub fn prove_and_verify(
+	builder: ConstraintSystemBuilder,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: Vec<Boundary<BinaryField128b>>,
+) -> Result<Proof> {
+	builder
+		.prove_and_verify::<Groestl256, Groestl256ByteCompression, HasherChallenger<Groestl256>, _>(
+			log_inv_rate,
+			security_bits,
+			boundaries,
+			&make_portable_backend(),
+		)
+}
@@ -0,0 +1,74 @@
+// Copyright 2025 Irreducible Inc.
+
+use anyhow::Result;
+use binius_circuits::{
+	builder::types::U,
+	fibonacci::{fibonacci_constraint_system, fibonacci_witness},
+};
+use binius_core::{constraint_system, fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
+use binius_hal::make_portable_backend;
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_utils::rayon::adjust_thread_pool;
+use bytesize::ByteSize;
+use clap::{value_parser, Parser};
+use tracing_profile::init_tracing;
+
+#[derive(Debug, Parser)]
+struct Args {
+	/// The number of Fibonacci numbers to prove.
+	#[arg(short, long, default_value_t = 1 << 16, value_parser = value_parser!(u32).range(3..))]
+	count: u32,
+	/// The negative binary logarithm of the Reed–Solomon code rate.
+	#[arg(long, default_value_t = 1, value_parser = value_parser!(u32).range(1..))]
+	log_inv_rate: u32,
+}
+
+const SECURITY_BITS: usize = 100;
+
+fn main() -> Result<()> {
+	adjust_thread_pool()
+		.as_ref()
+		.expect("failed to init thread pool");
+
+	let args = Args::parse();
+	let _guard = init_tracing().expect("failed to initialize tracing");
+
+	println!("Verifying the first {} Fibonacci numbers over u32", args.count);
+
+	let log_inv_rate = args.log_inv_rate as usize;
+
+	let allocator = bumpalo::Bump::new();
+	let trace_gen_scope = tracing::info_span!("generating trace").entered();
+	let witness = fibonacci_witness(args.count, &allocator)?;
+	drop(trace_gen_scope);
+
+	let (constraint_system, boundaries) = fibonacci_constraint_system(args.count)?;
+
+	let proof = constraint_system::prove::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		&constraint_system,
+		log_inv_rate,
+		SECURITY_BITS,
+		&witness.boundaries,
+		witness.witness,
+		&make_portable_backend(),
+	)?;
+
+	println!("Proof size: {}", ByteSize::b(proof.get_proof_size() as u64));
+
+	constraint_system::verify::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(&constraint_system, log_inv_rate, SECURITY_BITS, &boundaries, proof)?;
+
+	Ok(())
+}
@@ -3,7 +3,11 @@
 use std::vec;
 
 use anyhow::Result;
-use binius_circuits::builder::{types::U, ConstraintSystemBuilder};
+use binius_circuits::{
+	builder::types::U,
+	keccakf::{keccakf, KeccakState},
+	prelude::*,
+};
 use binius_core::{constraint_system, fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
 use binius_hal::make_portable_backend;
 use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
@@ -23,8 +27,6 @@ struct Args {
 }
 
 fn main() -> Result<()> {
-	const SECURITY_BITS: usize = 100;
-
 	adjust_thread_pool()
 		.as_ref()
 		.expect("failed to init thread pool");
@@ -33,6 +35,13 @@ fn main() -> Result<()> {
 
 	let _guard = init_tracing().expect("failed to initialize tracing");
 
+	prove_and_verify(&args)
+}
+
+/// Builds the circuit for `args`, then proves and verifies it.
+fn prove_and_verify(args: &Args) -> Result<()> {
+	const SECURITY_BITS: usize = 100;
+
 	println!("Verifying {} Keccak-f permutations", args.n_permutations);
 
 	let log_n_permutations = log2_ceil_usize(args.n_permutations as usize);
@@ -45,8 +54,7 @@ fn main() -> Result<()> {
 
 	let trace_gen_scope = tracing::info_span!("generating trace").entered();
 	let input_witness = vec![];
-	let _state_out =
-		binius_circuits::keccakf::keccakf(&mut builder, &Some(input_witness), log_size)?;
+	let _state_out: KeccakState = keccakf(&mut builder, &Some(input_witness), log_size)?.output;
 	drop(trace_gen_scope);
 
 	let witness = builder
@@ -84,3 +92,15 @@ fn main() -> Result<()> {
 
 	Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+	use clap::Parser;
+
+	use super::{prove_and_verify, Args};
+
+	#[test]
+	fn test_keccakf() {
+		prove_and_verify(&Args::parse_from(["keccakf_circuit", "--n-permutations", "8"])).unwrap();
+	}
+}
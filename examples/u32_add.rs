@@ -1,14 +1,10 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+mod common;
+
 use anyhow::Result;
-use binius_circuits::{
-	arithmetic::Flags,
-	builder::{types::U, ConstraintSystemBuilder},
-};
-use binius_core::{constraint_system, fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
+use binius_circuits::{arithmetic::Flags, prelude::*};
 use binius_field::BinaryField1b;
-use binius_hal::make_portable_backend;
-use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
 use binius_utils::{checked_arithmetics::log2_ceil_usize, rayon::adjust_thread_pool};
 use bytesize::ByteSize;
 use clap::{value_parser, Parser};
@@ -25,8 +21,6 @@ struct Args {
 }
 
 fn main() -> Result<()> {
-	const SECURITY_BITS: usize = 100;
-
 	adjust_thread_pool()
 		.as_ref()
 		.expect("failed to init thread pool");
@@ -35,6 +29,13 @@ fn main() -> Result<()> {
 
 	let _guard = init_tracing().expect("failed to initialize tracing");
 
+	prove_and_verify(&args)
+}
+
+/// Builds the circuit for `args`, then proves and verifies it.
+fn prove_and_verify(args: &Args) -> Result<()> {
+	const SECURITY_BITS: usize = 100;
+
 	println!("Verifying {} u32 additions", args.n_additions);
 
 	let log_n_additions = log2_ceil_usize(args.n_additions as usize);
@@ -43,46 +44,28 @@ fn main() -> Result<()> {
 	let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
 
 	let trace_gen_scope = tracing::info_span!("generating trace").entered();
-	let in_a = binius_circuits::unconstrained::unconstrained::<BinaryField1b>(
-		&mut builder,
-		"in_a",
-		log_n_additions + 5,
-	)?;
-	let in_b = binius_circuits::unconstrained::unconstrained::<BinaryField1b>(
-		&mut builder,
-		"in_b",
-		log_n_additions + 5,
-	)?;
+	let in_a = unconstrained::<BinaryField1b>(&mut builder, "in_a", log_n_additions + 5)?;
+	let in_b = unconstrained::<BinaryField1b>(&mut builder, "in_b", log_n_additions + 5)?;
 	let _sum =
 		binius_circuits::arithmetic::u32::add(&mut builder, "sum", in_a, in_b, Flags::Unchecked)?;
 	drop(trace_gen_scope);
 
-	let witness = builder
-		.take_witness()
-		.expect("builder created with witness");
-	let constraint_system = builder.build()?;
-
-	let backend = make_portable_backend();
-
 	let proof =
-		constraint_system::prove::<
-			U,
-			CanonicalTowerFamily,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
-			_,
-		>(&constraint_system, args.log_inv_rate as usize, SECURITY_BITS, &[], witness, &backend)?;
+		common::prove_and_verify(builder, args.log_inv_rate as usize, SECURITY_BITS, vec![])?;
 
 	println!("Proof size: {}", ByteSize::b(proof.get_proof_size() as u64));
 
-	constraint_system::verify::<
-		U,
-		CanonicalTowerFamily,
-		Groestl256,
-		Groestl256ByteCompression,
-		HasherChallenger<Groestl256>,
-	>(&constraint_system, args.log_inv_rate as usize, SECURITY_BITS, &[], proof)?;
-
 	Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+	use clap::Parser;
+
+	use super::{prove_and_verify, Args};
+
+	#[test]
+	fn test_u32_addition() {
+		prove_and_verify(&Args::parse_from(["u32_add"])).unwrap();
+	}
+}
@@ -4,12 +4,13 @@ use std::array;
 
 use anyhow::Result;
 use binius_circuits::{
-	builder::{types::U, ConstraintSystemBuilder},
+	builder::types::U,
 	lasso::{
 		batch::LookupBatch,
 		big_integer_ops::byte_sliced_mul,
 		lookups::u8_arithmetic::{add_lookup, dci_lookup, mul_lookup},
 	},
+	prelude::*,
 	transparent,
 };
 use binius_core::{constraint_system, fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
@@ -55,20 +56,10 @@ fn main() -> Result<()> {
 	let trace_gen_scope = tracing::info_span!("generating trace").entered();
 	// Assuming our input data is already transposed, i.e a length 4 array of B8's
 	let in_a = array::from_fn(|i| {
-		binius_circuits::unconstrained::unconstrained::<BinaryField8b>(
-			&mut builder,
-			format!("in_a_{}", i),
-			log_n_muls,
-		)
-		.unwrap()
+		unconstrained::<BinaryField8b>(&mut builder, format!("in_a_{}", i), log_n_muls).unwrap()
 	});
 	let in_b = array::from_fn(|i| {
-		binius_circuits::unconstrained::unconstrained::<BinaryField8b>(
-			&mut builder,
-			format!("in_b_{}", i),
-			log_n_muls,
-		)
-		.unwrap()
+		unconstrained::<BinaryField8b>(&mut builder, format!("in_b_{}", i), log_n_muls).unwrap()
 	});
 	let zero_oracle_carry =
 		transparent::constant(&mut builder, "zero carry", log_n_muls, BinaryField1b::ZERO).unwrap();
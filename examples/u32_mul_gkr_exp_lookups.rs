@@ -3,13 +3,8 @@
 use std::array;
 
 use anyhow::Result;
-use binius_circuits::{
-	arithmetic::mul,
-	builder::{types::U, ConstraintSystemBuilder},
-};
-use binius_core::{
-	constraint_system, fiat_shamir::HasherChallenger, oracle::OracleId, tower::CanonicalTowerFamily,
-};
+use binius_circuits::{arithmetic::mul, builder::types::U, prelude::*};
+use binius_core::{constraint_system, fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
 use binius_field::BinaryField1b;
 use binius_hal::make_portable_backend;
 use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
@@ -48,20 +43,10 @@ fn main() -> Result<()> {
 
 	let trace_gen_scope = tracing::info_span!("generating trace").entered();
 	let in_a: [OracleId; 32] = array::from_fn(|i| {
-		binius_circuits::unconstrained::unconstrained::<BinaryField1b>(
-			&mut builder,
-			format!("in_a_{}", i),
-			log_n_muls,
-		)
-		.unwrap()
+		unconstrained::<BinaryField1b>(&mut builder, format!("in_a_{}", i), log_n_muls).unwrap()
 	});
 	let in_b: [OracleId; 32] = array::from_fn(|i| {
-		binius_circuits::unconstrained::unconstrained::<BinaryField1b>(
-			&mut builder,
-			format!("in_b_{}", i),
-			log_n_muls,
-		)
-		.unwrap()
+		unconstrained::<BinaryField1b>(&mut builder, format!("in_b_{}", i), log_n_muls).unwrap()
 	});
 
 	mul::u32_mul::<22>(&mut builder, "u32_mul", in_a, in_b).unwrap();
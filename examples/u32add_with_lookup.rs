@@ -1,7 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use anyhow::Result;
-use binius_circuits::builder::{types::U, ConstraintSystemBuilder};
+use binius_circuits::{builder::types::U, prelude::*};
 use binius_core::{constraint_system, fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
 use binius_field::{
 	arch::OptimalUnderlier, as_packed_field::PackedType, BinaryField1b, BinaryField8b,
@@ -26,8 +26,6 @@ struct Args {
 }
 
 fn main() -> Result<()> {
-	const SECURITY_BITS: usize = 100;
-
 	adjust_thread_pool()
 		.as_ref()
 		.expect("failed to init thread pool");
@@ -36,6 +34,13 @@ fn main() -> Result<()> {
 
 	let _guard = init_tracing().expect("failed to initialize tracing");
 
+	prove_and_verify(&args)
+}
+
+/// Builds the circuit for `args`, then proves and verifies it.
+fn prove_and_verify(args: &Args) -> Result<()> {
+	const SECURITY_BITS: usize = 100;
+
 	println!("Verifying {} u32 additions", args.n_additions);
 
 	let log_n_additions = log2_ceil_usize(args.n_additions as usize);
@@ -44,22 +49,9 @@ fn main() -> Result<()> {
 	let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
 
 	let trace_gen_scope = tracing::info_span!("generating trace").entered();
-	let in_a = binius_circuits::unconstrained::unconstrained::<BinaryField8b>(
-		&mut builder,
-		"in_a",
-		log_n_additions + 2,
-	)?;
-	let in_b = binius_circuits::unconstrained::unconstrained::<BinaryField8b>(
-		&mut builder,
-		"in_b",
-		log_n_additions + 2,
-	)?;
-	let _product = binius_circuits::lasso::u32add::<BinaryField8b, BinaryField8b>(
-		&mut builder,
-		"out_c",
-		in_a,
-		in_b,
-	)?;
+	let in_a = unconstrained::<BinaryField8b>(&mut builder, "in_a", log_n_additions + 2)?;
+	let in_b = unconstrained::<BinaryField8b>(&mut builder, "in_b", log_n_additions + 2)?;
+	let _product = u32add::<BinaryField8b, BinaryField8b>(&mut builder, "out_c", in_a, in_b)?;
 	drop(trace_gen_scope);
 
 	let witness = builder
@@ -91,3 +83,15 @@ fn main() -> Result<()> {
 
 	Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+	use clap::Parser;
+
+	use super::{prove_and_verify, Args};
+
+	#[test]
+	fn test_u8_addition() {
+		prove_and_verify(&Args::parse_from(["u32add_with_lookup"])).unwrap();
+	}
+}
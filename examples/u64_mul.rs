@@ -1,10 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use anyhow::Result;
-use binius_circuits::{
-	arithmetic::mul,
-	builder::{types::U, ConstraintSystemBuilder},
-};
+use binius_circuits::{arithmetic::mul, builder::types::U, prelude::*};
 use binius_core::{constraint_system, fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
 use binius_field::{BinaryField128b, BinaryField1b};
 use binius_hal::make_portable_backend;
@@ -45,22 +42,12 @@ fn main() -> Result<()> {
 	let trace_gen_scope = tracing::info_span!("generating trace").entered();
 	let in_a = (0..64)
 		.map(|i| {
-			binius_circuits::unconstrained::unconstrained::<BinaryField1b>(
-				&mut builder,
-				format!("in_a_{}", i),
-				log_n_muls,
-			)
-			.unwrap()
+			unconstrained::<BinaryField1b>(&mut builder, format!("in_a_{}", i), log_n_muls).unwrap()
 		})
 		.collect::<Vec<_>>();
 	let in_b = (0..64)
 		.map(|i| {
-			binius_circuits::unconstrained::unconstrained::<BinaryField1b>(
-				&mut builder,
-				format!("in_b_{}", i),
-				log_n_muls,
-			)
-			.unwrap()
+			unconstrained::<BinaryField1b>(&mut builder, format!("in_b_{}", i), log_n_muls).unwrap()
 		})
 		.collect::<Vec<_>>();
 
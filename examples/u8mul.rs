@@ -1,14 +1,13 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+mod common;
+
 use anyhow::Result;
 use binius_circuits::{
-	builder::{types::U, ConstraintSystemBuilder},
 	lasso::{batch::LookupBatch, lookups},
+	prelude::*,
 };
-use binius_core::{constraint_system, fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
 use binius_field::{BinaryField32b, BinaryField8b};
-use binius_hal::make_portable_backend;
-use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
 use binius_utils::{checked_arithmetics::log2_ceil_usize, rayon::adjust_thread_pool};
 use bytesize::ByteSize;
 use clap::{value_parser, Parser};
@@ -43,16 +42,8 @@ fn main() -> Result<()> {
 	let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
 
 	let trace_gen_scope = tracing::info_span!("generating trace").entered();
-	let in_a = binius_circuits::unconstrained::unconstrained::<BinaryField8b>(
-		&mut builder,
-		"in_a",
-		log_n_multiplications,
-	)?;
-	let in_b = binius_circuits::unconstrained::unconstrained::<BinaryField8b>(
-		&mut builder,
-		"in_b",
-		log_n_multiplications,
-	)?;
+	let in_a = unconstrained::<BinaryField8b>(&mut builder, "in_a", log_n_multiplications)?;
+	let in_b = unconstrained::<BinaryField8b>(&mut builder, "in_b", log_n_multiplications)?;
 
 	let mul_lookup_table = lookups::u8_arithmetic::mul_lookup(&mut builder, "mul table").unwrap();
 
@@ -70,38 +61,10 @@ fn main() -> Result<()> {
 	lookup_batch.execute::<BinaryField32b>(&mut builder)?;
 	drop(trace_gen_scope);
 
-	let witness = builder
-		.take_witness()
-		.expect("builder created with witness");
-	let constraint_system = builder.build()?;
-
-	let backend = make_portable_backend();
-
 	let proof =
-		constraint_system::prove::<
-			U,
-			CanonicalTowerFamily,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
-			_,
-		>(&constraint_system, args.log_inv_rate as usize, SECURITY_BITS, &[], witness, &backend)?;
+		common::prove_and_verify(builder, args.log_inv_rate as usize, SECURITY_BITS, vec![])?;
 
 	println!("Proof size: {}", ByteSize::b(proof.get_proof_size() as u64));
 
-	constraint_system::verify::<
-		U,
-		CanonicalTowerFamily,
-		Groestl256,
-		Groestl256ByteCompression,
-		HasherChallenger<Groestl256>,
-	>(
-		&constraint_system.no_base_constraints(),
-		args.log_inv_rate as usize,
-		SECURITY_BITS,
-		&[],
-		proof,
-	)?;
-
 	Ok(())
 }
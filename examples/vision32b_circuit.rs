@@ -10,10 +10,8 @@
 use std::array;
 
 use anyhow::Result;
-use binius_circuits::builder::{types::U, ConstraintSystemBuilder};
-use binius_core::{
-	constraint_system, fiat_shamir::HasherChallenger, oracle::OracleId, tower::CanonicalTowerFamily,
-};
+use binius_circuits::{builder::types::U, prelude::*};
+use binius_core::{constraint_system, fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
 use binius_field::BinaryField32b;
 use binius_hal::make_portable_backend;
 use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
@@ -52,12 +50,8 @@ fn main() -> Result<()> {
 
 	let trace_gen_scope = tracing::info_span!("generating trace").entered();
 	let state_in: [OracleId; 24] = array::from_fn(|i| {
-		binius_circuits::unconstrained::unconstrained::<BinaryField32b>(
-			&mut builder,
-			format!("p_in_{i}"),
-			log_n_permutations,
-		)
-		.unwrap()
+		unconstrained::<BinaryField32b>(&mut builder, format!("p_in_{i}"), log_n_permutations)
+			.unwrap()
 	});
 	let _state_out =
 		binius_circuits::vision::vision_permutation(&mut builder, log_n_permutations, state_in)?;